@@ -0,0 +1,725 @@
+use crate::{CartesianPoint, GeographicPoint, GreatCircleArc};
+use std::f64::consts::{FRAC_PI_2, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+const EPSILON: f64 = 1e-12;
+
+/// Represents a region of the sphere bounded by two meridians and two parallels.
+///
+/// The longitude range goes eastwards from the west bound to the east one, so
+/// whenever the former is greater than the latter the box crosses the
+/// antimeridian. Both bounds are in the range __[-π, +π]__, being the box of
+/// the full longitude range the one going from `-π` to `π`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoBoundingBox {
+    west: f64,
+    south: f64,
+    east: f64,
+    north: f64,
+}
+
+impl Default for GeoBoundingBox {
+    fn default() -> Self {
+        Self::new(0., 0., 0., 0.)
+    }
+}
+
+#[wasm_bindgen]
+impl GeoBoundingBox {
+    /// Returns the box with the given bounds (in radiants). Longitudes are
+    /// normalized into the range __[-π, +π]__ and latitudes are clamped into
+    /// the range __\[-π/2, +π/2\]__.
+    #[wasm_bindgen(constructor)]
+    pub fn new(west: f64, south: f64, east: f64, north: f64) -> Self {
+        let normalize = |longitude: f64| {
+            if (-PI..=PI).contains(&longitude) {
+                longitude
+            } else {
+                (longitude + PI).rem_euclid(2. * PI) - PI
+            }
+        };
+
+        Self {
+            west: normalize(west),
+            south: south.clamp(-FRAC_PI_2, FRAC_PI_2),
+            east: normalize(east),
+            north: north.clamp(-FRAC_PI_2, FRAC_PI_2),
+        }
+    }
+
+    /// Returns the box covering the whole sphere.
+    pub fn world() -> Self {
+        Self::new(-PI, -FRAC_PI_2, PI, FRAC_PI_2)
+    }
+
+    /// Returns the western bound (in radiants) of the box.
+    pub fn west(&self) -> f64 {
+        self.west
+    }
+
+    /// Returns the southern bound (in radiants) of the box.
+    pub fn south(&self) -> f64 {
+        self.south
+    }
+
+    /// Returns the eastern bound (in radiants) of the box.
+    pub fn east(&self) -> f64 {
+        self.east
+    }
+
+    /// Returns the northern bound (in radiants) of the box.
+    pub fn north(&self) -> f64 {
+        self.north
+    }
+
+    /// Returns the smallest box containing the given [`GreatCircleArc`], which
+    /// may reach latitudes beyond the ones of its ends.
+    pub fn from_arc(arc: &GreatCircleArc) -> Self {
+        let (from, to) = (arc.from(), arc.to());
+        let mut south = from.latitude().min(to.latitude());
+        let mut north = from.latitude().max(to.latitude());
+
+        let is_pole = |point: &GeographicPoint| point.latitude().abs() == FRAC_PI_2;
+        if is_pole(&from) || is_pole(&to) {
+            // the arc is part of a meridian, but the longitude of a pole is
+            // meaningless and so the one of the other end is taken.
+            let longitude = match (is_pole(&from), is_pole(&to)) {
+                (true, true) => 0.,
+                (true, false) => to.longitude(),
+                _ => from.longitude(),
+            };
+
+            return Self::new(longitude, south, longitude, north);
+        }
+
+        // the northernmost point of the great circle is the projection of the
+        // north pole onto its plane, and its antipode is the southernmost one.
+        let pole = arc.pole();
+        let highest = CartesianPoint::new(
+            -pole.z() * pole.x(),
+            -pole.z() * pole.y(),
+            1. - pole.z().powi(2),
+        );
+
+        if highest.magnitude() > f64::EPSILON {
+            let highest = highest.normalize();
+            let lowest = CartesianPoint::new(-highest.x(), -highest.y(), -highest.z());
+
+            let highest = GeographicPoint::from_cartesian(&highest).with_altitude(0.);
+            if arc.contains(&highest) {
+                north = highest.latitude();
+            }
+
+            let lowest = GeographicPoint::from_cartesian(&lowest).with_altitude(0.);
+            if arc.contains(&lowest) {
+                south = lowest.latitude();
+            }
+        }
+
+        if north >= FRAC_PI_2 - f64::EPSILON || south <= -FRAC_PI_2 + f64::EPSILON {
+            // the arc goes across a pole, and so through all the meridians
+            return Self::new(-PI, south, PI, north);
+        }
+
+        // the arc always goes through the shortest longitude range
+        let delta = GeographicPoint::default()
+            .with_longitude(to.longitude() - from.longitude())
+            .longitude();
+
+        if delta.is_sign_negative() {
+            Self::new(to.longitude(), south, from.longitude(), north)
+        } else {
+            Self::new(from.longitude(), south, to.longitude(), north)
+        }
+    }
+
+    /// Returns true if, and only if, the box crosses the antimeridian.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// Returns true if, and only if, the box covers all the meridians.
+    pub fn is_full_longitude(&self) -> bool {
+        self.west == -PI && self.east == PI
+    }
+
+    /// Returns the width (in radiants) of the longitude range of the box.
+    pub fn width(&self) -> f64 {
+        if self.is_full_longitude() {
+            return 2. * PI;
+        }
+
+        (self.east - self.west).rem_euclid(2. * PI)
+    }
+
+    /// Returns true if, and only if, the given point lies inside the box or on
+    /// its boundary.
+    pub fn contains(&self, point: &GeographicPoint) -> bool {
+        (self.south..=self.north).contains(&point.latitude())
+            && self.contains_longitude(point.longitude())
+    }
+
+    /// Returns true if, and only if, the given box lies entirely inside self.
+    pub fn contains_box(&self, other: &GeoBoundingBox) -> bool {
+        self.south <= other.south && other.north <= self.north && self.contains_longitudes(other)
+    }
+
+    /// Returns true if, and only if, self and the given box share any point.
+    pub fn intersects(&self, other: &GeoBoundingBox) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the smallest box containing both, self and the given box.
+    pub fn union(&self, other: &GeoBoundingBox) -> GeoBoundingBox {
+        let (west, east) = if self.contains_longitudes(other) {
+            (self.west, self.east)
+        } else if other.contains_longitudes(self) {
+            (other.west, other.east)
+        } else if self.contains_longitude(other.west) && self.contains_longitude(other.east) {
+            // both ranges cover all the meridians together
+            (-PI, PI)
+        } else if self.contains_longitude(other.west) {
+            (self.west, other.east)
+        } else if self.contains_longitude(other.east) {
+            (other.west, self.east)
+        } else if (other.west - self.east).rem_euclid(2. * PI)
+            < (self.west - other.east).rem_euclid(2. * PI)
+        {
+            // the ranges are disjoint, so the smallest gap between them is filled
+            (self.west, other.east)
+        } else {
+            (other.west, self.east)
+        };
+
+        Self::new(
+            west,
+            self.south.min(other.south),
+            east,
+            self.north.max(other.north),
+        )
+    }
+
+    /// Returns the intersection between self and the given box, if any.
+    ///
+    /// Two longitude ranges may intersect in two disjoint ranges, in which case
+    /// the smallest box containing both is returned.
+    pub fn intersection(&self, other: &GeoBoundingBox) -> Option<GeoBoundingBox> {
+        let south = self.south.max(other.south);
+        let north = self.north.min(other.north);
+        if south > north {
+            return None;
+        }
+
+        let (west, east) = if self.contains_longitudes(other) {
+            (other.west, other.east)
+        } else if other.contains_longitudes(self) {
+            (self.west, self.east)
+        } else {
+            match (
+                self.contains_longitude(other.west),
+                other.contains_longitude(self.west),
+            ) {
+                (true, true) if self.width() <= other.width() => (self.west, self.east),
+                (true, true) => (other.west, other.east),
+                (true, false) => (other.west, self.east),
+                (false, true) => (self.west, other.east),
+                (false, false) => return None,
+            }
+        };
+
+        Some(Self::new(west, south, east, north))
+    }
+
+    /// Returns the smallest box containing all the points whose angular distance
+    /// (in radiants) to self is not greater than the given one.
+    pub fn expand(&self, distance: f64) -> GeoBoundingBox {
+        let south = self.south - distance;
+        let north = self.north + distance;
+
+        if south <= -FRAC_PI_2 || north >= FRAC_PI_2 {
+            // the expanded box contains a pole, and so all the meridians
+            return Self::new(-PI, south, PI, north);
+        }
+
+        // the farther from the equator, the wider the expansion in longitude
+        let latitude = self.south.abs().max(self.north.abs());
+        let delta = (distance.sin() / latitude.cos()).min(1.).asin();
+
+        if self.width() + 2. * delta >= 2. * PI {
+            return Self::new(-PI, south, PI, north);
+        }
+
+        Self::new(self.west - delta, south, self.east + delta, north)
+    }
+
+    /// Returns the angular distance (in radiants) from the given point to the
+    /// closest point of the box, which is zero if the box contains it.
+    pub fn distance(&self, point: &GeographicPoint) -> f64 {
+        if self.contains_longitude(point.longitude()) {
+            // no point of the box is closer than the one on the same meridian
+            return (point.latitude() - self.north)
+                .max(self.south - point.latitude())
+                .max(0.);
+        }
+
+        // otherwise, the closest point lays on any of the meridian bounds
+        [self.west, self.east]
+            .into_iter()
+            .map(|longitude| self.meridian_distance(longitude, point))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl GeoBoundingBox {
+    /// Returns the smallest box containing all the given points, if any.
+    pub fn from_points(points: &[GeographicPoint]) -> Option<Self> {
+        let south = points
+            .iter()
+            .map(GeographicPoint::latitude)
+            .reduce(f64::min)?;
+        let north = points
+            .iter()
+            .map(GeographicPoint::latitude)
+            .reduce(f64::max)?;
+
+        let mut longitudes: Vec<f64> = points.iter().map(GeographicPoint::longitude).collect();
+        longitudes.sort_by(f64::total_cmp);
+
+        // the smallest longitude range is the complement of the largest gap
+        // between consecutive longitudes.
+        let (west, east, _) = longitudes
+            .iter()
+            .zip(longitudes.iter().cycle().skip(1))
+            .map(|(&before, &after)| (after, before, (after - before).rem_euclid(2. * PI)))
+            .max_by(|a, b| a.2.total_cmp(&b.2))?;
+
+        if longitudes.len() == 1 {
+            return Some(Self::new(west, south, west, north));
+        }
+
+        Some(Self::new(west, south, east, north))
+    }
+
+    /// Returns the angular distance (in radiants) from the given point to the
+    /// segment of the meridian of the given longitude between the latitudes of
+    /// the box.
+    ///
+    /// Unlike a [`GreatCircleArc`], the segment is well defined even if it
+    /// goes from pole to pole.
+    fn meridian_distance(&self, longitude: f64, point: &GeographicPoint) -> f64 {
+        let delta = point.longitude() - longitude;
+        let (sin_latitude, cos_latitude) = point.latitude().sin_cos();
+
+        // the foot of the perpendicular from the point to the great circle of the
+        // meridian, which is on the meridian itself unless beyond the poles.
+        let foot = sin_latitude.atan2(cos_latitude * delta.cos());
+        let foot = (delta.cos() >= 0. && (self.south..=self.north).contains(&foot)).then_some(foot);
+
+        [self.south, self.north]
+            .into_iter()
+            .chain(foot)
+            .map(|latitude| {
+                point.distance(
+                    &GeographicPoint::default()
+                        .with_longitude(longitude)
+                        .with_latitude(latitude),
+                )
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns true if, and only if, the given arc crosses the segment of the
+    /// meridian of the given longitude between the latitudes of the box.
+    pub(crate) fn meridian_crosses(&self, longitude: f64, arc: &GreatCircleArc) -> bool {
+        let meridian = |latitude| {
+            GeographicPoint::default()
+                .with_longitude(longitude)
+                .with_latitude(latitude)
+        };
+
+        let (sin, cos) = longitude.sin_cos();
+        let line = arc.pole().cross(&CartesianPoint::new(-sin, cos, 0.));
+        let candidates = if line.magnitude() <= EPSILON {
+            // both lay on the same great circle, or the arc is a single point
+            vec![
+                arc.from(),
+                arc.to(),
+                meridian(self.south),
+                meridian(self.north),
+            ]
+        } else {
+            let point = line.normalize();
+            let antipode = CartesianPoint::new(-point.x(), -point.y(), -point.z());
+            vec![
+                GeographicPoint::from_cartesian(&point),
+                GeographicPoint::from_cartesian(&antipode),
+            ]
+        };
+
+        candidates.into_iter().any(|point| {
+            let delta = point.longitude() - longitude;
+            let cos_latitude = point.latitude().cos();
+
+            arc.contains(&point)
+                && (self.south - EPSILON..=self.north + EPSILON).contains(&point.latitude())
+                && (cos_latitude * delta.sin()).abs() <= EPSILON
+                && (delta.cos() >= 0. || cos_latitude <= EPSILON)
+        })
+    }
+
+    /// Returns true if, and only if, the given longitude is inside the
+    /// longitude range of the box.
+    fn contains_longitude(&self, longitude: f64) -> bool {
+        if self.is_full_longitude() {
+            return true;
+        }
+
+        let offset = (longitude - self.west).rem_euclid(2. * PI);
+        offset <= self.width()
+    }
+
+    /// Returns true if, and only if, the longitude range of the given box is
+    /// inside the one of self.
+    fn contains_longitudes(&self, other: &GeoBoundingBox) -> bool {
+        if self.is_full_longitude() {
+            return true;
+        }
+
+        if other.is_full_longitude() {
+            return false;
+        }
+
+        let west = (other.west - self.west).rem_euclid(2. * PI);
+        let east = (other.east - self.west).rem_euclid(2. * PI);
+        west <= east && east <= self.width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    fn box_from_degrees(west: f64, south: f64, east: f64, north: f64) -> GeoBoundingBox {
+        GeoBoundingBox::new(
+            west.to_radians(),
+            south.to_radians(),
+            east.to_radians(),
+            north.to_radians(),
+        )
+    }
+
+    fn assert_box_eq(name: &str, got: GeoBoundingBox, want: GeoBoundingBox) {
+        assert!(
+            approx_eq!(
+                &[f64],
+                &[got.west(), got.south(), got.east(), got.north()],
+                &[want.west(), want.south(), want.east(), want.north()],
+                epsilon = 1e-12
+            ),
+            "{}: {:?} ±ε = {:?}",
+            name,
+            got,
+            want
+        );
+    }
+
+    #[test]
+    fn contains_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            bounding_box: GeoBoundingBox,
+            point: GeographicPoint,
+            contains: bool,
+        }
+
+        vec![
+            TestCase {
+                name: "point inside a regular box",
+                bounding_box: box_from_degrees(-10., -10., 10., 10.),
+                point: from_degrees(5., 5.),
+                contains: true,
+            },
+            TestCase {
+                name: "point outside a regular box",
+                bounding_box: box_from_degrees(-10., -10., 10., 10.),
+                point: from_degrees(180., 5.),
+                contains: false,
+            },
+            TestCase {
+                name: "point inside a box crossing the antimeridian",
+                bounding_box: box_from_degrees(170., -10., -170., 10.),
+                point: from_degrees(-180., 0.),
+                contains: true,
+            },
+            TestCase {
+                name: "point outside a box crossing the antimeridian",
+                bounding_box: box_from_degrees(170., -10., -170., 10.),
+                point: from_degrees(0., 0.),
+                contains: false,
+            },
+            TestCase {
+                name: "point inside the world",
+                bounding_box: GeoBoundingBox::world(),
+                point: from_degrees(123., -45.),
+                contains: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.bounding_box.contains(&test_case.point),
+                test_case.contains,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn union_and_intersection_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            bounding_box: GeoBoundingBox,
+            other: GeoBoundingBox,
+            union: GeoBoundingBox,
+            intersection: Option<GeoBoundingBox>,
+        }
+
+        vec![
+            TestCase {
+                name: "overlapping boxes",
+                bounding_box: box_from_degrees(0., 0., 20., 20.),
+                other: box_from_degrees(10., 10., 30., 30.),
+                union: box_from_degrees(0., 0., 30., 30.),
+                intersection: Some(box_from_degrees(10., 10., 20., 20.)),
+            },
+            TestCase {
+                name: "disjoint boxes must fill the smallest gap",
+                bounding_box: box_from_degrees(160., 0., 170., 10.),
+                other: box_from_degrees(-170., 0., -160., 10.),
+                union: box_from_degrees(160., 0., -160., 10.),
+                intersection: None,
+            },
+            TestCase {
+                name: "boxes overlapping across the antimeridian",
+                bounding_box: box_from_degrees(170., -10., -170., 10.),
+                other: box_from_degrees(-175., -20., -150., 0.),
+                union: box_from_degrees(170., -20., -150., 10.),
+                intersection: Some(box_from_degrees(-175., -10., -170., 0.)),
+            },
+            TestCase {
+                name: "nested boxes",
+                bounding_box: box_from_degrees(-50., -50., 50., 50.),
+                other: box_from_degrees(-10., -10., 10., 10.),
+                union: box_from_degrees(-50., -50., 50., 50.),
+                intersection: Some(box_from_degrees(-10., -10., 10., 10.)),
+            },
+            TestCase {
+                name: "boxes covering all the meridians together",
+                bounding_box: box_from_degrees(-100., 0., 100., 10.),
+                other: box_from_degrees(90., 0., -90., 10.),
+                union: box_from_degrees(-180., 0., 180., 10.),
+                intersection: Some(box_from_degrees(90., 0., -90., 10.)),
+            },
+            TestCase {
+                name: "boxes with disjoint latitudes",
+                bounding_box: box_from_degrees(0., 0., 10., 10.),
+                other: box_from_degrees(0., 20., 10., 30.),
+                union: box_from_degrees(0., 0., 10., 30.),
+                intersection: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_box_eq(
+                test_case.name,
+                test_case.bounding_box.union(&test_case.other),
+                test_case.union,
+            );
+
+            let intersection = test_case.bounding_box.intersection(&test_case.other);
+            assert_eq!(
+                intersection.is_some(),
+                test_case.intersection.is_some(),
+                "{}: intersection {:?}",
+                test_case.name,
+                intersection
+            );
+
+            if let (Some(got), Some(want)) = (intersection, test_case.intersection) {
+                assert_box_eq(test_case.name, got, want);
+            }
+
+            assert!(
+                test_case.union.contains_box(&test_case.bounding_box)
+                    && test_case.union.contains_box(&test_case.other),
+                "{}: union must contain both boxes",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn expand_must_not_fail() {
+        let bounding_box = box_from_degrees(175., 0., -175., 60.);
+        let expanded = bounding_box.expand(0.1);
+
+        assert!(
+            expanded.contains_box(&bounding_box),
+            "expanded box must contain the original one"
+        );
+
+        assert!(
+            expanded.contains(&from_degrees(175., 60.).destination(-PI / 2., 0.1)),
+            "expanded box must contain points at the given distance: {:?}",
+            expanded
+        );
+
+        assert!(
+            bounding_box.expand(0.6).is_full_longitude(),
+            "expanding beyond a pole must cover all the meridians"
+        );
+    }
+
+    #[test]
+    fn distance_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            bounding_box: GeoBoundingBox,
+            point: GeographicPoint,
+            distance: f64,
+        }
+
+        let bounding_box = box_from_degrees(170., -10., -170., 10.);
+
+        vec![
+            TestCase {
+                name: "point inside",
+                bounding_box,
+                point: from_degrees(180., 5.),
+                distance: 0.,
+            },
+            TestCase {
+                name: "point north of the box",
+                bounding_box,
+                point: from_degrees(-175., 30.),
+                distance: 20_f64.to_radians(),
+            },
+            TestCase {
+                name: "point east of the box on the equator",
+                bounding_box,
+                point: from_degrees(-160., 0.),
+                distance: 10_f64.to_radians(),
+            },
+            TestCase {
+                name: "point closest to a corner",
+                bounding_box,
+                point: from_degrees(160., 20.),
+                distance: from_degrees(160., 20.).distance(&from_degrees(170., 10.)),
+            },
+            TestCase {
+                name: "point east of a box from pole to pole",
+                bounding_box: box_from_degrees(100., -90., 110., 90.),
+                point: from_degrees(150., 0.),
+                distance: 40_f64.to_radians(),
+            },
+            TestCase {
+                name: "point off the equator east of a box from pole to pole",
+                bounding_box: box_from_degrees(100., -90., 110., 90.),
+                point: from_degrees(150., 30.),
+                distance: (30_f64.to_radians().cos() * 40_f64.to_radians().sin()).asin(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let distance = test_case.bounding_box.distance(&test_case.point);
+            assert!(
+                approx_eq!(f64, distance, test_case.distance, epsilon = 1e-12),
+                "{}: distance {} ±ε = {}",
+                test_case.name,
+                distance,
+                test_case.distance
+            );
+        });
+    }
+
+    #[test]
+    fn from_points_must_not_fail() {
+        assert_eq!(GeoBoundingBox::from_points(&[]), None, "no points");
+
+        assert_box_eq(
+            "single point",
+            GeoBoundingBox::from_points(&[from_degrees(10., 20.)]).unwrap(),
+            box_from_degrees(10., 20., 10., 20.),
+        );
+
+        assert_box_eq(
+            "points across the antimeridian",
+            GeoBoundingBox::from_points(&[
+                from_degrees(170., 20.),
+                from_degrees(-175., -5.),
+                from_degrees(179., 0.),
+            ])
+            .unwrap(),
+            box_from_degrees(170., -5., -175., 20.),
+        );
+    }
+
+    #[test]
+    fn from_arc_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            arc: GreatCircleArc,
+            bounding_box: GeoBoundingBox,
+        }
+
+        vec![
+            TestCase {
+                name: "arc along the equator",
+                arc: GreatCircleArc::new(from_degrees(-10., 0.), from_degrees(10., 0.)),
+                bounding_box: box_from_degrees(-10., 0., 10., 0.),
+            },
+            TestCase {
+                name: "arc bulging northwards",
+                arc: GreatCircleArc::new(from_degrees(-45., 45.), from_degrees(45., 45.)),
+                bounding_box: GeoBoundingBox::new(
+                    -PI / 4.,
+                    PI / 4.,
+                    PI / 4.,
+                    (1. / 3_f64.sqrt()).acos(),
+                ),
+            },
+            TestCase {
+                name: "arc bulging southwards across the antimeridian",
+                arc: GreatCircleArc::new(from_degrees(-135., -45.), from_degrees(135., -45.)),
+                bounding_box: GeoBoundingBox::new(
+                    3. * PI / 4.,
+                    -(1. / 3_f64.sqrt()).acos(),
+                    -3. * PI / 4.,
+                    -PI / 4.,
+                ),
+            },
+            TestCase {
+                name: "arc going across the pole",
+                arc: GreatCircleArc::new(from_degrees(0., 80.), from_degrees(180., 80.)),
+                bounding_box: box_from_degrees(-180., 80., 180., 90.),
+            },
+            TestCase {
+                name: "arc ending at the pole",
+                arc: GreatCircleArc::new(from_degrees(30., 10.), from_degrees(0., 90.)),
+                bounding_box: box_from_degrees(30., 10., 30., 90.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_box_eq(
+                test_case.name,
+                GeoBoundingBox::from_arc(&test_case.arc),
+                test_case.bounding_box,
+            );
+        });
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{CartesianPoint, GeoBoundingBox, GeographicPoint, SphericalPolygon};
+use std::f64::consts::{FRAC_PI_2, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Represents a [spherical cap](https://en.wikipedia.org/wiki/Spherical_cap):
+/// the region of the sphere within a given angular distance (in radiants) from
+/// a center point, being its boundary a small circle. All areas are in
+/// steradians.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SphericalCap {
+    center: GeographicPoint,
+    radius: f64,
+}
+
+#[wasm_bindgen]
+impl SphericalCap {
+    /// Returns the cap with the given center and angular radius, which is
+    /// clamped into the range __[0, π]__.
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: GeographicPoint, radius: f64) -> Self {
+        Self {
+            center,
+            radius: radius.clamp(0., PI),
+        }
+    }
+
+    /// Returns the center of the cap.
+    pub fn center(&self) -> GeographicPoint {
+        self.center
+    }
+
+    /// Returns the angular radius (in radiants) of the cap.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Returns the area of the cap, in steradians.
+    pub fn area(&self) -> f64 {
+        2. * PI * (1. - self.radius.cos())
+    }
+
+    /// Returns the area of the cap on a sphere of the given radius, in squared
+    /// units of the radius.
+    pub fn scaled_area(&self, radius: f64) -> f64 {
+        self.area() * radius.powi(2)
+    }
+
+    /// Returns true if, and only if, the given point lies inside the cap or on
+    /// its boundary.
+    pub fn contains(&self, point: &GeographicPoint) -> bool {
+        let center = CartesianPoint::direction_of(&self.center);
+        let point = CartesianPoint::direction_of(point);
+
+        // comparing chords instead of dot products keeps the precision for
+        // small radiuses.
+        center.distance(&point) <= 2. * (self.radius / 2.).sin()
+    }
+
+    /// Returns true if, and only if, the given cap lies entirely inside self.
+    pub fn contains_cap(&self, other: &SphericalCap) -> bool {
+        // the whole sphere contains any cap, even those whose distance to its
+        // center plus their radius exceeds half the circumference.
+        if self.radius >= PI {
+            return true;
+        }
+
+        self.center.distance(&other.center) + other.radius <= self.radius
+    }
+
+    /// Returns true if, and only if, self and the given cap share any point.
+    pub fn intersects(&self, other: &SphericalCap) -> bool {
+        self.center.distance(&other.center) <= self.radius + other.radius
+    }
+
+    /// Returns the smallest [`GeoBoundingBox`] containing the cap.
+    pub fn bounding_box(&self) -> GeoBoundingBox {
+        let south = self.center.latitude() - self.radius;
+        let north = self.center.latitude() + self.radius;
+
+        if south <= -FRAC_PI_2 || north >= FRAC_PI_2 {
+            // the cap contains a pole, and so all the meridians
+            return GeoBoundingBox::new(-PI, south, PI, north);
+        }
+
+        // see: http://janmatuschek.de/LatitudeLongitudeBoundingCoordinates
+        let delta = (self.radius.sin() / self.center.latitude().cos())
+            .min(1.)
+            .asin();
+        GeoBoundingBox::new(
+            self.center.longitude() - delta,
+            south,
+            self.center.longitude() + delta,
+            north,
+        )
+    }
+
+    /// Returns the [`SphericalPolygon`] whose vertices are the given amount of
+    /// points evenly spaced along the boundary of the cap, in counterclockwise
+    /// order.
+    pub fn boundary(&self, vertices: usize) -> SphericalPolygon {
+        SphericalPolygon::new(
+            (0..vertices)
+                .map(|index| {
+                    let bearing = -2. * PI * index as f64 / vertices as f64;
+                    self.center
+                        .with_altitude(0.)
+                        .destination(bearing, self.radius)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn area_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            radius: f64,
+            area: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "empty cap",
+                radius: 0.,
+                area: 0.,
+            },
+            TestCase {
+                name: "hemisphere",
+                radius: FRAC_PI_2,
+                area: 2. * PI,
+            },
+            TestCase {
+                name: "whole sphere",
+                radius: PI,
+                area: 4. * PI,
+            },
+            TestCase {
+                name: "overflowing radius must be the whole sphere",
+                radius: 2. * PI,
+                area: 4. * PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let cap = SphericalCap::new(GeographicPoint::default(), test_case.radius);
+            assert!(
+                approx_eq!(f64, cap.area(), test_case.area, epsilon = 1e-15),
+                "{}: area {} ±ε = {}",
+                test_case.name,
+                cap.area(),
+                test_case.area
+            );
+        });
+    }
+
+    #[test]
+    fn contains_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            cap: SphericalCap,
+            point: GeographicPoint,
+            contains: bool,
+        }
+
+        vec![
+            TestCase {
+                name: "center of the cap",
+                cap: SphericalCap::new(from_degrees(10., 20.), 0.),
+                point: from_degrees(10., 20.),
+                contains: true,
+            },
+            TestCase {
+                name: "point inside a tiny cap",
+                cap: SphericalCap::new(from_degrees(10., 20.), 1e-9),
+                point: from_degrees(10., 20.).destination(1., 0.9e-9),
+                contains: true,
+            },
+            TestCase {
+                name: "point outside a tiny cap",
+                cap: SphericalCap::new(from_degrees(10., 20.), 1e-9),
+                point: from_degrees(10., 20.).destination(1., 1.1e-9),
+                contains: false,
+            },
+            TestCase {
+                name: "point across the antimeridian",
+                cap: SphericalCap::new(from_degrees(179., 0.), 0.1),
+                point: from_degrees(-179., 0.),
+                contains: true,
+            },
+            TestCase {
+                name: "antipode of the center",
+                cap: SphericalCap::new(from_degrees(0., 0.), PI - 0.1),
+                point: from_degrees(180., 0.),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.cap.contains(&test_case.point),
+                test_case.contains,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn cap_relations_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            cap: SphericalCap,
+            other: SphericalCap,
+            contains: bool,
+            intersects: bool,
+        }
+
+        let cap = SphericalCap::new(from_degrees(0., 0.), 0.5);
+
+        vec![
+            TestCase {
+                name: "inner cap",
+                cap,
+                other: SphericalCap::new(from_degrees(0., 5.), 0.2),
+                contains: true,
+                intersects: true,
+            },
+            TestCase {
+                name: "overlapping cap",
+                cap,
+                other: SphericalCap::new(from_degrees(0., 30.), 0.2),
+                contains: false,
+                intersects: true,
+            },
+            TestCase {
+                name: "disjoint cap",
+                cap,
+                other: SphericalCap::new(from_degrees(180., 0.), 0.2),
+                contains: false,
+                intersects: false,
+            },
+            TestCase {
+                name: "whole sphere",
+                cap: SphericalCap::new(from_degrees(0., 0.), PI),
+                other: SphericalCap::new(from_degrees(180., 0.), 0.2),
+                contains: true,
+                intersects: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.cap.contains_cap(&test_case.other),
+                test_case.contains,
+                "{}: contains",
+                test_case.name
+            );
+
+            assert_eq!(
+                test_case.cap.intersects(&test_case.other),
+                test_case.intersects,
+                "{}: intersects",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn bounding_box_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            cap: SphericalCap,
+            bounding_box: GeoBoundingBox,
+        }
+
+        vec![
+            TestCase {
+                name: "cap on the equator",
+                cap: SphericalCap::new(from_degrees(0., 0.), 0.1),
+                bounding_box: GeoBoundingBox::new(-0.1, -0.1, 0.1, 0.1),
+            },
+            TestCase {
+                name: "cap across the antimeridian",
+                cap: SphericalCap::new(from_degrees(180., 0.), 0.1),
+                bounding_box: GeoBoundingBox::new(PI - 0.1, -0.1, -PI + 0.1, 0.1),
+            },
+            TestCase {
+                name: "cap containing the north pole",
+                cap: SphericalCap::new(from_degrees(30., 80.), 0.2),
+                bounding_box: GeoBoundingBox::new(-PI, 80_f64.to_radians() - 0.2, PI, FRAC_PI_2),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let bounding_box = test_case.cap.bounding_box();
+            assert!(
+                approx_eq!(
+                    &[f64],
+                    &[
+                        bounding_box.west(),
+                        bounding_box.south(),
+                        bounding_box.east(),
+                        bounding_box.north()
+                    ],
+                    &[
+                        test_case.bounding_box.west(),
+                        test_case.bounding_box.south(),
+                        test_case.bounding_box.east(),
+                        test_case.bounding_box.north()
+                    ],
+                    epsilon = 1e-15
+                ),
+                "{}: {:?} ±ε = {:?}",
+                test_case.name,
+                bounding_box,
+                test_case.bounding_box
+            );
+        });
+    }
+
+    #[test]
+    fn boundary_must_not_fail() {
+        let cap = SphericalCap::new(from_degrees(-30., 45.), 0.3);
+        let boundary = cap.boundary(64);
+
+        assert_eq!(boundary.exterior().len(), 64, "amount of vertices");
+        boundary.exterior().iter().for_each(|vertex| {
+            assert!(
+                approx_eq!(f64, cap.center().distance(vertex), 0.3, epsilon = 1e-12),
+                "vertex {:?} must lay on the boundary",
+                vertex
+            );
+        });
+
+        assert!(
+            boundary.contains(&cap.center()),
+            "boundary must contain the center"
+        );
+
+        assert!(
+            (boundary.area() - cap.area()).abs() / cap.area() < 0.01,
+            "boundary area {} must approximate the cap one {}",
+            boundary.area(),
+            cap.area()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::GeographicPoint;
+use crate::{Ellipsoid, GeographicPoint};
 use nalgebra::{Matrix3, Vector3};
 use std::{
     f64::consts::{FRAC_PI_2, PI},
@@ -37,6 +37,20 @@ impl IndexMut<usize> for CartesianPoint {
     }
 }
 
+impl CartesianPoint {
+    /// Returns the point on the unit sphere in the direction of the given
+    /// [`GeographicPoint`], no matter its altitude.
+    pub(crate) fn direction_of(point: &GeographicPoint) -> Self {
+        Self::from_geographic(&point.with_altitude(0.))
+    }
+
+    /// Returns the point resulting from adding the given one, scaled by the
+    /// given factor, to self.
+    pub(crate) fn add_scaled(&self, other: &CartesianPoint, factor: f64) -> Self {
+        Self(self.0 + other.0 * factor)
+    }
+}
+
 #[wasm_bindgen]
 impl CartesianPoint {
     #[wasm_bindgen(constructor)]
@@ -45,10 +59,16 @@ impl CartesianPoint {
     }
 
     /// Returns the equivalent [`CartesianPoint`] of the given [`GeographicPoint`]
+    /// on a sphere, taking the altitude of the point as its radial distance. A
+    /// zero altitude is assumed to be a point on the unit sphere.
+    ///
+    /// For geodetic coordinates on a reference ellipsoid see
+    /// [`CartesianPoint::from_geodetic`].
     pub fn from_geographic(point: &GeographicPoint) -> Self {
-        let radial_distance = match point.altitude() {
-            altitude if altitude == 0. => 1.,
-            altitude => altitude,
+        let radial_distance = if point.altitude() == 0. {
+            1.
+        } else {
+            point.altitude()
         };
 
         let theta = FRAC_PI_2 - point.latitude();
@@ -77,6 +97,25 @@ impl CartesianPoint {
         ))
     }
 
+    /// Returns the equivalent [`CartesianPoint`] of the given [`GeographicPoint`]
+    /// in Earth-centered, Earth-fixed (ECEF) coordinates, in meters. The
+    /// altitude of the point is taken as its height above the given
+    /// [`Ellipsoid`], in meters.
+    pub fn from_geodetic(point: &GeographicPoint, ellipsoid: &Ellipsoid) -> Self {
+        // see: https://en.wikipedia.org/wiki/Geographic_coordinate_conversion
+        let (lat_sin, lat_cos) = point.latitude().sin_cos();
+        let (long_sin, long_cos) = point.longitude().sin_cos();
+
+        let n = ellipsoid.prime_vertical_radius(point.latitude());
+        let e2 = ellipsoid.eccentricity_squared();
+
+        Self(Vector3::new(
+            (n + point.altitude()) * lat_cos * long_cos,
+            (n + point.altitude()) * lat_cos * long_sin,
+            (n * (1. - e2) + point.altitude()) * lat_sin,
+        ))
+    }
+
     #[inline(always)]
     pub fn x(&self) -> f64 {
         self[0]
@@ -120,6 +159,48 @@ impl CartesianPoint {
         self.0.cross(&other.0).into()
     }
 
+    /// Performs the dot product between self and the given point.
+    pub fn dot(&self, other: &CartesianPoint) -> f64 {
+        self.0.dot(&other.0)
+    }
+
+    /// Returns the distance between self and the origin of coordinates.
+    pub fn magnitude(&self) -> f64 {
+        self.0.norm()
+    }
+
+    /// Returns the point in the same direction as self at a distance of one from
+    /// the origin of coordinates. If self is the origin, it is returned as is.
+    pub fn normalize(&self) -> Self {
+        self.0.try_normalize(0.).map(Self).unwrap_or(*self)
+    }
+
+    /// Returns the spherical linear interpolation between the directions of self
+    /// and the given point, as a point on the unit sphere. The fraction `t` is
+    /// the ratio of the arc between both directions to travel from self.
+    ///
+    /// Since there are infinitely many arcs between two opposite directions, in
+    /// such a case the arc passing through the north pole (z axis) is taken. If
+    /// both directions lay on the z axis, then it passes through the x axis.
+    pub fn slerp(&self, other: &CartesianPoint, t: f64) -> Self {
+        let from = self.normalize();
+        let to = other.normalize();
+
+        let omega = from.cross(&to).magnitude().atan2(from.dot(&to));
+        if omega == 0. {
+            return from;
+        }
+
+        // unit vector orthogonal to the start direction, towards the end one.
+        let tangent = (to.0 - from.0 * from.dot(&to))
+            .try_normalize(f64::EPSILON)
+            .or_else(|| (Vector3::z() - from.0 * from.z()).try_normalize(f64::EPSILON))
+            .unwrap_or_else(Vector3::x);
+
+        let (sin, cos) = (t * omega).sin_cos();
+        Self(from.0 * cos + tangent * sin)
+    }
+
     /// Rotates self in theta radians about the edge passing by the origin and the given axis point.
     pub fn rotate(&mut self, axis: Self, theta: f64) {
         if self.0.normalize() == axis.0.normalize() {
@@ -171,6 +252,7 @@ impl CartesianPoint {
 mod tests {
     use super::*;
     use float_cmp::approx_eq;
+    use std::f64::consts::FRAC_PI_4;
 
     const ULPS: i64 = 2;
 
@@ -319,4 +401,71 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn slerp_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            from: CartesianPoint,
+            to: CartesianPoint,
+            t: f64,
+            want: CartesianPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "zero fraction must return the start direction",
+                from: CartesianPoint::new(2., 0., 0.),
+                to: CartesianPoint::new(0., 1., 0.),
+                t: 0.,
+                want: CartesianPoint::new(1., 0., 0.),
+            },
+            TestCase {
+                name: "full fraction must return the end direction",
+                from: CartesianPoint::new(1., 0., 0.),
+                to: CartesianPoint::new(0., 3., 0.),
+                t: 1.,
+                want: CartesianPoint::new(0., 1., 0.),
+            },
+            TestCase {
+                name: "half fraction must bisect the arc",
+                from: CartesianPoint::new(1., 0., 0.),
+                to: CartesianPoint::new(0., 0., 1.),
+                t: 0.5,
+                want: CartesianPoint::new(FRAC_PI_4.cos(), 0., FRAC_PI_4.sin()),
+            },
+            TestCase {
+                name: "same direction must not move",
+                from: CartesianPoint::new(0., 1., 0.),
+                to: CartesianPoint::new(0., 1., 0.),
+                t: 0.5,
+                want: CartesianPoint::new(0., 1., 0.),
+            },
+            TestCase {
+                name: "opposite directions must pass through the north pole",
+                from: CartesianPoint::new(0., 1., 0.),
+                to: CartesianPoint::new(0., -1., 0.),
+                t: 0.5,
+                want: CartesianPoint::new(0., 0., 1.),
+            },
+            TestCase {
+                name: "opposite poles must pass through the x axis",
+                from: CartesianPoint::new(0., 0., -1.),
+                to: CartesianPoint::new(0., 0., 1.),
+                t: 0.5,
+                want: CartesianPoint::new(1., 0., 0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = test_case.from.slerp(&test_case.to, test_case.t);
+            assert!(
+                point.distance(&test_case.want) < 1e-15,
+                "{}: {} ±ε = {}",
+                test_case.name,
+                point.0,
+                test_case.want.0
+            );
+        });
+    }
 }
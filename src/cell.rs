@@ -0,0 +1,798 @@
+use crate::{CartesianPoint, GeographicPoint, GreatCircleArc, SphericalCap, SphericalPolygon};
+use std::f64::consts::PI;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The level of the smallest cells, which are the leaves of the hierarchy.
+pub const MAX_CELL_LEVEL: u8 = 30;
+
+/// The amount of bits of a cell id taken by the face.
+const FACE_BITS: u32 = 3;
+/// The amount of bits of a cell id taken by the position along the curve.
+const POSITION_BITS: u32 = u64::BITS - FACE_BITS;
+/// The amount of leaf cells along each side of a face.
+const MAX_SIZE: i64 = 1 << MAX_CELL_LEVEL;
+
+/// Mask of the orientation bit telling if the i and j axis are swapped.
+const SWAP_MASK: usize = 1;
+/// Mask of the orientation bit telling if the i and j axis are inverted.
+const INVERT_MASK: usize = 2;
+
+/// The (i, j) quadrant, as `i << 1 | j`, at each position of the curve for each
+/// orientation.
+const POSITION_TO_IJ: [[usize; 4]; 4] = [[0, 1, 3, 2], [0, 2, 3, 1], [3, 2, 0, 1], [3, 1, 0, 2]];
+/// The position of the curve at each (i, j) quadrant, as `i << 1 | j`, for each
+/// orientation.
+const IJ_TO_POSITION: [[usize; 4]; 4] = [[0, 1, 3, 2], [0, 3, 1, 2], [2, 3, 1, 0], [2, 1, 3, 0]];
+/// The change of orientation of the curve when descending into each position.
+const POSITION_TO_ORIENTATION: [usize; 4] = [SWAP_MASK, 0, 0, SWAP_MASK | INVERT_MASK];
+
+/// Represents a cell of a hierarchical grid over the sphere, in the fashion of
+/// [S2](http://s2geometry.io/devguide/s2cell_hierarchy).
+///
+/// The sphere is projected onto the six faces of a cube, each of them being
+/// recursively split into four cells up to [`MAX_CELL_LEVEL`] times. Cells are
+/// ordered along a Hilbert curve, which makes cells close in space to have
+/// close ids too. The id of a cell packs the face in the 3 most significant
+/// bits, followed by two bits per level telling the position of the cell along
+/// the curve, and a trailing bit set to one.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CellId(u64);
+
+impl Default for CellId {
+    /// Returns the cell covering the whole face 0.
+    fn default() -> Self {
+        Self(1 << (POSITION_BITS - 1))
+    }
+}
+
+#[wasm_bindgen]
+impl CellId {
+    /// Returns the cell with the given id, if valid.
+    pub fn from_raw(id: u64) -> Option<CellId> {
+        let cell = Self(id);
+        cell.is_valid().then_some(cell)
+    }
+
+    /// Returns the cell covering the whole given face of the cube, being faces 0
+    /// to 2 the ones pointing to the positive x, y and z axis, and faces 3 to 5
+    /// the ones pointing to the negative ones.
+    pub fn from_face(face: u8) -> Option<CellId> {
+        (face < 6).then_some(Self(
+            (face as u64) << POSITION_BITS | 1 << (POSITION_BITS - 1),
+        ))
+    }
+
+    /// Returns the cell of the given level containing the direction of the
+    /// given [`CartesianPoint`].
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{CartesianPoint, CellId};
+    ///
+    /// let point = CartesianPoint::new(1., 0., 0.);
+    /// assert_eq!(CellId::from_cartesian(&point, 30).id(), 0x1000000000000001);
+    /// ```
+    pub fn from_cartesian(point: &CartesianPoint, level: u8) -> CellId {
+        let face = face_of(point);
+        let (u, v) = face_uv(face, point);
+        Self::from_face_ij(face, st_to_ij(uv_to_st(u)), st_to_ij(uv_to_st(v)))
+            .ancestor(level.min(MAX_CELL_LEVEL))
+    }
+
+    /// Returns the cell of the given level containing the given
+    /// [`GeographicPoint`], no matter its altitude.
+    pub fn from_geographic(point: &GeographicPoint, level: u8) -> CellId {
+        Self::from_cartesian(&CartesianPoint::direction_of(point), level)
+    }
+
+    /// Returns the cells, of at most the given level, covering the given
+    /// [`SphericalCap`]. Cells entirely inside the cap are not split any
+    /// further, nor are those whose split would exceed the given amount of
+    /// cells.
+    pub fn cover_cap(cap: &SphericalCap, max_level: u8, max_cells: usize) -> Vec<CellId> {
+        cover(cap, max_level, max_cells)
+    }
+
+    /// Returns the cells, of at most the given level, covering the given
+    /// [`SphericalPolygon`]. Cells entirely inside the polygon are not split any
+    /// further, nor are those whose split would exceed the given amount of
+    /// cells.
+    pub fn cover_polygon(
+        polygon: &SphericalPolygon,
+        max_level: u8,
+        max_cells: usize,
+    ) -> Vec<CellId> {
+        cover(polygon, max_level, max_cells)
+    }
+
+    /// Returns the 64-bit id of the cell.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if, and only if, the id of the cell is a valid one.
+    pub fn is_valid(&self) -> bool {
+        // the trailing bit must be at an even position of the curve bits
+        self.face() < 6 && self.lowest_bit() & 0x1555555555555555 != 0
+    }
+
+    /// Returns the face of the cube the cell belongs to.
+    pub fn face(&self) -> u8 {
+        (self.0 >> POSITION_BITS) as u8
+    }
+
+    /// Returns the level of the cell, being 0 the level of the faces.
+    pub fn level(&self) -> u8 {
+        // invalid ids may have no bit set at all
+        MAX_CELL_LEVEL.saturating_sub((self.0.trailing_zeros() / 2) as u8)
+    }
+
+    /// Returns true if, and only if, the cell is of the maximum level.
+    pub fn is_leaf(&self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    /// Returns the cell containing self one level above, if any.
+    pub fn parent(&self) -> Option<CellId> {
+        (self.level() > 0).then(|| self.ancestor(self.level() - 1))
+    }
+
+    /// Returns the cell of the given level containing self, which is self if
+    /// the given level is not above the one of the cell.
+    pub fn ancestor(&self, level: u8) -> CellId {
+        if level >= self.level() {
+            return *self;
+        }
+
+        let lowest_bit = lowest_bit_of(level);
+        Self(self.0 & lowest_bit.wrapping_neg() | lowest_bit)
+    }
+
+    /// Returns the four cells one level below self, in the order of the curve,
+    /// if any.
+    pub fn children(&self) -> Vec<CellId> {
+        if self.is_leaf() {
+            return Vec::new();
+        }
+
+        let lowest_bit = self.lowest_bit() >> 2;
+        let first = self.0 - self.lowest_bit() + lowest_bit;
+        (0..4)
+            .map(|index| Self(first + index * (lowest_bit << 1)))
+            .collect()
+    }
+
+    /// Returns true if, and only if, the given cell is self or any of its
+    /// descendants.
+    pub fn contains(&self, other: &CellId) -> bool {
+        let extent = self.lowest_bit().saturating_sub(1);
+        (self.0.saturating_sub(extent)..=self.0.saturating_add(extent)).contains(&other.0)
+    }
+
+    /// Returns true if, and only if, any of both cells contains the other.
+    pub fn intersects(&self, other: &CellId) -> bool {
+        self.contains(other) || other.contains(self)
+    }
+
+    /// Returns true if, and only if, the given point lies inside the cell. Any
+    /// point on the boundary between two cells belongs to just one of them.
+    pub fn contains_point(&self, point: &GeographicPoint) -> bool {
+        self.contains(&Self::from_geographic(point, MAX_CELL_LEVEL))
+    }
+
+    /// Returns the center of the cell.
+    pub fn center(&self) -> GeographicPoint {
+        let (i, j) = self.origin();
+        let half = self.size() as f64 / 2.;
+        self.point_at(i as f64 + half, j as f64 + half)
+    }
+
+    /// Returns the vertices of the cell in counterclockwise order, starting from
+    /// the one with the lowest coordinates on its face. Edges between vertices
+    /// are arcs of great circles.
+    pub fn vertices(&self) -> Vec<GeographicPoint> {
+        let (i, j) = self.origin();
+        let size = self.size();
+
+        [(i, j), (i + size, j), (i + size, j + size), (i, j + size)]
+            .into_iter()
+            .map(|(i, j)| self.point_at(i as f64, j as f64))
+            .collect()
+    }
+
+    /// Returns the [`SphericalPolygon`] bounded by the cell.
+    pub fn polygon(&self) -> SphericalPolygon {
+        SphericalPolygon::new(self.vertices())
+    }
+
+    /// Returns the four cells of the same level sharing an edge with self, in
+    /// the same order as the edges starting from the first vertex.
+    pub fn edge_neighbours(&self) -> Vec<CellId> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(|(columns, rows)| self.neighbour(columns, rows))
+            .collect()
+    }
+
+    /// Returns all the cells of the same level sharing an edge or a vertex with
+    /// self. These are eight cells, except for those cells touching a corner of
+    /// the cube, which have seven instead.
+    pub fn neighbours(&self) -> Vec<CellId> {
+        (-1..=1)
+            .flat_map(|rows| (-1..=1).map(move |columns| (columns, rows)))
+            .map(|(columns, rows)| self.neighbour(columns, rows))
+            .fold(Vec::new(), |mut neighbours, neighbour| {
+                if neighbour != *self && !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+
+                neighbours
+            })
+    }
+}
+
+impl CellId {
+    /// Returns the leaf cell at the given coordinates of the given face.
+    fn from_face_ij(face: usize, i: i64, j: i64) -> Self {
+        let mut orientation = face & SWAP_MASK;
+        let position = (0..MAX_CELL_LEVEL).rev().fold(0, |position, bit| {
+            let ij = ((i >> bit & 1) << 1 | (j >> bit & 1)) as usize;
+            let quadrant = IJ_TO_POSITION[orientation][ij];
+            orientation ^= POSITION_TO_ORIENTATION[quadrant];
+            position << 2 | quadrant as u64
+        });
+
+        Self((face as u64) << POSITION_BITS | position << 1 | 1)
+    }
+
+    /// Returns the leaf cell at the given coordinates of the given face, which
+    /// may lay beyond the boundaries of the face, and so wrap onto the
+    /// adjacent one.
+    fn from_face_ij_wrap(face: usize, i: i64, j: i64) -> Self {
+        if (0..MAX_SIZE).contains(&i) && (0..MAX_SIZE).contains(&j) {
+            return Self::from_face_ij(face, i, j);
+        }
+
+        // Any projection works for moving into the adjacent face, since all of
+        // them map the edges of the cube the same way. The linear one is the
+        // simplest, but the point must be kept barely outside the face for its
+        // coordinates to not change once projected.
+        let limit = 1. + f64::EPSILON;
+        let linear = |coordinate: i64| {
+            let coordinate = coordinate.clamp(-1, MAX_SIZE);
+            ((2 * (coordinate - MAX_SIZE / 2) + 1) as f64 / MAX_SIZE as f64).clamp(-limit, limit)
+        };
+
+        let point = face_uv_to_xyz(face, linear(i), linear(j));
+        let face = face_of(&point);
+        let (u, v) = face_uv(face, &point);
+        Self::from_face_ij(face, st_to_ij((u + 1.) / 2.), st_to_ij((v + 1.) / 2.))
+    }
+
+    /// Returns the lowest bit set of the id, which is zero if no bit is set.
+    fn lowest_bit(&self) -> u64 {
+        self.0 & self.0.wrapping_neg()
+    }
+
+    /// Returns the amount of leaf cells along each side of the cell.
+    fn size(&self) -> i64 {
+        1 << (MAX_CELL_LEVEL - self.level())
+    }
+
+    /// Returns the coordinates of the leaf cell at the lowest corner of self.
+    fn origin(&self) -> (i64, i64) {
+        let mut orientation = self.face() as usize & SWAP_MASK;
+        let (i, j) = (0..MAX_CELL_LEVEL).rev().fold((0, 0), |(i, j), bit| {
+            let quadrant = (self.0 >> (2 * bit + 1) & 3) as usize;
+            let ij = POSITION_TO_IJ[orientation][quadrant] as i64;
+            orientation ^= POSITION_TO_ORIENTATION[quadrant];
+            (i | (ij >> 1) << bit, j | (ij & 1) << bit)
+        });
+
+        let mask = !(self.size() - 1);
+        (i & mask, j & mask)
+    }
+
+    /// Returns the point at the given coordinates of the face of the cell.
+    fn point_at(&self, i: f64, j: f64) -> GeographicPoint {
+        let u = st_to_uv(i / MAX_SIZE as f64);
+        let v = st_to_uv(j / MAX_SIZE as f64);
+
+        GeographicPoint::from_cartesian(&face_uv_to_xyz(self.face() as usize, u, v).normalize())
+            .with_altitude(0.)
+    }
+
+    /// Returns the cell of the same level displaced the given amount of cells
+    /// along each axis of the face.
+    fn neighbour(&self, columns: i64, rows: i64) -> Self {
+        let (i, j) = self.origin();
+        let size = self.size();
+
+        Self::from_face_ij_wrap(self.face() as usize, i + columns * size, j + rows * size)
+            .ancestor(self.level())
+    }
+
+    /// Returns the edges of the cell.
+    fn edges(&self) -> Vec<GreatCircleArc> {
+        let vertices = self.vertices();
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(from, to)| GreatCircleArc::new(*from, *to))
+            .collect()
+    }
+}
+
+/// Returns the lowest bit set of the ids of the given level.
+fn lowest_bit_of(level: u8) -> u64 {
+    1 << (2 * (MAX_CELL_LEVEL - level) as u64)
+}
+
+/// Returns the face of the cube the given point is projected onto.
+fn face_of(point: &CartesianPoint) -> usize {
+    let axis = (0..3)
+        .max_by(|&a, &b| point[a].abs().total_cmp(&point[b].abs()))
+        .unwrap_or_default();
+
+    if point[axis] < 0. {
+        axis + 3
+    } else {
+        axis
+    }
+}
+
+/// Returns the coordinates, in the range __[-1, 1]__, of the projection of the
+/// given point onto the given face.
+fn face_uv(face: usize, point: &CartesianPoint) -> (f64, f64) {
+    let (x, y, z) = (point.x(), point.y(), point.z());
+    match face {
+        0 => (y / x, z / x),
+        1 => (-x / y, z / y),
+        2 => (-x / z, -y / z),
+        3 => (z / x, y / x),
+        4 => (z / y, -x / y),
+        _ => (-y / z, -x / z),
+    }
+}
+
+/// Returns the point at the given coordinates of the given face.
+fn face_uv_to_xyz(face: usize, u: f64, v: f64) -> CartesianPoint {
+    match face {
+        0 => CartesianPoint::new(1., u, v),
+        1 => CartesianPoint::new(-u, 1., v),
+        2 => CartesianPoint::new(-u, -v, 1.),
+        3 => CartesianPoint::new(-1., -v, -u),
+        4 => CartesianPoint::new(v, -1., -u),
+        _ => CartesianPoint::new(v, u, -1.),
+    }
+}
+
+/// Returns the coordinate in the range __[0, 1]__ of the given one in the range
+/// __[-1, 1]__, through the quadratic transformation that keeps cells of the
+/// same level of a similar area.
+fn uv_to_st(u: f64) -> f64 {
+    if u >= 0. {
+        0.5 * (1. + 3. * u).sqrt()
+    } else {
+        1. - 0.5 * (1. - 3. * u).sqrt()
+    }
+}
+
+/// Returns the inverse of [`uv_to_st`].
+fn st_to_uv(s: f64) -> f64 {
+    if s >= 0.5 {
+        (4. * s * s - 1.) / 3.
+    } else {
+        (1. - 4. * (1. - s) * (1. - s)) / 3.
+    }
+}
+
+/// Returns the coordinate of the leaf cell containing the given one.
+fn st_to_ij(s: f64) -> i64 {
+    ((s * MAX_SIZE as f64).floor() as i64).clamp(0, MAX_SIZE - 1)
+}
+
+/// A region of the sphere which can be covered by cells.
+trait Region {
+    /// Returns true if, and only if, the given cell lies entirely inside the
+    /// region. False negatives are allowed, at the cost of larger coverings.
+    fn contains_cell(&self, cell: &CellId) -> bool;
+
+    /// Returns true if, and only if, the given cell and the region have any
+    /// point in common. False positives are allowed, at the cost of larger
+    /// coverings.
+    fn intersects_cell(&self, cell: &CellId) -> bool;
+}
+
+impl Region for SphericalCap {
+    fn contains_cell(&self, cell: &CellId) -> bool {
+        // the cell is inside the cap if it does not intersect its complement
+        let complement = GeographicPoint::default()
+            .with_longitude(self.center().longitude() + PI)
+            .with_latitude(-self.center().latitude());
+
+        !cell.contains_point(&complement)
+            && cell
+                .edges()
+                .iter()
+                .all(|edge| edge.distance(&complement) > PI - self.radius())
+    }
+
+    fn intersects_cell(&self, cell: &CellId) -> bool {
+        cell.contains_point(&self.center())
+            || cell
+                .edges()
+                .iter()
+                .any(|edge| edge.distance(&self.center()) <= self.radius())
+    }
+}
+
+impl Region for SphericalPolygon {
+    fn contains_cell(&self, cell: &CellId) -> bool {
+        cell.vertices().iter().all(|vertex| self.contains(vertex))
+            && !self
+                .rings()
+                .flatten()
+                .any(|vertex| cell.contains_point(vertex))
+            && !crosses(self, cell)
+    }
+
+    fn intersects_cell(&self, cell: &CellId) -> bool {
+        cell.vertices().iter().any(|vertex| self.contains(vertex))
+            || self
+                .rings()
+                .flatten()
+                .any(|vertex| cell.contains_point(vertex))
+            || crosses(self, cell)
+    }
+}
+
+/// Returns true if, and only if, any edge of the given polygon intersects any
+/// edge of the given cell.
+fn crosses(polygon: &SphericalPolygon, cell: &CellId) -> bool {
+    let edges = cell.edges();
+    polygon
+        .rings()
+        .flat_map(|ring| ring.iter().zip(ring.iter().cycle().skip(1)))
+        .map(|(from, to)| GreatCircleArc::new(*from, *to))
+        .any(|polygon_edge| {
+            edges
+                .iter()
+                .any(|edge| !edge.intersections(&polygon_edge).is_empty())
+        })
+}
+
+/// Returns the cells, of at most the given level, covering the given region.
+///
+/// Cells are split level by level, so all of them get equally refined, as long
+/// as the covering does not exceed the given amount of cells. Otherwise, the
+/// cells left are kept as they are. The covering may only have more cells than
+/// that if the region intersects more faces of the cube.
+fn cover(region: &impl Region, max_level: u8, max_cells: usize) -> Vec<CellId> {
+    let max_level = max_level.min(MAX_CELL_LEVEL);
+    let mut candidates: Vec<CellId> = (0..6)
+        .filter_map(CellId::from_face)
+        .filter(|face| region.intersects_cell(face))
+        .collect();
+
+    let mut covering = Vec::new();
+    while !candidates.is_empty() {
+        let (done, pending): (Vec<CellId>, Vec<CellId>) = candidates
+            .into_iter()
+            .partition(|cell| cell.level() >= max_level || region.contains_cell(cell));
+
+        covering.extend(done);
+        candidates = Vec::new();
+        for (index, cell) in pending.iter().enumerate() {
+            let children: Vec<CellId> = cell
+                .children()
+                .into_iter()
+                .filter(|child| region.intersects_cell(child))
+                .collect();
+
+            // every pending cell takes at least one cell of the covering
+            let total =
+                covering.len() + candidates.len() + children.len() + pending.len() - index - 1;
+            if total > max_cells {
+                covering.push(*cell);
+            } else {
+                candidates.extend(children);
+            }
+        }
+    }
+
+    covering.sort();
+    covering
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn from_geographic_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            level: u8,
+            id: u64,
+        }
+
+        vec![
+            TestCase {
+                name: "origin of coordinates",
+                point: from_degrees(0., 0.),
+                level: 30,
+                id: 0x1000000000000001,
+            },
+            TestCase {
+                name: "north pole",
+                point: from_degrees(0., 90.),
+                level: 0,
+                id: 0x5000000000000000,
+            },
+            TestCase {
+                name: "south pole",
+                point: from_degrees(0., -90.),
+                level: 0,
+                id: 0xb000000000000000,
+            },
+            TestCase {
+                name: "first level",
+                point: from_degrees(10., 10.),
+                level: 1,
+                id: 0x1400000000000000,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let cell = CellId::from_geographic(&test_case.point, test_case.level);
+            assert_eq!(
+                cell.id(),
+                test_case.id,
+                "{}: got {:#x}",
+                test_case.name,
+                cell.id()
+            );
+
+            assert_eq!(cell.level(), test_case.level, "{}: level", test_case.name);
+            assert!(cell.is_valid(), "{}: must be valid", test_case.name);
+            assert!(
+                cell.contains_point(&test_case.point),
+                "{}: must contain the point",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn hierarchy_must_not_fail() {
+        let leaf = CellId::from_geographic(&from_degrees(-3.7, 40.4), MAX_CELL_LEVEL);
+        assert!(leaf.is_leaf(), "must be a leaf");
+        assert!(leaf.children().is_empty(), "leaves must have no children");
+        assert!(
+            leaf.center().distance(&from_degrees(-3.7, 40.4)) < 1e-8,
+            "center of a leaf must be close to the point"
+        );
+
+        (0..MAX_CELL_LEVEL).for_each(|level| {
+            let cell = leaf.ancestor(level);
+            assert_eq!(cell.level(), level, "ancestor level");
+            assert!(cell.contains(&leaf), "ancestor of level {level}");
+            assert_eq!(
+                cell.children()[0].parent(),
+                Some(cell),
+                "parent of children of level {level}"
+            );
+
+            let children = cell.children();
+            assert_eq!(
+                children
+                    .iter()
+                    .filter(|child| child.contains(&leaf))
+                    .count(),
+                1,
+                "only one child of level {level} must contain the leaf"
+            );
+
+            let area: f64 = children.iter().map(|child| child.polygon().area()).sum();
+            assert!(
+                approx_eq!(f64, area, cell.polygon().area(), epsilon = 1e-9),
+                "area of children of level {level}: {area} ±ε = {}",
+                cell.polygon().area()
+            );
+        });
+
+        assert_eq!(CellId::from_face(0).unwrap().parent(), None, "faces");
+        assert_eq!(CellId::from_face(6), None, "face out of range");
+        assert_eq!(CellId::from_raw(0), None, "zero id");
+        assert_eq!(CellId::default(), CellId::from_face(0).unwrap(), "default");
+        assert!(CellId::default().is_valid(), "default must be valid");
+
+        let invalid = CellId(0);
+        assert_eq!(invalid.level(), 0, "level of the zero id");
+        assert!(!invalid.contains(&leaf), "zero id must contain no leaf");
+        assert!(!leaf.contains(&invalid), "no leaf must contain the zero id");
+        assert_eq!(
+            CellId::from_raw(0x1000000000000002),
+            None,
+            "trailing bit in odd position"
+        );
+    }
+
+    #[test]
+    fn polygon_must_not_fail() {
+        let area: f64 = (0..6)
+            .filter_map(CellId::from_face)
+            .map(|face| face.polygon().area())
+            .sum();
+
+        assert!(
+            approx_eq!(f64, area, 4. * PI, epsilon = 1e-9),
+            "faces must cover the sphere: {area}"
+        );
+    }
+
+    #[test]
+    fn neighbours_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            cell: CellId,
+            neighbours: usize,
+        }
+
+        vec![
+            TestCase {
+                name: "face",
+                cell: CellId::from_face(2).unwrap(),
+                neighbours: 4,
+            },
+            TestCase {
+                name: "cell in the middle of a face",
+                cell: CellId::from_geographic(&from_degrees(10., 10.), 8),
+                neighbours: 8,
+            },
+            TestCase {
+                name: "cell on an edge of the cube",
+                cell: CellId::from_geographic(&from_degrees(44.99, 0.), 5),
+                neighbours: 8,
+            },
+            TestCase {
+                name: "cell on a corner of the cube",
+                cell: CellId::from_face(0).unwrap().children()[0],
+                neighbours: 7,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let neighbours = test_case.cell.neighbours();
+            assert_eq!(
+                neighbours.len(),
+                test_case.neighbours,
+                "{}: {:?}",
+                test_case.name,
+                neighbours
+            );
+
+            test_case
+                .cell
+                .edge_neighbours()
+                .iter()
+                .for_each(|neighbour| {
+                    assert_eq!(neighbour.level(), test_case.cell.level());
+                    assert!(
+                        neighbours.contains(neighbour),
+                        "{}: edge neighbour {:?} must be a neighbour",
+                        test_case.name,
+                        neighbour
+                    );
+
+                    let shared = neighbour
+                        .vertices()
+                        .iter()
+                        .filter(|vertex| {
+                            test_case
+                                .cell
+                                .vertices()
+                                .iter()
+                                .any(|other| other.distance(vertex) < 1e-12)
+                        })
+                        .count();
+
+                    assert_eq!(
+                        shared, 2,
+                        "{}: edge neighbour {:?} must share an edge",
+                        test_case.name, neighbour
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn cover_must_not_fail() {
+        let cap = SphericalCap::new(from_degrees(44., 1.), 0.05);
+        let covering = CellId::cover_cap(&cap, 8, usize::MAX);
+        assert!(!covering.is_empty(), "cap covering must not be empty");
+
+        std::iter::once(cap.center())
+            .chain(cap.boundary(32).exterior())
+            .for_each(|point| {
+                assert!(
+                    covering.iter().any(|cell| cell.contains_point(&point)),
+                    "cap covering must contain {point:?}"
+                );
+            });
+
+        assert!(
+            covering.iter().any(|cell| cell.level() < 8),
+            "cells inside the cap must not be split"
+        );
+
+        let polygon = SphericalPolygon::new(vec![
+            from_degrees(-10., -5.),
+            from_degrees(10., -5.),
+            from_degrees(10., 5.),
+            from_degrees(-10., 5.),
+        ]);
+
+        let covering = CellId::cover_polygon(&polygon, 6, usize::MAX);
+        let area: f64 = covering.iter().map(|cell| cell.polygon().area()).sum();
+        assert!(
+            area >= polygon.area(),
+            "polygon covering must not be smaller than the polygon"
+        );
+
+        polygon.exterior().iter().for_each(|point| {
+            assert!(
+                covering.iter().any(|cell| cell.contains_point(point)),
+                "polygon covering must contain {point:?}"
+            );
+        });
+
+        assert!(
+            !covering
+                .iter()
+                .any(|cell| cell.contains_point(&from_degrees(20., 0.))),
+            "polygon covering must not contain far away points"
+        );
+    }
+
+    #[test]
+    fn cover_must_not_exceed_max_cells() {
+        let cap = SphericalCap::new(from_degrees(-3.7, 40.4), PI / 2.);
+        [6, 8, 64, 200].into_iter().for_each(|max_cells| {
+            let covering = CellId::cover_cap(&cap, MAX_CELL_LEVEL, max_cells);
+            assert!(
+                covering.len() <= max_cells,
+                "hemisphere covering of {} cells must not exceed {max_cells}",
+                covering.len()
+            );
+
+            std::iter::once(cap.center())
+                .chain(cap.boundary(64).exterior())
+                .for_each(|point| {
+                    assert!(
+                        covering.iter().any(|cell| cell.contains_point(&point)),
+                        "covering of at most {max_cells} cells must contain {point:?}"
+                    );
+                });
+        });
+
+        let polygon = SphericalPolygon::new(vec![
+            from_degrees(-10., -5.),
+            from_degrees(10., -5.),
+            from_degrees(10., 5.),
+            from_degrees(-10., 5.),
+        ]);
+
+        let covering = CellId::cover_polygon(&polygon, MAX_CELL_LEVEL, 32);
+        assert!(covering.len() <= 32, "polygon covering: {}", covering.len());
+        assert!(
+            covering.iter().any(|cell| cell.level() > 0),
+            "polygon covering must be refined within the budget"
+        );
+    }
+}
@@ -0,0 +1,791 @@
+use crate::GeographicPoint;
+use std::fmt;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The maximum amount of decimal places when formatting a coordinate.
+const MAX_PRECISION: usize = 9;
+
+/// Represents an error while parsing a coordinate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoordinateError {
+    /// The string has no coordinates.
+    Empty,
+    /// The character at the given position is not expected there.
+    InvalidCharacter(char, usize),
+    /// A number was expected at the given position.
+    MissingNumber(usize),
+    /// The component of the coordinate at the given position is not valid,
+    /// either because it is out of its range, because it is not the last one
+    /// but has decimals, or because it is not in the expected order.
+    InvalidComponent(usize),
+    /// The coordinate at the given position has both a sign and a hemisphere.
+    ConflictingSign(usize),
+    /// The hemisphere does not belong to the expected coordinate, or both
+    /// coordinates of a point have the same axis.
+    InvalidHemisphere(char),
+    /// The value (in degrees) is out of the range of the coordinate.
+    OutOfRange(f64),
+    /// The point has a single coordinate.
+    MissingCoordinate,
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateError::Empty => write!(f, "coordinate must not be empty"),
+            CoordinateError::InvalidCharacter(character, position) => {
+                write!(
+                    f,
+                    "unexpected character {character:?} at position {position}"
+                )
+            }
+            CoordinateError::MissingNumber(position) => {
+                write!(f, "expected a number at position {position}")
+            }
+            CoordinateError::InvalidComponent(position) => {
+                write!(f, "invalid component at position {position}")
+            }
+            CoordinateError::ConflictingSign(position) => write!(
+                f,
+                "the sign at position {position} conflicts with the hemisphere"
+            ),
+            CoordinateError::InvalidHemisphere(hemisphere) => {
+                write!(f, "unexpected hemisphere {hemisphere:?}")
+            }
+            CoordinateError::OutOfRange(degrees) => {
+                write!(f, "coordinate {degrees}° is out of range")
+            }
+            CoordinateError::MissingCoordinate => {
+                write!(f, "point must have both a latitude and a longitude")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CoordinateError {}
+
+/// Represents each of the notations of a coordinate.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoordinateNotation {
+    /// Decimal degrees, like `40.446°N`.
+    Decimal,
+    /// Degrees and decimal minutes, like `40°26.767'N`.
+    DegreesMinutes,
+    /// Degrees, minutes and decimal seconds, like `40°26'46"N`.
+    #[default]
+    DegreesMinutesSeconds,
+}
+
+/// Represents the way latitudes and longitudes are written as text.
+///
+/// Any of the common notations is accepted when parsing, no matter the format:
+/// decimal degrees, degrees and minutes, or degrees, minutes and seconds,
+/// either with symbols (`°`, `'`, `"`) or separated by spaces or colons, and
+/// with a sign or a hemisphere letter before or after the numbers.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateFormat {
+    notation: CoordinateNotation,
+    precision: usize,
+    hemisphere: bool,
+}
+
+impl Default for CoordinateFormat {
+    fn default() -> Self {
+        Self {
+            notation: CoordinateNotation::default(),
+            precision: 0,
+            hemisphere: true,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl CoordinateFormat {
+    /// Sets the notation of the format.
+    pub fn with_notation(mut self, notation: CoordinateNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Sets the amount of decimal places of the last component, up to 9.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(MAX_PRECISION);
+        self
+    }
+
+    /// Sets whether coordinates end with their hemisphere letter, or start
+    /// with a minus sign when negative.
+    pub fn with_hemisphere(mut self, hemisphere: bool) -> Self {
+        self.hemisphere = hemisphere;
+        self
+    }
+
+    /// Returns the given latitude (in radiants) as text.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{CoordinateFormat, CoordinateNotation};
+    ///
+    /// let latitude = 40.446195_f64.to_radians();
+    ///
+    /// assert_eq!(CoordinateFormat::default().format_latitude(latitude), "40°26'46\"N");
+    /// assert_eq!(
+    ///     CoordinateFormat::default()
+    ///         .with_notation(CoordinateNotation::DegreesMinutes)
+    ///         .with_precision(3)
+    ///         .format_latitude(latitude),
+    ///     "40°26.772'N"
+    /// );
+    /// ```
+    pub fn format_latitude(&self, latitude: f64) -> String {
+        self.format_degrees(latitude.to_degrees(), ('N', 'S'))
+    }
+
+    /// Returns the given longitude (in radiants) as text.
+    pub fn format_longitude(&self, longitude: f64) -> String {
+        self.format_degrees(longitude.to_degrees(), ('E', 'W'))
+    }
+
+    /// Returns the latitude and longitude of the given point as text,
+    /// separated by a space.
+    pub fn format(&self, point: &GeographicPoint) -> String {
+        format!(
+            "{} {}",
+            self.format_latitude(point.latitude()),
+            self.format_longitude(point.longitude())
+        )
+    }
+}
+
+impl CoordinateFormat {
+    /// Returns the latitude (in radiants) in the given string.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::CoordinateFormat;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let latitude = CoordinateFormat::parse_latitude("S 40 26.767").unwrap();
+    /// assert!(approx_eq!(f64, latitude.to_degrees(), -40.446117, epsilon = 1e-6));
+    /// ```
+    pub fn parse_latitude(value: &str) -> Result<f64, CoordinateError> {
+        let tokens = tokenize(value)?;
+        let coordinate = Coordinate::parse(value, &tokens)?;
+        coordinate.latitude()
+    }
+
+    /// Returns the longitude (in radiants) in the given string.
+    pub fn parse_longitude(value: &str) -> Result<f64, CoordinateError> {
+        let tokens = tokenize(value)?;
+        let coordinate = Coordinate::parse(value, &tokens)?;
+        coordinate.longitude()
+    }
+
+    /// Returns the point whose latitude and longitude are in the given
+    /// string, in that order unless their hemisphere letters say otherwise.
+    /// Both coordinates may be separated by a comma or a semicolon.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::CoordinateFormat;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let point = CoordinateFormat::parse_point("40°26'46\"N 79°58'56\"W").unwrap();
+    ///
+    /// assert!(approx_eq!(f64, point.latitude().to_degrees(), 40.446111, epsilon = 1e-6));
+    /// assert!(approx_eq!(f64, point.longitude().to_degrees(), -79.982222, epsilon = 1e-6));
+    /// ```
+    pub fn parse_point(value: &str) -> Result<GeographicPoint, CoordinateError> {
+        let tokens = tokenize(value)?;
+        let (first, second) = split(&tokens)?;
+        let first = Coordinate::parse(value, first)?;
+        let second = Coordinate::parse(value, second)?;
+
+        let (latitude, longitude) = match (first.axis(), second.axis()) {
+            (Some(first_axis), Some(second_axis)) if first_axis == second_axis => {
+                return Err(CoordinateError::InvalidHemisphere(
+                    second.hemisphere.unwrap_or_default().0,
+                ))
+            }
+            (Some(Axis::Longitude), _) | (_, Some(Axis::Latitude)) => (second, first),
+            _ => (first, second),
+        };
+
+        Ok(GeographicPoint::default()
+            .with_longitude(longitude.longitude()?)
+            .with_latitude(latitude.latitude()?))
+    }
+
+    /// Returns the given amount of degrees as text, with the given letters for
+    /// the positive and negative hemispheres.
+    fn format_degrees(&self, degrees: f64, (positive, negative): (char, char)) -> String {
+        let scale = 10_u64.pow(self.precision as u32);
+        let units = match self.notation {
+            CoordinateNotation::Decimal => 1,
+            CoordinateNotation::DegreesMinutes => 60,
+            CoordinateNotation::DegreesMinutesSeconds => 3600,
+        };
+
+        // rounding the whole value at once carries any overflow of the last
+        // component into the previous ones
+        let total = (degrees.abs() * (units * scale) as f64).round() as u64;
+        let is_negative = degrees.is_sign_negative() && total > 0;
+
+        let fraction = |value: u64| {
+            if self.precision == 0 {
+                String::new()
+            } else {
+                format!(".{:0width$}", value % scale, width = self.precision)
+            }
+        };
+
+        let last = total % (60 * scale);
+        let body = match self.notation {
+            CoordinateNotation::Decimal => format!("{}{}°", total / scale, fraction(total)),
+            CoordinateNotation::DegreesMinutes => format!(
+                "{}°{:02}{}'",
+                total / (60 * scale),
+                last / scale,
+                fraction(last)
+            ),
+            CoordinateNotation::DegreesMinutesSeconds => format!(
+                "{}°{:02}'{:02}{}\"",
+                total / (3600 * scale),
+                total % (3600 * scale) / (60 * scale),
+                last / scale,
+                fraction(last)
+            ),
+        };
+
+        match (self.hemisphere, is_negative) {
+            (true, false) => format!("{body}{positive}"),
+            (true, true) => format!("{body}{negative}"),
+            (false, false) => body,
+            (false, true) => format!("-{body}"),
+        }
+    }
+}
+
+/// Represents each of the units of the components of a coordinate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Unit {
+    Degrees,
+    Minutes,
+    Seconds,
+}
+
+/// Represents each of the axes a coordinate may belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Latitude,
+    Longitude,
+}
+
+/// Represents each of the meaningful pieces of a coordinate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Number(f64, bool),
+    Unit(Unit),
+    Hemisphere(char),
+    Sign(f64),
+    Separator,
+}
+
+/// A sequence of tokens, together with their positions.
+type Tokens<'a> = &'a [(usize, Token)];
+
+/// Returns the tokens of the given string, together with their positions.
+fn tokenize(value: &str) -> Result<Vec<(usize, Token)>, CoordinateError> {
+    let mut tokens = Vec::new();
+    let mut characters = value.char_indices().peekable();
+
+    while let Some((position, character)) = characters.next() {
+        let token = match character {
+            character if character.is_whitespace() || character == ':' => continue,
+            '0'..='9' | '.' => {
+                let mut end = position + character.len_utf8();
+                while let Some(&(next, digit)) = characters.peek() {
+                    if !digit.is_ascii_digit() && digit != '.' {
+                        break;
+                    }
+
+                    end = next + digit.len_utf8();
+                    characters.next();
+                }
+
+                let number = &value[position..end];
+                let parsed = number
+                    .parse::<f64>()
+                    .map_err(|_| CoordinateError::InvalidCharacter(character, position))?;
+
+                Token::Number(parsed, number.contains('.'))
+            }
+            '°' | 'º' | '˚' => Token::Unit(Unit::Degrees),
+            '\'' | '′' | '’' => {
+                if characters
+                    .peek()
+                    .is_some_and(|&(_, next)| next == character)
+                {
+                    // two single quotes stand for a double one
+                    characters.next();
+                    Token::Unit(Unit::Seconds)
+                } else {
+                    Token::Unit(Unit::Minutes)
+                }
+            }
+            '"' | '″' | '”' => Token::Unit(Unit::Seconds),
+            'n' | 'N' | 's' | 'S' | 'e' | 'E' | 'w' | 'W' => {
+                Token::Hemisphere(character.to_ascii_uppercase())
+            }
+            '+' => Token::Sign(1.),
+            '-' | '−' => Token::Sign(-1.),
+            ',' | ';' => Token::Separator,
+            _ => return Err(CoordinateError::InvalidCharacter(character, position)),
+        };
+
+        tokens.push((position, token));
+    }
+
+    if tokens.is_empty() {
+        return Err(CoordinateError::Empty);
+    }
+
+    Ok(tokens)
+}
+
+/// Returns the tokens of each of both coordinates of a point.
+fn split(tokens: Tokens) -> Result<(Tokens, Tokens), CoordinateError> {
+    let separators: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, token))| *token == Token::Separator)
+        .map(|(index, _)| index)
+        .collect();
+
+    if let Some(&separator) = separators.first() {
+        return Ok((&tokens[..separator], &tokens[separator + 1..]));
+    }
+
+    let hemispheres: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, token))| matches!(token, Token::Hemisphere(_)))
+        .map(|(index, _)| index)
+        .collect();
+
+    let index = if let [first, second] = hemispheres[..] {
+        if first == 0 {
+            // hemispheres come before the numbers
+            second
+        } else {
+            first + 1
+        }
+    } else {
+        // otherwise, both coordinates must have the same amount of components
+        let numbers: Vec<usize> = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, token))| matches!(token, Token::Number(..)))
+            .map(|(index, _)| index)
+            .collect();
+
+        if numbers.is_empty() || !numbers.len().is_multiple_of(2) {
+            return Err(CoordinateError::MissingCoordinate);
+        }
+
+        let index = numbers[numbers.len() / 2];
+        if index > 0 && matches!(tokens[index - 1].1, Token::Sign(_)) {
+            index - 1
+        } else {
+            index
+        }
+    };
+
+    Ok((&tokens[..index], &tokens[index..]))
+}
+
+/// Represents a single coordinate, as parsed from a string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Coordinate {
+    degrees: f64,
+    hemisphere: Option<(char, usize)>,
+}
+
+impl Coordinate {
+    /// Returns the coordinate in the given tokens of the given string.
+    fn parse(value: &str, tokens: Tokens) -> Result<Self, CoordinateError> {
+        let unexpected = |position: usize| {
+            CoordinateError::InvalidCharacter(
+                value[position..].chars().next().unwrap_or_default(),
+                position,
+            )
+        };
+
+        // the position right after the last token
+        let end = tokens.last().map_or(value.len(), |(position, _)| {
+            position + value[*position..].chars().next().map_or(0, char::len_utf8)
+        });
+
+        let mut tokens = tokens.iter().copied().peekable();
+
+        let mut hemisphere = None;
+        if let Some(&(position, Token::Hemisphere(letter))) = tokens.peek() {
+            hemisphere = Some((letter, position));
+            tokens.next();
+        }
+
+        let mut sign = None;
+        if let Some(&(position, Token::Sign(value))) = tokens.peek() {
+            sign = Some((value, position));
+            tokens.next();
+        }
+
+        let mut degrees = 0.;
+        let mut previous: Option<(Unit, bool)> = None;
+        while let Some(&(position, Token::Number(number, has_decimals))) = tokens.peek() {
+            tokens.next();
+
+            let unit = match tokens.peek() {
+                Some(&(_, Token::Unit(unit))) => {
+                    tokens.next();
+                    unit
+                }
+                _ => match previous {
+                    None => Unit::Degrees,
+                    Some((Unit::Degrees, _)) => Unit::Minutes,
+                    Some(_) => Unit::Seconds,
+                },
+            };
+
+            let is_in_order =
+                previous.is_none_or(|(previous, had_decimals)| previous < unit && !had_decimals);
+
+            if !is_in_order || (unit != Unit::Degrees && number >= 60.) {
+                return Err(CoordinateError::InvalidComponent(position));
+            }
+
+            degrees += match unit {
+                Unit::Degrees => number,
+                Unit::Minutes => number / 60.,
+                Unit::Seconds => number / 3600.,
+            };
+
+            previous = Some((unit, has_decimals));
+        }
+
+        if previous.is_none() {
+            return Err(CoordinateError::MissingNumber(
+                tokens.peek().map_or(end, |(position, _)| *position),
+            ));
+        }
+
+        if let Some(&(position, Token::Hemisphere(letter))) = tokens.peek() {
+            if hemisphere.is_some() {
+                return Err(unexpected(position));
+            }
+
+            hemisphere = Some((letter, position));
+            tokens.next();
+        }
+
+        if let Some((position, _)) = tokens.next() {
+            return Err(unexpected(position));
+        }
+
+        if let (Some((_, position)), Some(_)) = (sign, hemisphere) {
+            return Err(CoordinateError::ConflictingSign(position));
+        }
+
+        let sign = match hemisphere {
+            Some(('S' | 'W', _)) => -1.,
+            _ => sign.map_or(1., |(sign, _)| sign),
+        };
+
+        Ok(Self {
+            degrees: sign * degrees,
+            hemisphere,
+        })
+    }
+
+    /// Returns the axis of the coordinate, if it has a hemisphere.
+    fn axis(&self) -> Option<Axis> {
+        self.hemisphere.map(|(letter, _)| match letter {
+            'N' | 'S' => Axis::Latitude,
+            _ => Axis::Longitude,
+        })
+    }
+
+    /// Returns the coordinate as a latitude (in radiants).
+    fn latitude(&self) -> Result<f64, CoordinateError> {
+        self.checked(Axis::Latitude, 90.)
+    }
+
+    /// Returns the coordinate as a longitude (in radiants).
+    fn longitude(&self) -> Result<f64, CoordinateError> {
+        self.checked(Axis::Longitude, 180.)
+    }
+
+    /// Returns the coordinate (in radiants), if it belongs to the given axis
+    /// and is in the given range.
+    fn checked(&self, axis: Axis, limit: f64) -> Result<f64, CoordinateError> {
+        if let Some((letter, _)) = self.hemisphere.filter(|_| self.axis() != Some(axis)) {
+            return Err(CoordinateError::InvalidHemisphere(letter));
+        }
+
+        if self.degrees.abs() > limit {
+            return Err(CoordinateError::OutOfRange(self.degrees));
+        }
+
+        Ok(self.degrees.to_radians())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn parse_point_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            output: Result<(f64, f64), CoordinateError>,
+        }
+
+        vec![
+            TestCase {
+                name: "degrees, minutes and seconds",
+                input: "40°26'46\"N 79°58'56\"W",
+                output: Ok((40.446111, -79.982222)),
+            },
+            TestCase {
+                name: "prime symbols",
+                input: "40° 26′ 46″ S, 79° 58′ 56″ E",
+                output: Ok((-40.446111, 79.982222)),
+            },
+            TestCase {
+                name: "degrees and minutes with leading hemisphere",
+                input: "N40 26.767 W79 58.933",
+                output: Ok((40.446117, -79.982217)),
+            },
+            TestCase {
+                name: "decimal degrees with hemisphere",
+                input: "40.446 N 79.982 W",
+                output: Ok((40.446, -79.982)),
+            },
+            TestCase {
+                name: "signed decimal degrees",
+                input: "-40.446, +79.982",
+                output: Ok((-40.446, 79.982)),
+            },
+            TestCase {
+                name: "signed decimal degrees without separator",
+                input: "40.446 -79.982",
+                output: Ok((40.446, -79.982)),
+            },
+            TestCase {
+                name: "longitude first",
+                input: "79.982W 40.446n",
+                output: Ok((40.446, -79.982)),
+            },
+            TestCase {
+                name: "colons",
+                input: "40:26:46 -79:58:56",
+                output: Ok((40.446111, -79.982222)),
+            },
+            TestCase {
+                name: "double single quotes",
+                input: "40°26'46''N 79°58'56''W",
+                output: Ok((40.446111, -79.982222)),
+            },
+            TestCase {
+                name: "empty",
+                input: " ",
+                output: Err(CoordinateError::Empty),
+            },
+            TestCase {
+                name: "single coordinate",
+                input: "40.446",
+                output: Err(CoordinateError::MissingCoordinate),
+            },
+            TestCase {
+                name: "same axis",
+                input: "40N 79S",
+                output: Err(CoordinateError::InvalidHemisphere('S')),
+            },
+            TestCase {
+                name: "minutes out of range",
+                input: "40 61 N 79 W",
+                output: Err(CoordinateError::InvalidComponent(3)),
+            },
+            TestCase {
+                name: "decimals before the last component",
+                input: "40.5°26'N 79W",
+                output: Err(CoordinateError::InvalidComponent(6)),
+            },
+            TestCase {
+                name: "components out of order",
+                input: "40'26°N 79W",
+                output: Err(CoordinateError::InvalidComponent(3)),
+            },
+            TestCase {
+                name: "sign and hemisphere",
+                input: "-40N 79W",
+                output: Err(CoordinateError::ConflictingSign(0)),
+            },
+            TestCase {
+                name: "latitude out of range",
+                input: "91, 0",
+                output: Err(CoordinateError::OutOfRange(91.)),
+            },
+            TestCase {
+                name: "unknown character",
+                input: "40x 79W",
+                output: Err(CoordinateError::InvalidCharacter('x', 2)),
+            },
+            TestCase {
+                name: "missing number",
+                input: "N, 79W",
+                output: Err(CoordinateError::MissingNumber(1)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = CoordinateFormat::parse_point(test_case.input);
+            match (output, test_case.output) {
+                (Ok(point), Ok((latitude, longitude))) => assert!(
+                    approx_eq!(f64, point.latitude().to_degrees(), latitude, epsilon = 1e-6)
+                        && approx_eq!(
+                            f64,
+                            point.longitude().to_degrees(),
+                            longitude,
+                            epsilon = 1e-6
+                        ),
+                    "{}: got {:?}, want {:?}",
+                    test_case.name,
+                    point,
+                    (latitude, longitude)
+                ),
+                (got, want) => assert_eq!(got.map(|_| ()), want.map(|_| ()), "{}", test_case.name),
+            }
+        });
+    }
+
+    #[test]
+    fn parse_coordinate_must_not_fail() {
+        assert_eq!(
+            CoordinateFormat::parse_longitude("40N"),
+            Err(CoordinateError::InvalidHemisphere('N')),
+            "latitude as longitude"
+        );
+
+        assert_eq!(
+            CoordinateFormat::parse_latitude("N40N"),
+            Err(CoordinateError::InvalidCharacter('N', 3)),
+            "two hemispheres"
+        );
+
+        assert!(
+            CoordinateFormat::parse_longitude("-180").is_ok_and(|longitude| approx_eq!(
+                f64,
+                longitude.to_degrees(),
+                -180.,
+                ulps = 2
+            )),
+            "antimeridian"
+        );
+    }
+
+    #[test]
+    fn format_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            format: CoordinateFormat,
+            point: (f64, f64),
+            output: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "degrees, minutes and seconds",
+                format: CoordinateFormat::default(),
+                point: (40.446195, -79.948862),
+                output: "40°26'46\"N 79°56'56\"W",
+            },
+            TestCase {
+                name: "seconds carry",
+                format: CoordinateFormat::default().with_precision(1),
+                point: (-0.99999999, 0.),
+                output: "1°00'00.0\"S 0°00'00.0\"E",
+            },
+            TestCase {
+                name: "degrees and minutes",
+                format: CoordinateFormat::default()
+                    .with_notation(CoordinateNotation::DegreesMinutes)
+                    .with_precision(3),
+                point: (40.446195, -79.948862),
+                output: "40°26.772'N 79°56.932'W",
+            },
+            TestCase {
+                name: "signed decimal degrees",
+                format: CoordinateFormat::default()
+                    .with_notation(CoordinateNotation::Decimal)
+                    .with_precision(4)
+                    .with_hemisphere(false),
+                point: (-40.446195, 79.948862),
+                output: "-40.4462° 79.9489°",
+            },
+            TestCase {
+                name: "negative zero",
+                format: CoordinateFormat::default()
+                    .with_notation(CoordinateNotation::Decimal)
+                    .with_hemisphere(false),
+                point: (-0.1, 0.),
+                output: "0° 0°",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = GeographicPoint::default()
+                .with_longitude(test_case.point.1.to_radians())
+                .with_latitude(test_case.point.0.to_radians());
+
+            let output = test_case.format.format(&point);
+            assert_eq!(output, test_case.output, "{}", test_case.name);
+        });
+    }
+
+    #[test]
+    fn parse_must_revert_format() {
+        let point = GeographicPoint::default()
+            .with_longitude((-123.456789_f64).to_radians())
+            .with_latitude(12.345678_f64.to_radians());
+
+        [
+            CoordinateNotation::Decimal,
+            CoordinateNotation::DegreesMinutes,
+            CoordinateNotation::DegreesMinutesSeconds,
+        ]
+        .into_iter()
+        .for_each(|notation| {
+            [true, false].into_iter().for_each(|hemisphere| {
+                let format = CoordinateFormat::default()
+                    .with_notation(notation)
+                    .with_precision(6)
+                    .with_hemisphere(hemisphere);
+
+                let text = format.format(&point);
+                let parsed = CoordinateFormat::parse_point(&text);
+                assert!(
+                    parsed
+                        .as_ref()
+                        .is_ok_and(|parsed| parsed.distance(&point) < 1e-8),
+                    "{text} was parsed into {parsed:?}"
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,227 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Represents a reference ellipsoid of revolution, defined by its semi-major
+/// axis (in meters) and its flattening.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    semi_major_axis: f64,
+    flattening: f64,
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+impl Ellipsoid {
+    /// The World Geodetic System 1984 ellipsoid.
+    pub const WGS84: Self = Self {
+        semi_major_axis: 6_378_137.,
+        flattening: 1. / 298.257_223_563,
+    };
+
+    /// The Geodetic Reference System 1980 ellipsoid.
+    pub const GRS80: Self = Self {
+        semi_major_axis: 6_378_137.,
+        flattening: 1. / 298.257_222_101,
+    };
+
+    /// The Clarke 1866 ellipsoid.
+    pub const CLARKE_1866: Self = Self {
+        semi_major_axis: 6_378_206.4,
+        flattening: 1. / 294.978_698_213_898,
+    };
+}
+
+#[wasm_bindgen]
+impl Ellipsoid {
+    #[wasm_bindgen(constructor)]
+    pub fn new(semi_major_axis: f64, flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// Returns the semi-major axis (equatorial radius) of the ellipsoid, in meters.
+    pub fn semi_major_axis(&self) -> f64 {
+        self.semi_major_axis
+    }
+
+    /// Returns the flattening of the ellipsoid.
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// Returns the semi-minor axis (polar radius) of the ellipsoid, in meters.
+    pub fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1. - self.flattening)
+    }
+
+    /// Returns the square of the first eccentricity of the ellipsoid.
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2. - self.flattening)
+    }
+
+    /// Returns the square of the second eccentricity of the ellipsoid.
+    pub fn second_eccentricity_squared(&self) -> f64 {
+        self.eccentricity_squared() / (1. - self.flattening).powi(2)
+    }
+
+    /// Returns the radius of curvature in the prime vertical at the given
+    /// latitude (in radiants).
+    pub fn prime_vertical_radius(&self, latitude: f64) -> f64 {
+        self.semi_major_axis / (1. - self.eccentricity_squared() * latitude.sin().powi(2)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CartesianPoint, GeographicPoint};
+    use float_cmp::approx_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    /// Sub-millimeter precision, in meters.
+    const EPSILON: f64 = 1e-4;
+
+    #[test]
+    fn geodetic_to_ecef_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            geographic: GeographicPoint,
+            cartesian: CartesianPoint,
+        }
+
+        let wgs84 = Ellipsoid::WGS84;
+
+        vec![
+            TestCase {
+                name: "origin of coordinates on the surface",
+                geographic: GeographicPoint::default(),
+                cartesian: CartesianPoint::new(wgs84.semi_major_axis(), 0., 0.),
+            },
+            TestCase {
+                name: "east point with altitude",
+                geographic: GeographicPoint::default()
+                    .with_longitude(FRAC_PI_2)
+                    .with_altitude(1000.),
+                cartesian: CartesianPoint::new(0., wgs84.semi_major_axis() + 1000., 0.),
+            },
+            TestCase {
+                name: "north pole",
+                geographic: GeographicPoint::default().with_latitude(FRAC_PI_2),
+                cartesian: CartesianPoint::new(0., 0., wgs84.semi_minor_axis()),
+            },
+            TestCase {
+                name: "south pole below the surface",
+                geographic: GeographicPoint::default()
+                    .with_latitude(-FRAC_PI_2)
+                    .with_altitude(-100.),
+                cartesian: CartesianPoint::new(0., 0., -wgs84.semi_minor_axis() + 100.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = CartesianPoint::from_geodetic(&test_case.geographic, &wgs84);
+            assert!(
+                point.distance(&test_case.cartesian) < EPSILON,
+                "{}: {:?} ±ε = {:?}",
+                test_case.name,
+                point,
+                test_case.cartesian
+            );
+        });
+    }
+
+    #[test]
+    fn ecef_to_geodetic_must_be_reversible() {
+        struct TestCase {
+            name: &'static str,
+            ellipsoid: Ellipsoid,
+            geographic: GeographicPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "arbitrary point on the wgs84 surface",
+                ellipsoid: Ellipsoid::WGS84,
+                geographic: GeographicPoint::default()
+                    .with_longitude(-1.2)
+                    .with_latitude(0.7),
+            },
+            TestCase {
+                name: "high altitude point on grs80",
+                ellipsoid: Ellipsoid::GRS80,
+                geographic: GeographicPoint::default()
+                    .with_longitude(2.5)
+                    .with_latitude(-1.3)
+                    .with_altitude(35_786_000.),
+            },
+            TestCase {
+                name: "point below the clarke 1866 surface",
+                ellipsoid: Ellipsoid::CLARKE_1866,
+                geographic: GeographicPoint::default()
+                    .with_longitude(PI - 0.1)
+                    .with_latitude(FRAC_PI_4)
+                    .with_altitude(-10_000.),
+            },
+            TestCase {
+                name: "point close to the pole on a custom ellipsoid",
+                ellipsoid: Ellipsoid::new(3_396_190., 1. / 169.894_447_223_611),
+                geographic: GeographicPoint::default()
+                    .with_longitude(0.3)
+                    .with_latitude(FRAC_PI_2 - 1e-9)
+                    .with_altitude(25.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let cartesian =
+                CartesianPoint::from_geodetic(&test_case.geographic, &test_case.ellipsoid);
+            let point = GeographicPoint::from_geocentric(&cartesian, &test_case.ellipsoid);
+            let radius = test_case.ellipsoid.semi_major_axis();
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    point.latitude(),
+                    test_case.geographic.latitude(),
+                    epsilon = EPSILON / radius
+                ),
+                "{}: latitude {} ±ε = {}",
+                test_case.name,
+                point.latitude(),
+                test_case.geographic.latitude()
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    point.longitude(),
+                    test_case.geographic.longitude(),
+                    epsilon = EPSILON / radius
+                ),
+                "{}: longitude {} ±ε = {}",
+                test_case.name,
+                point.longitude(),
+                test_case.geographic.longitude()
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    point.altitude(),
+                    test_case.geographic.altitude(),
+                    epsilon = EPSILON
+                ),
+                "{}: altitude {} ±ε = {}",
+                test_case.name,
+                point.altitude(),
+                test_case.geographic.altitude()
+            );
+        });
+    }
+}
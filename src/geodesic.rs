@@ -0,0 +1,616 @@
+use crate::{geographic::longitude_offset, Ellipsoid, GeographicPoint};
+use std::f64::consts::{FRAC_PI_2, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Maximum number of iterations for any of the iterative methods.
+const MAX_ITERATIONS: usize = 200;
+
+/// Represents the shortest path between two points on the surface of an
+/// [`Ellipsoid`], as given by the solution of either the direct or the inverse
+/// [geodesic problem](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid).
+///
+/// Both problems are solved on the auxiliary sphere using Vincenty's series,
+/// which are accurate to within a fraction of a millimeter. However, instead of
+/// Vincenty's fixed-point iteration, the inverse problem is solved by searching
+/// for the initial azimuth as proposed by Karney, which converges even for
+/// nearly antipodal points.
+///
+/// The altitude of the points is not taken into account.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Geodesic {
+    from: GeographicPoint,
+    to: GeographicPoint,
+    distance: f64,
+    initial_azimuth: f64,
+    final_azimuth: f64,
+}
+
+#[wasm_bindgen]
+impl Geodesic {
+    /// Solves the inverse geodesic problem: the shortest path between the two
+    /// given points on the given [`Ellipsoid`].
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{Ellipsoid, Geodesic, GeographicPoint};
+    /// use float_cmp::approx_eq;
+    ///
+    /// let from = GeographicPoint::default();
+    /// let to = GeographicPoint::default().with_longitude(1.);
+    ///
+    /// let geodesic = Geodesic::inverse(&from, &to, &Ellipsoid::WGS84);
+    /// assert!(approx_eq!(f64, geodesic.distance(), 6_378_137., epsilon = 1e-6));
+    /// ```
+    pub fn inverse(from: &GeographicPoint, to: &GeographicPoint, ellipsoid: &Ellipsoid) -> Self {
+        let f = ellipsoid.flattening();
+
+        // Arrange the points canonically, such that the longitude difference is
+        // positive, the first point is the farthest from the equator, and it
+        // lies on the south hemisphere.
+        let mut lambda12 = GeographicPoint::default()
+            .with_longitude(to.longitude() - from.longitude())
+            .longitude();
+
+        let mut lon_sign = if lambda12.is_sign_negative() { -1. } else { 1. };
+        lambda12 *= lon_sign;
+
+        if lambda12 == 0. && from.latitude() == to.latitude() {
+            return Self {
+                from: *from,
+                to: *to,
+                ..Default::default()
+            };
+        }
+
+        let swapped = from.latitude().abs() < to.latitude().abs();
+        if swapped {
+            // going backwards reverses the direction of the longitude as well.
+            lon_sign = -lon_sign;
+        }
+
+        let (mut phi1, mut phi2) = if swapped {
+            (to.latitude(), from.latitude())
+        } else {
+            (from.latitude(), to.latitude())
+        };
+
+        let lat_sign = if phi1 > 0. { -1. } else { 1. };
+        phi1 *= lat_sign;
+        phi2 *= lat_sign;
+
+        let beta1 = reduced_latitude(phi1, f);
+        let beta2 = reduced_latitude(phi2, f);
+
+        let (distance, mut alpha1, mut alpha2) =
+            if beta1 == 0. && beta2 == 0. && lambda12 <= (1. - f) * PI {
+                // both points on the equator and close enough for the shortest
+                // path to follow the equator itself.
+                (ellipsoid.semi_major_axis() * lambda12, FRAC_PI_2, FRAC_PI_2)
+            } else {
+                let alpha1 = search_initial_azimuth(beta1, beta2, lambda12, f);
+                let arc = AuxiliaryArc::new(beta1, beta2, alpha1, f);
+                (arc.distance(ellipsoid), alpha1, arc.final_azimuth())
+            };
+
+        // Undo the canonical arrangement in reverse order.
+        if lat_sign < 0. {
+            alpha1 = PI - alpha1;
+            alpha2 = PI - alpha2;
+        }
+
+        if swapped {
+            (alpha1, alpha2) = (alpha2 + PI, alpha1 + PI);
+        }
+
+        Self {
+            from: *from,
+            to: *to,
+            distance,
+            initial_azimuth: normalize_azimuth(lon_sign * alpha1),
+            final_azimuth: normalize_azimuth(lon_sign * alpha2),
+        }
+    }
+
+    /// Solves the direct geodesic problem: the path on the given [`Ellipsoid`]
+    /// that starts at the given point with the given azimuth (in radiants,
+    /// clockwise from the north) and has the given length (in meters).
+    pub fn direct(
+        from: &GeographicPoint,
+        azimuth: f64,
+        distance: f64,
+        ellipsoid: &Ellipsoid,
+    ) -> Self {
+        // see: https://en.wikipedia.org/wiki/Vincenty%27s_formulae#Direct_problem
+        let f = ellipsoid.flattening();
+        let b = ellipsoid.semi_minor_axis();
+
+        let (alpha1_sin, alpha1_cos) = azimuth.sin_cos();
+        let (beta1_sin, beta1_cos) = reduced_latitude(from.latitude(), f).sin_cos();
+
+        let sigma1 = beta1_sin.atan2(beta1_cos * alpha1_cos);
+        let alpha0_sin = beta1_cos * alpha1_sin;
+        let alpha0_cos2 = 1. - alpha0_sin.powi(2);
+        let (a, bb) = distance_coefficients(alpha0_cos2 * ellipsoid.second_eccentricity_squared());
+
+        let mut sigma = distance / (b * a);
+        for _ in 0..MAX_ITERATIONS {
+            let next = distance / (b * a) + sigma_correction(bb, sigma, 2. * sigma1 + sigma);
+            let converged = (next - sigma).abs() < 1e-14;
+            sigma = next;
+            if converged {
+                break;
+            }
+        }
+
+        let (sigma_sin, sigma_cos) = sigma.sin_cos();
+        let latitude = (beta1_sin * sigma_cos + beta1_cos * sigma_sin * alpha1_cos).atan2(
+            (1. - f) * alpha0_sin.hypot(beta1_sin * sigma_sin - beta1_cos * sigma_cos * alpha1_cos),
+        );
+
+        let omega = (sigma_sin * alpha1_sin)
+            .atan2(beta1_cos * sigma_cos - beta1_sin * sigma_sin * alpha1_cos);
+        let lambda = omega - longitude_correction(f, alpha0_sin, sigma, 2. * sigma1 + sigma);
+
+        let final_azimuth =
+            alpha0_sin.atan2(beta1_cos * sigma_cos * alpha1_cos - beta1_sin * sigma_sin);
+
+        Self {
+            from: *from,
+            to: GeographicPoint::default()
+                .with_longitude(from.longitude() + lambda)
+                .with_latitude(latitude),
+            distance,
+            initial_azimuth: normalize_azimuth(azimuth),
+            final_azimuth: normalize_azimuth(final_azimuth),
+        }
+    }
+
+    /// Returns the starting point of the geodesic.
+    pub fn from(&self) -> GeographicPoint {
+        self.from
+    }
+
+    /// Returns the ending point of the geodesic.
+    pub fn to(&self) -> GeographicPoint {
+        self.to
+    }
+
+    /// Returns the length of the geodesic, in meters.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the forward azimuth (in radiants, clockwise from the north) at
+    /// the starting point of the geodesic, in the range __(-π, +π]__.
+    pub fn initial_azimuth(&self) -> f64 {
+        self.initial_azimuth
+    }
+
+    /// Returns the forward azimuth (in radiants, clockwise from the north) at
+    /// the ending point of the geodesic, in the range __(-π, +π]__.
+    pub fn final_azimuth(&self) -> f64 {
+        self.final_azimuth
+    }
+
+    /// Returns the azimuth (in radiants, clockwise from the north) from the
+    /// ending point of the geodesic back to the starting one, in the range
+    /// __(-π, +π]__.
+    pub fn back_azimuth(&self) -> f64 {
+        normalize_azimuth(self.final_azimuth + PI)
+    }
+}
+
+/// Represents a geodesic on the auxiliary sphere, going from the reduced
+/// latitude `beta1` to `beta2` with the initial azimuth `alpha1`.
+struct AuxiliaryArc {
+    flattening: f64,
+    alpha0_sin: f64,
+    alpha2_cos: f64,
+    beta2_cos: f64,
+    sigma1: f64,
+    sigma12: f64,
+    omega12: f64,
+}
+
+impl AuxiliaryArc {
+    fn new(beta1: f64, beta2: f64, alpha1: f64, flattening: f64) -> Self {
+        // see: https://arxiv.org/abs/1109.4448
+        let (beta1_sin, beta1_cos) = beta1.sin_cos();
+        let (beta2_sin, beta2_cos) = beta2.sin_cos();
+        let (alpha1_sin, alpha1_cos) = alpha1.sin_cos();
+
+        let alpha0_sin = alpha1_sin * beta1_cos;
+
+        // the second point is always reached while heading north, given the
+        // canonical arrangement of the points.
+        let alpha2_cos = if beta2_cos == 0. {
+            1.
+        } else {
+            ((alpha1_cos * beta1_cos).powi(2) + (beta2_cos - beta1_cos) * (beta2_cos + beta1_cos))
+                .max(0.)
+                .sqrt()
+                / beta2_cos
+        };
+
+        let (sigma1_sin, sigma1_cos) = (beta1_sin, alpha1_cos * beta1_cos);
+        let (sigma2_sin, sigma2_cos) = (beta2_sin, alpha2_cos * beta2_cos);
+        let (omega1_sin, omega1_cos) = (alpha0_sin * beta1_sin, alpha1_cos * beta1_cos);
+        let (omega2_sin, omega2_cos) = (alpha0_sin * beta2_sin, alpha2_cos * beta2_cos);
+
+        let sigma12 = forward_angle((sigma1_sin, sigma1_cos), (sigma2_sin, sigma2_cos));
+        let omega12 = forward_angle((omega1_sin, omega1_cos), (omega2_sin, omega2_cos));
+
+        Self {
+            flattening,
+            alpha0_sin,
+            alpha2_cos,
+            beta2_cos,
+            sigma1: sigma1_sin.atan2(sigma1_cos),
+            sigma12,
+            omega12,
+        }
+    }
+
+    /// Returns the longitude difference (in radiants) between both ends of the
+    /// arc on the ellipsoid.
+    fn longitude(&self) -> f64 {
+        self.omega12
+            - longitude_correction(
+                self.flattening,
+                self.alpha0_sin,
+                self.sigma12,
+                2. * self.sigma1 + self.sigma12,
+            )
+    }
+
+    /// Returns the length of the arc on the given ellipsoid, in meters.
+    fn distance(&self, ellipsoid: &Ellipsoid) -> f64 {
+        let alpha0_cos2 = 1. - self.alpha0_sin.powi(2);
+        let (a, b) = distance_coefficients(alpha0_cos2 * ellipsoid.second_eccentricity_squared());
+
+        ellipsoid.semi_minor_axis()
+            * a
+            * (self.sigma12 - sigma_correction(b, self.sigma12, 2. * self.sigma1 + self.sigma12))
+    }
+
+    /// Returns the forward azimuth at the end of the arc.
+    fn final_azimuth(&self) -> f64 {
+        self.alpha0_sin.atan2(self.alpha2_cos * self.beta2_cos)
+    }
+}
+
+/// Returns the initial azimuth in the range __[0, π]__ of the geodesic going
+/// from `beta1` to `beta2` whose longitude difference is `lambda12`.
+///
+/// Given the canonical arrangement of the points, the longitude difference is
+/// a monotonic function of the initial azimuth, and so the bisection method
+/// always converges.
+fn search_initial_azimuth(beta1: f64, beta2: f64, lambda12: f64, f: f64) -> f64 {
+    let (mut low, mut high) = (0., PI);
+    for _ in 0..MAX_ITERATIONS {
+        let alpha1 = (low + high) / 2.;
+        if alpha1 <= low || alpha1 >= high {
+            // the interval cannot be split any further
+            return alpha1;
+        }
+
+        let lambda = AuxiliaryArc::new(beta1, beta2, alpha1, f).longitude();
+        if lambda < lambda12 {
+            low = alpha1;
+        } else {
+            high = alpha1;
+        }
+    }
+
+    (low + high) / 2.
+}
+
+/// Returns the angle in the range __[0, π]__ going from the first angle to the
+/// second one, both given as their sine and cosine.
+fn forward_angle((sin1, cos1): (f64, f64), (sin2, cos2): (f64, f64)) -> f64 {
+    let sin = cos1 * sin2 - sin1 * cos2;
+    let sin = if sin > 0. { sin } else { 0. };
+    sin.atan2(cos1 * cos2 + sin1 * sin2)
+}
+
+/// Returns the reduced (parametric) latitude of the given geodetic one.
+fn reduced_latitude(latitude: f64, f: f64) -> f64 {
+    if latitude.abs() == FRAC_PI_2 {
+        return latitude;
+    }
+
+    ((1. - f) * latitude.tan()).atan()
+}
+
+/// Returns Vincenty's `A` and `B` coefficients for the given `u²`.
+fn distance_coefficients(u2: f64) -> (f64, f64) {
+    let a = 1. + u2 / 16384. * (4096. + u2 * (-768. + u2 * (320. - 175. * u2)));
+    let b = u2 / 1024. * (256. + u2 * (-128. + u2 * (74. - 47. * u2)));
+    (a, b)
+}
+
+/// Returns Vincenty's `Δσ` for the given arc length `sigma` and `2σm`.
+fn sigma_correction(b: f64, sigma: f64, two_sigma_m: f64) -> f64 {
+    let (sigma_sin, sigma_cos) = sigma.sin_cos();
+    let two_sigma_m_cos = two_sigma_m.cos();
+
+    b * sigma_sin
+        * (two_sigma_m_cos
+            + b / 4.
+                * (sigma_cos * (-1. + 2. * two_sigma_m_cos.powi(2))
+                    - b / 6.
+                        * two_sigma_m_cos
+                        * (-3. + 4. * sigma_sin.powi(2))
+                        * (-3. + 4. * two_sigma_m_cos.powi(2))))
+}
+
+/// Returns the difference between the longitude on the auxiliary sphere and the
+/// one on the ellipsoid for the given arc length `sigma` and `2σm`.
+fn longitude_correction(f: f64, alpha0_sin: f64, sigma: f64, two_sigma_m: f64) -> f64 {
+    let alpha0_cos2 = 1. - alpha0_sin.powi(2);
+    let c = f / 16. * alpha0_cos2 * (4. + f * (4. - 3. * alpha0_cos2));
+    let (sigma_sin, sigma_cos) = sigma.sin_cos();
+    let two_sigma_m_cos = two_sigma_m.cos();
+
+    (1. - c)
+        * f
+        * alpha0_sin
+        * (sigma
+            + c * sigma_sin
+                * (two_sigma_m_cos + c * sigma_cos * (-1. + 2. * two_sigma_m_cos.powi(2))))
+}
+
+/// Returns the equivalent of the given azimuth in the range __(-π, +π]__, the
+/// same as the one of bearings.
+fn normalize_azimuth(azimuth: f64) -> f64 {
+    match longitude_offset(azimuth, 0.) {
+        azimuth if azimuth == -PI => PI,
+        azimuth => azimuth,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    /// Sub-millimeter precision, in meters.
+    const EPSILON: f64 = 1e-4;
+
+    /// Angular precision equivalent to a millimeter on the Earth's surface.
+    const ANGULAR_EPSILON: f64 = 1e-10;
+
+    fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        degrees.signum() * (degrees.abs() + minutes / 60. + seconds / 3600.)
+    }
+
+    #[test]
+    fn inverse_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            ellipsoid: Ellipsoid,
+            from: GeographicPoint,
+            to: GeographicPoint,
+            distance: f64,
+            initial_azimuth: f64,
+            final_azimuth: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "same point must be zero",
+                ellipsoid: Ellipsoid::WGS84,
+                from: from_degrees(10., 20.),
+                to: from_degrees(10., 20.),
+                distance: 0.,
+                initial_azimuth: 0.,
+                final_azimuth: 0.,
+            },
+            TestCase {
+                name: "flinders peak to buninyong",
+                ellipsoid: Ellipsoid::GRS80,
+                from: from_degrees(dms(144., 25., 29.5244), dms(-37., 57., 3.7203)),
+                to: from_degrees(dms(143., 55., 35.3839), dms(-37., 39., 10.1561)),
+                distance: 54_972.271,
+                initial_azimuth: dms(306., 52., 5.37) - 360.,
+                final_azimuth: dms(127., 10., 25.07) - 180.,
+            },
+            TestCase {
+                name: "nearly antipodal points",
+                ellipsoid: Ellipsoid::WGS84,
+                from: from_degrees(0., -30.),
+                to: from_degrees(179.8, 29.9),
+                distance: 19_989_832.827_610,
+                initial_azimuth: 161.890_524_736,
+                final_azimuth: 18.090_737_246,
+            },
+            TestCase {
+                name: "antipodal points on the equator must follow a meridian",
+                ellipsoid: Ellipsoid::WGS84,
+                from: from_degrees(0., 0.),
+                to: from_degrees(180., 0.),
+                distance: 20_003_931.458_625,
+                initial_azimuth: 180.,
+                final_azimuth: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let geodesic = Geodesic::inverse(&test_case.from, &test_case.to, &test_case.ellipsoid);
+
+            assert!(
+                approx_eq!(f64, geodesic.distance(), test_case.distance, epsilon = 1e-3),
+                "{}: distance {} ±ε = {}",
+                test_case.name,
+                geodesic.distance(),
+                test_case.distance
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    normalize_azimuth(
+                        geodesic.initial_azimuth() - test_case.initial_azimuth.to_radians()
+                    ),
+                    0.,
+                    epsilon = 1e-7
+                ),
+                "{}: initial azimuth {} ±ε = {}",
+                test_case.name,
+                geodesic.initial_azimuth().to_degrees(),
+                test_case.initial_azimuth
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    normalize_azimuth(
+                        geodesic.final_azimuth() - test_case.final_azimuth.to_radians()
+                    ),
+                    0.,
+                    epsilon = 1e-7
+                ),
+                "{}: final azimuth {} ±ε = {}",
+                test_case.name,
+                geodesic.final_azimuth().to_degrees(),
+                test_case.final_azimuth
+            );
+        });
+    }
+
+    #[test]
+    fn normalize_azimuth_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            azimuth: f64,
+            want: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "azimuth in range must be kept",
+                azimuth: 1.,
+                want: 1.,
+            },
+            TestCase {
+                name: "upper bound must be kept",
+                azimuth: PI,
+                want: PI,
+            },
+            TestCase {
+                name: "lower bound must be the upper one",
+                azimuth: -PI,
+                want: PI,
+            },
+            TestCase {
+                name: "full turn must be zero",
+                azimuth: 2. * PI,
+                want: 0.,
+            },
+            TestCase {
+                name: "three half turns must be the upper bound",
+                azimuth: 3. * PI,
+                want: PI,
+            },
+            TestCase {
+                name: "negative three half turns must be the upper bound",
+                azimuth: -3. * PI,
+                want: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let azimuth = normalize_azimuth(test_case.azimuth);
+            assert!(
+                approx_eq!(f64, azimuth, test_case.want, epsilon = 1e-15),
+                "{}: {} ±ε = {}",
+                test_case.name,
+                azimuth,
+                test_case.want
+            );
+        });
+    }
+
+    #[test]
+    fn direct_must_revert_inverse() {
+        struct TestCase {
+            name: &'static str,
+            from: GeographicPoint,
+            to: GeographicPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "short path on the north hemisphere",
+                from: from_degrees(2.17, 41.38),
+                to: from_degrees(-3.7, 40.41),
+            },
+            TestCase {
+                name: "path crossing the antimeridian",
+                from: from_degrees(170., -10.),
+                to: from_degrees(-170., 15.),
+            },
+            TestCase {
+                name: "path crossing the equator",
+                from: from_degrees(-74., 40.7),
+                to: from_degrees(-58.4, -34.6),
+            },
+            TestCase {
+                name: "nearly antipodal points",
+                from: from_degrees(0., 0.5),
+                to: from_degrees(179.7, -0.3),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let inverse = Geodesic::inverse(&test_case.from, &test_case.to, &Ellipsoid::WGS84);
+            let direct = Geodesic::direct(
+                &test_case.from,
+                inverse.initial_azimuth(),
+                inverse.distance(),
+                &Ellipsoid::WGS84,
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    direct.to().latitude(),
+                    test_case.to.latitude(),
+                    epsilon = ANGULAR_EPSILON
+                ),
+                "{}: latitude {} ±ε = {}",
+                test_case.name,
+                direct.to().latitude(),
+                test_case.to.latitude()
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    direct.to().longitude(),
+                    test_case.to.longitude(),
+                    epsilon = ANGULAR_EPSILON
+                ),
+                "{}: longitude {} ±ε = {}",
+                test_case.name,
+                direct.to().longitude(),
+                test_case.to.longitude()
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    direct.final_azimuth(),
+                    inverse.final_azimuth(),
+                    epsilon = EPSILON
+                ),
+                "{}: final azimuth {} ±ε = {}",
+                test_case.name,
+                direct.final_azimuth(),
+                inverse.final_azimuth()
+            );
+        });
+    }
+}
@@ -1,8 +1,12 @@
-use crate::CartesianPoint;
+use crate::{CartesianPoint, Ellipsoid};
 use std::f64::consts::{FRAC_PI_2, PI};
 use std::ops::{Add, Mul, Neg, Sub};
 use wasm_bindgen::prelude::wasm_bindgen;
 
+/// The maximum amount of segments [`GeographicPoint::densify_by_step`] may
+/// split a path into.
+pub const MAX_DENSIFY_SEGMENTS: usize = 1 << 20;
+
 /// Represents a point using the geographic system of coordinates.
 #[wasm_bindgen]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -59,6 +63,52 @@ impl GeographicPoint {
             .with_altitude(radius)
     }
 
+    /// Returns the [`GeographicPoint`] of the given Earth-centered, Earth-fixed
+    /// (ECEF) [`CartesianPoint`], in meters, where the altitude is the height
+    /// above the given [`Ellipsoid`], in meters.
+    ///
+    /// The latitude is computed iteratively using Bowring's method, which
+    /// converges to sub-millimeter precision in very few steps.
+    pub fn from_geocentric(point: &CartesianPoint, ellipsoid: &Ellipsoid) -> Self {
+        // see: https://en.wikipedia.org/wiki/Geographic_coordinate_conversion
+        const MAX_ITERATIONS: usize = 10;
+
+        let a = ellipsoid.semi_major_axis();
+        let b = ellipsoid.semi_minor_axis();
+        let e2 = ellipsoid.eccentricity_squared();
+        let ep2 = ellipsoid.second_eccentricity_squared();
+
+        let p = point.x().hypot(point.y());
+        let longitude = point.y().atan2(point.x());
+
+        // parametric (reduced) latitude as first approximation
+        let mut beta = point.z().atan2((1. - ellipsoid.flattening()) * p);
+        let mut latitude = 0.;
+
+        for _ in 0..MAX_ITERATIONS {
+            let (beta_sin, beta_cos) = beta.sin_cos();
+            let next =
+                (point.z() + ep2 * b * beta_sin.powi(3)).atan2(p - e2 * a * beta_cos.powi(3));
+
+            let converged = (next - latitude).abs() < f64::EPSILON;
+            latitude = next;
+            if converged {
+                break;
+            }
+
+            beta = ((1. - ellipsoid.flattening()) * latitude.sin()).atan2(latitude.cos());
+        }
+
+        // this formulation of the height is stable even close to the poles
+        let (lat_sin, lat_cos) = latitude.sin_cos();
+        let altitude = p * lat_cos + point.z() * lat_sin - a * (1. - e2 * lat_sin.powi(2)).sqrt();
+
+        Self::default()
+            .with_longitude(longitude)
+            .with_latitude(latitude)
+            .with_altitude(altitude)
+    }
+
     /// Calls set_longitude on self and returns it.
     pub fn with_longitude(mut self, value: f64) -> Self {
         self.set_longitude(value);
@@ -102,15 +152,14 @@ impl GeographicPoint {
     /// assert!(approx_eq!(f64, point.longitude(), -PI + 1_f64, ulps = 2));
     /// ```
     pub fn set_longitude(&mut self, value: f64) {
-        self.longitude = (-PI..=PI)
-            .contains(&value)
-            .then_some(value)
-            .unwrap_or_else(|| {
-                // Both boundaries of the range are consecutive, which means that
-                // overflowing one is the same as continuing from the other in the
-                // same direction.
-                value.add(PI).rem_euclid(2_f64.mul(PI)).sub(PI)
-            })
+        self.longitude = if (-PI..=PI).contains(&value) {
+            value
+        } else {
+            // Both boundaries of the range are consecutive, which means that
+            // overflowing one is the same as continuing from the other in the
+            // same direction.
+            value.add(PI).rem_euclid(2_f64.mul(PI)).sub(PI)
+        }
     }
 
     /// Sets the given latitude (in radiants) to the point.
@@ -153,23 +202,22 @@ impl GeographicPoint {
     /// assert!(approx_eq!(f64, point.longitude(), -PI, ulps = 2));
     /// ```
     pub fn set_latitude(&mut self, value: f64) {
-        self.latitude = (-FRAC_PI_2..=FRAC_PI_2)
-            .contains(&value)
-            .then_some(value)
-            .unwrap_or_else(|| {
-                // The derivative of sin(x) is cos(x), and so, cos(x) determines if
-                // the sign of the longitude of the point must change.
-                if value.cos().is_sign_negative() {
-                    // Increasing the longitude of the point by ±π radiants (180º)
-                    // ensures the sign is changed while maintaining it in the same
-                    // pair of complementary meridians.
-                    let direction = self.longitude.cos().signum().neg(); // invert direction
-                    let rotation = PI * direction;
-                    self.set_longitude(self.longitude + rotation);
-                }
-
-                value.sin().asin()
-            });
+        self.latitude = if (-FRAC_PI_2..=FRAC_PI_2).contains(&value) {
+            value
+        } else {
+            // The derivative of sin(x) is cos(x), and so, cos(x) determines if
+            // the sign of the longitude of the point must change.
+            if value.cos().is_sign_negative() {
+                // Increasing the longitude of the point by ±π radiants (180º)
+                // ensures the sign is changed while maintaining it in the same
+                // pair of complementary meridians.
+                let direction = self.longitude.cos().signum().neg(); // invert direction
+                let rotation = PI * direction;
+                self.set_longitude(self.longitude + rotation);
+            }
+
+            value.sin().asin()
+        };
     }
 
     /// Sets the given altitude to the point.
@@ -206,11 +254,151 @@ impl GeographicPoint {
 
     /// Computes the [great-circle distance](https://en.wikipedia.org/wiki/Great-circle_distance) from self to the given point (in radiants).
     pub fn distance(&self, other: &GeographicPoint) -> f64 {
-        (self.latitude().sin() * other.latitude().sin()
-            + self.latitude().cos()
-                * other.latitude().cos()
-                * (self.longitude() - other.longitude()).abs().cos())
-        .acos()
+        // The arctangent formulation keeps its precision for both close and
+        // antipodal points, where the arccosine one does not.
+        let (from_sin, from_cos) = self.latitude().sin_cos();
+        let (to_sin, to_cos) = other.latitude().sin_cos();
+        let (delta_sin, delta_cos) = (self.longitude() - other.longitude()).abs().sin_cos();
+
+        (to_cos * delta_sin)
+            .hypot(from_cos * to_sin - from_sin * to_cos * delta_cos)
+            .atan2(from_sin * to_sin + from_cos * to_cos * delta_cos)
+    }
+
+    /// Computes the initial bearing (in radiants, clockwise from the north) of
+    /// the great-circle path going from self to the given point, in the range
+    /// __(-π, +π]__.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::GeographicPoint;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let from = GeographicPoint::default();
+    /// let to = GeographicPoint::default().with_longitude(1.);
+    ///
+    /// assert!(approx_eq!(f64, from.initial_bearing(&to), FRAC_PI_2, ulps = 2));
+    /// ```
+    pub fn initial_bearing(&self, other: &GeographicPoint) -> f64 {
+        // see: https://www.movable-type.co.uk/scripts/latlong.html
+        let delta = other.longitude() - self.longitude();
+
+        let y = delta.sin() * other.latitude().cos();
+        let x = self.latitude().cos() * other.latitude().sin()
+            - self.latitude().sin() * other.latitude().cos() * delta.cos();
+
+        let bearing = y.atan2(x);
+        if bearing == -PI {
+            // heading south from a negative zero delta
+            return PI;
+        }
+
+        bearing
+    }
+
+    /// Computes the final bearing (in radiants, clockwise from the north) of the
+    /// great-circle path going from self to the given point, which is the
+    /// direction of travel when arriving to the given point, in the range
+    /// __(-π, +π]__.
+    pub fn final_bearing(&self, other: &GeographicPoint) -> f64 {
+        let bearing = other.initial_bearing(self);
+        if bearing > 0. {
+            bearing - PI
+        } else {
+            bearing + PI
+        }
+    }
+
+    /// Returns the point reached after traveling the given angular distance (in
+    /// radiants) along the great circle that leaves self with the given bearing
+    /// (in radiants, clockwise from the north). The altitude is preserved.
+    pub fn destination(&self, bearing: f64, distance: f64) -> GeographicPoint {
+        let (lat_sin, lat_cos) = self.latitude().sin_cos();
+        let (dist_sin, dist_cos) = distance.sin_cos();
+        let (bearing_sin, bearing_cos) = bearing.sin_cos();
+
+        let latitude = (lat_sin * dist_cos + lat_cos * dist_sin * bearing_cos).asin();
+        let delta = (bearing_sin * dist_sin * lat_cos).atan2(dist_cos - lat_sin * latitude.sin());
+
+        GeographicPoint::default()
+            .with_longitude(self.longitude() + delta)
+            .with_latitude(latitude)
+            .with_altitude(self.altitude())
+    }
+
+    /// Returns the point halfway along the great-circle path going from self to
+    /// the given point. See [`GeographicPoint::interpolate`].
+    pub fn midpoint(&self, other: &GeographicPoint) -> GeographicPoint {
+        self.interpolate(other, 0.5)
+    }
+
+    /// Returns the point at the given fraction of the great-circle path going
+    /// from self to the given point, being `0` self and `1` the given point. The
+    /// altitude is linearly interpolated.
+    ///
+    /// Since there are infinitely many great circles passing through two
+    /// antipodal points, in such a case the one passing through the north pole is
+    /// taken, or the prime meridian if both points are poles.
+    pub fn interpolate(&self, other: &GeographicPoint, fraction: f64) -> GeographicPoint {
+        let from = CartesianPoint::direction_of(self);
+        let to = CartesianPoint::direction_of(other);
+
+        GeographicPoint::from_cartesian(&from.slerp(&to, fraction))
+            .with_altitude(self.altitude() + (other.altitude() - self.altitude()) * fraction)
+    }
+
+    /// Returns the given amount of points evenly spaced along the great-circle
+    /// path going from self to the given point, both included.
+    /// See [`GeographicPoint::interpolate`].
+    pub fn densify(&self, other: &GeographicPoint, count: usize) -> Vec<GeographicPoint> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![*self],
+            count => (0..count)
+                .map(|index| match index {
+                    0 => *self,
+                    index if index == count - 1 => *other,
+                    index => self.interpolate(other, index as f64 / (count - 1) as f64),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the minimum amount of evenly spaced points along the great-circle
+    /// path going from self to the given point, both included, such that no pair
+    /// of consecutive points is farther than the given angular distance (in
+    /// radiants). See [`GeographicPoint::interpolate`].
+    ///
+    /// Returns `None` if the step is not a finite positive number, or if the
+    /// path would be split in more than [`MAX_DENSIFY_SEGMENTS`] segments.
+    pub fn densify_by_step(
+        &self,
+        other: &GeographicPoint,
+        step: f64,
+    ) -> Option<Vec<GeographicPoint>> {
+        if !step.is_finite() || step <= 0. {
+            return None;
+        }
+
+        let segments = (self.distance(other) / step).ceil().max(1.);
+        if segments > MAX_DENSIFY_SEGMENTS as f64 {
+            return None;
+        }
+
+        Some(self.densify(other, segments as usize + 1))
+    }
+}
+
+/// Returns the angle (in radiants) from the reference longitude to the given
+/// one, in the range __[-π, +π]__. Offsets already in range are kept as they
+/// are, so that a point on the antimeridian keeps its side.
+pub(crate) fn longitude_offset(longitude: f64, reference: f64) -> f64 {
+    let offset = longitude - reference;
+    if (-PI..=PI).contains(&offset) {
+        offset
+    } else {
+        (offset + PI).rem_euclid(2. * PI) - PI
     }
 }
 
@@ -589,6 +777,22 @@ mod tests {
                 to: GeographicPoint::default(),
                 distance: 0.,
             },
+            TestCase {
+                name: "Same point off the equator must be zero",
+                from: GeographicPoint::default()
+                    .with_longitude(1.)
+                    .with_latitude(0.7),
+                to: GeographicPoint::default()
+                    .with_longitude(1.)
+                    .with_latitude(0.7),
+                distance: 0.,
+            },
+            TestCase {
+                name: "Close points must keep their precision",
+                from: GeographicPoint::default(),
+                to: GeographicPoint::default().with_longitude(1e-9),
+                distance: 1e-9,
+            },
             TestCase {
                 name: "Oposite points in the horizontal",
                 from: GeographicPoint::default(),
@@ -615,4 +819,406 @@ mod tests {
             )
         });
     }
+
+    #[test]
+    fn bearing_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            from: GeographicPoint,
+            to: GeographicPoint,
+            initial: f64,
+            last: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "heading east along the equator",
+                from: GeographicPoint::default(),
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                initial: FRAC_PI_2,
+                last: FRAC_PI_2,
+            },
+            TestCase {
+                name: "heading north along a meridian",
+                from: GeographicPoint::default().with_longitude(1.),
+                to: GeographicPoint::default()
+                    .with_longitude(1.)
+                    .with_latitude(FRAC_PI_2 / 2.),
+                initial: 0.,
+                last: 0.,
+            },
+            TestCase {
+                name: "heading south along the negative zero meridian",
+                from: GeographicPoint::default(),
+                to: GeographicPoint::default()
+                    .with_longitude(-0.)
+                    .with_latitude(-FRAC_PI_2 / 2.),
+                initial: PI,
+                last: PI,
+            },
+            TestCase {
+                name: "heading west across the antimeridian",
+                from: GeographicPoint::default().with_longitude(-PI + 0.1),
+                to: GeographicPoint::default().with_longitude(PI - 0.1),
+                initial: -FRAC_PI_2,
+                last: -FRAC_PI_2,
+            },
+            TestCase {
+                name: "heading east across the antimeridian",
+                from: GeographicPoint::default().with_longitude(PI - 0.1),
+                to: GeographicPoint::default().with_longitude(-PI + 0.1),
+                initial: FRAC_PI_2,
+                last: FRAC_PI_2,
+            },
+            TestCase {
+                name: "heading north-east from the equator",
+                from: GeographicPoint::default(),
+                to: GeographicPoint::default()
+                    .with_longitude(FRAC_PI_2)
+                    .with_latitude(FRAC_PI_2 / 2.),
+                initial: FRAC_PI_2 / 2.,
+                last: FRAC_PI_2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let initial = test_case.from.initial_bearing(&test_case.to);
+            assert!(
+                approx_eq!(f64, initial, test_case.initial, epsilon = 1e-15),
+                "{}: initial bearing {} ±ε = {}",
+                test_case.name,
+                initial,
+                test_case.initial
+            );
+
+            let last = test_case.from.final_bearing(&test_case.to);
+            assert!(
+                approx_eq!(f64, last, test_case.last, epsilon = 1e-15),
+                "{}: final bearing {} ±ε = {}",
+                test_case.name,
+                last,
+                test_case.last
+            );
+        });
+    }
+
+    #[test]
+    fn destination_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            from: GeographicPoint,
+            bearing: f64,
+            distance: f64,
+            to: GeographicPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "no distance must not move the point",
+                from: GeographicPoint::default()
+                    .with_latitude(1.)
+                    .with_altitude(2.),
+                bearing: 1.,
+                distance: 0.,
+                to: GeographicPoint::default()
+                    .with_latitude(1.)
+                    .with_altitude(2.),
+            },
+            TestCase {
+                name: "moving north to the pole",
+                from: GeographicPoint::default(),
+                bearing: 0.,
+                distance: FRAC_PI_2,
+                to: GeographicPoint::default().with_latitude(FRAC_PI_2),
+            },
+            TestCase {
+                name: "moving east across the antimeridian",
+                from: GeographicPoint::default().with_longitude(PI - 0.1),
+                bearing: FRAC_PI_2,
+                distance: 0.2,
+                to: GeographicPoint::default().with_longitude(-PI + 0.1),
+            },
+            TestCase {
+                name: "moving west towards the equator",
+                from: GeographicPoint::default()
+                    .with_longitude(FRAC_PI_2)
+                    .with_latitude(FRAC_PI_2 / 2.),
+                bearing: -FRAC_PI_2,
+                distance: FRAC_PI_2,
+                to: GeographicPoint::default(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = test_case
+                .from
+                .destination(test_case.bearing, test_case.distance);
+            assert!(
+                point.distance(&test_case.to) < 1e-7,
+                "{}: {:?} ±ε = {:?}",
+                test_case.name,
+                point,
+                test_case.to
+            );
+
+            assert_eq!(
+                point.altitude(),
+                test_case.from.altitude(),
+                "{}: altitude must be preserved",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn interpolate_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            from: GeographicPoint,
+            to: GeographicPoint,
+            fraction: f64,
+            want: GeographicPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "midpoint along the equator",
+                from: GeographicPoint::default(),
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                fraction: 0.5,
+                want: GeographicPoint::default().with_longitude(FRAC_PI_2 / 2.),
+            },
+            TestCase {
+                name: "midpoint across the antimeridian",
+                from: GeographicPoint::default().with_longitude(PI - 0.1),
+                to: GeographicPoint::default().with_longitude(-PI + 0.1),
+                fraction: 0.5,
+                want: GeographicPoint::default().with_longitude(PI),
+            },
+            TestCase {
+                name: "altitude must be interpolated",
+                from: GeographicPoint::default().with_altitude(10.),
+                to: GeographicPoint::default()
+                    .with_latitude(FRAC_PI_2)
+                    .with_altitude(20.),
+                fraction: 0.25,
+                want: GeographicPoint::default()
+                    .with_latitude(FRAC_PI_2 / 4.)
+                    .with_altitude(12.5),
+            },
+            TestCase {
+                name: "coincident points must not move",
+                from: GeographicPoint::default().with_latitude(1.),
+                to: GeographicPoint::default().with_latitude(1.),
+                fraction: 0.7,
+                want: GeographicPoint::default().with_latitude(1.),
+            },
+            TestCase {
+                name: "antipodal points must pass through the north pole",
+                from: GeographicPoint::default().with_longitude(1.),
+                to: GeographicPoint::default().with_longitude(1. - PI),
+                fraction: 0.5,
+                want: GeographicPoint::default().with_latitude(FRAC_PI_2),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = test_case
+                .from
+                .interpolate(&test_case.to, test_case.fraction);
+            assert!(
+                point.distance(&test_case.want) < 1e-7,
+                "{}: {:?} ±ε = {:?}",
+                test_case.name,
+                point,
+                test_case.want
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    point.altitude(),
+                    test_case.want.altitude(),
+                    ulps = ULPS
+                ),
+                "{}: altitude {} ±ε = {}",
+                test_case.name,
+                point.altitude(),
+                test_case.want.altitude()
+            );
+        });
+    }
+
+    #[test]
+    fn longitude_offset_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            longitude: f64,
+            reference: f64,
+            want: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "offset in range must be kept",
+                longitude: 1.,
+                reference: -1.,
+                want: 2.,
+            },
+            TestCase {
+                name: "lower bound must be kept",
+                longitude: -PI,
+                reference: 0.,
+                want: -PI,
+            },
+            TestCase {
+                name: "upper bound must be kept",
+                longitude: PI,
+                reference: 0.,
+                want: PI,
+            },
+            TestCase {
+                name: "offset across the antimeridian must wrap",
+                longitude: -3.,
+                reference: 3.,
+                want: 2. * PI - 6.,
+            },
+            TestCase {
+                name: "offset across the antimeridian the other way must wrap",
+                longitude: 3.,
+                reference: -3.,
+                want: 6. - 2. * PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let offset = longitude_offset(test_case.longitude, test_case.reference);
+            assert!(
+                approx_eq!(f64, offset, test_case.want, epsilon = 1e-15),
+                "{}: {} ±ε = {}",
+                test_case.name,
+                offset,
+                test_case.want
+            );
+        });
+    }
+
+    #[test]
+    fn densify_must_not_fail() {
+        let from = GeographicPoint::default();
+        let to = GeographicPoint::default().with_longitude(FRAC_PI_2);
+
+        assert!(from.densify(&to, 0).is_empty(), "no points must be empty");
+        assert_eq!(
+            from.densify(&to, 1),
+            vec![from],
+            "a single point must be self"
+        );
+
+        let points = from.densify(&to, 4);
+        assert_eq!(points.len(), 4, "must return the given amount of points");
+        assert_eq!(points.first(), Some(&from), "must start at self");
+        assert_eq!(points.last(), Some(&to), "must end at the given point");
+        points.windows(2).for_each(|pair| {
+            assert!(
+                approx_eq!(
+                    f64,
+                    pair[0].distance(&pair[1]),
+                    FRAC_PI_2 / 3.,
+                    epsilon = 1e-15
+                ),
+                "points must be evenly spaced: {:?}",
+                pair
+            );
+        });
+    }
+
+    #[test]
+    fn densify_by_step_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            to: GeographicPoint,
+            step: f64,
+            want: Option<usize>,
+        }
+
+        vec![
+            TestCase {
+                name: "A quarter of the equator in steps of half a radiant",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: 0.5,
+                want: Some(5),
+            },
+            TestCase {
+                name: "A step longer than the path must keep the endpoints",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: PI,
+                want: Some(2),
+            },
+            TestCase {
+                name: "The same point must keep the endpoints",
+                to: GeographicPoint::default(),
+                step: 0.5,
+                want: Some(2),
+            },
+            TestCase {
+                name: "Zero step must be rejected",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: 0.,
+                want: None,
+            },
+            TestCase {
+                name: "Negative step must be rejected",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: -0.5,
+                want: None,
+            },
+            TestCase {
+                name: "Not a number step must be rejected",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: f64::NAN,
+                want: None,
+            },
+            TestCase {
+                name: "Infinite step must be rejected",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: f64::INFINITY,
+                want: None,
+            },
+            TestCase {
+                name: "Too many segments must be rejected",
+                to: GeographicPoint::default().with_longitude(FRAC_PI_2),
+                step: 1e-300,
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let from = GeographicPoint::default();
+            let points = from.densify_by_step(&test_case.to, test_case.step);
+
+            assert_eq!(
+                points.as_ref().map(Vec::len),
+                test_case.want,
+                "{}: got points = {:?}",
+                test_case.name,
+                points
+            );
+
+            let Some(points) = points else {
+                return;
+            };
+
+            assert_eq!(points.first(), Some(&from), "{}", test_case.name);
+            assert_eq!(points.last(), Some(&test_case.to), "{}", test_case.name);
+            points.windows(2).for_each(|pair| {
+                assert!(
+                    pair[0].distance(&pair[1]) <= test_case.step,
+                    "{}: steps must not exceed the given one: {:?}",
+                    test_case.name,
+                    pair
+                );
+            });
+        });
+    }
 }
@@ -0,0 +1,552 @@
+use crate::{GeoBoundingBox, GeographicPoint, SphericalCap};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The alphabet of the base 32 encoding used by geohashes.
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The maximum amount of characters of an encoded geohash, beyond which cells
+/// are smaller than the precision of a double.
+const MAX_PRECISION: usize = 22;
+
+/// Represents an error while parsing a [`Geohash`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeohashError {
+    /// The string has no characters.
+    Empty,
+    /// The character at the given position is not part of the alphabet.
+    InvalidCharacter(char, usize),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeohashError::Empty => write!(f, "geohash must not be empty"),
+            GeohashError::InvalidCharacter(character, position) => write!(
+                f,
+                "invalid geohash character {character:?} at position {position}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+/// Represents a [geohash](https://en.wikipedia.org/wiki/Geohash): a cell of a
+/// grid of latitudes and longitudes encoded as a base 32 string, whose length
+/// is the precision of the cell.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Geohash(String);
+
+impl fmt::Display for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Geohash {
+    type Err = GeohashError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(GeohashError::Empty);
+        }
+
+        value
+            .chars()
+            .enumerate()
+            .map(|(position, character)| {
+                let lowercase = character.to_ascii_lowercase();
+                if lowercase.is_ascii() && ALPHABET.contains(&(lowercase as u8)) {
+                    Ok(lowercase)
+                } else {
+                    Err(GeohashError::InvalidCharacter(character, position))
+                }
+            })
+            .collect::<Result<String, _>>()
+            .map(Self)
+    }
+}
+
+#[wasm_bindgen]
+impl Geohash {
+    /// Returns the geohash of the given amount of characters containing the
+    /// given point. The precision is clamped to __[1, 22]__ characters.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{Geohash, GeographicPoint};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(10.40744_f64.to_radians())
+    ///     .with_latitude(57.64911_f64.to_radians());
+    ///
+    /// assert_eq!(Geohash::encode(&point, 11).to_string(), "u4pruydqqvj");
+    /// ```
+    pub fn encode(point: &GeographicPoint, precision: usize) -> Geohash {
+        let precision = precision.clamp(1, MAX_PRECISION);
+        let (mut west, mut east) = (-180., 180.);
+        let (mut south, mut north) = (-90., 90.);
+        let longitude = point.longitude().to_degrees();
+        let latitude = point.latitude().to_degrees();
+
+        let hash = (0..precision)
+            .map(|index| {
+                let value = (0..5).fold(0, |value, bit| {
+                    // bits are interleaved starting by the longitude one
+                    let is_longitude = (index * 5 + bit) % 2 == 0;
+                    let (target, low, high) = if is_longitude {
+                        (longitude, &mut west, &mut east)
+                    } else {
+                        (latitude, &mut south, &mut north)
+                    };
+
+                    let middle = (*low + *high) / 2.;
+                    if target >= middle {
+                        *low = middle;
+                        value << 1 | 1
+                    } else {
+                        *high = middle;
+                        value << 1
+                    }
+                });
+
+                ALPHABET[value] as char
+            })
+            .collect();
+
+        Geohash(hash)
+    }
+
+    /// Returns the geohashes of the given precision covering the given
+    /// [`GeoBoundingBox`]. The precision is clamped to __[1, 22]__ characters,
+    /// and lowered as much as required for the covering not to exceed the given
+    /// amount of cells. The covering may only have more cells than that if it
+    /// does at the lowest precision too.
+    pub fn cover_box(
+        bounding_box: &GeoBoundingBox,
+        precision: usize,
+        max_cells: usize,
+    ) -> Vec<Geohash> {
+        let (precision, grid) = (1..=precision.clamp(1, MAX_PRECISION))
+            .rev()
+            .map(|precision| (precision, Grid::new(bounding_box, precision)))
+            .find(|(_, grid)| grid.columns.saturating_mul(grid.rows) <= max_cells)
+            .unwrap_or_else(|| (1, Grid::new(bounding_box, 1)));
+
+        let (width, height) = cell_size(precision);
+        (0..grid.rows)
+            .flat_map(|row| (0..grid.columns).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let longitude = (grid.west + column as f64 + 0.5) * width - 180.;
+                let latitude = (grid.south + row as f64 + 0.5) * height - 90.;
+
+                Geohash::encode(
+                    &GeographicPoint::default()
+                        .with_longitude(longitude.to_radians())
+                        .with_latitude(latitude.to_radians()),
+                    precision,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the geohashes of the given precision covering the given
+    /// [`SphericalCap`]. See [`Geohash::cover_box`].
+    pub fn cover_cap(cap: &SphericalCap, precision: usize, max_cells: usize) -> Vec<Geohash> {
+        Self::cover_box(&cap.bounding_box(), precision, max_cells)
+            .into_iter()
+            .filter(|geohash| geohash.bounding_box().distance(&cap.center()) <= cap.radius())
+            .collect()
+    }
+
+    /// Returns the amount of characters of the geohash.
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the center of the cell.
+    pub fn center(&self) -> GeographicPoint {
+        let bounding_box = self.bounding_box();
+        GeographicPoint::default()
+            .with_longitude((bounding_box.west() + bounding_box.east()) / 2.)
+            .with_latitude((bounding_box.south() + bounding_box.north()) / 2.)
+    }
+
+    /// Returns the bounds of the cell, which are the error margins of its center.
+    pub fn bounding_box(&self) -> GeoBoundingBox {
+        let (mut west, mut east) = (-180., 180.);
+        let (mut south, mut north) = (-90., 90.);
+
+        self.0
+            .bytes()
+            .filter_map(|character| ALPHABET.iter().position(|&c| c == character))
+            .enumerate()
+            .for_each(|(index, value)| {
+                (0..5).for_each(|bit| {
+                    let is_longitude = (index * 5 + bit) % 2 == 0;
+                    let (low, high) = if is_longitude {
+                        (&mut west, &mut east)
+                    } else {
+                        (&mut south, &mut north)
+                    };
+
+                    let middle = (*low + *high) / 2.;
+                    if value >> (4 - bit) & 1 == 1 {
+                        *low = middle;
+                    } else {
+                        *high = middle;
+                    }
+                });
+            });
+
+        GeoBoundingBox::new(
+            f64::to_radians(west),
+            f64::to_radians(south),
+            f64::to_radians(east),
+            f64::to_radians(north),
+        )
+    }
+
+    /// Returns the adjacent cells of the same precision, clockwise from the
+    /// north one. Cells beyond any of the poles do not exist, and so they are
+    /// skipped.
+    pub fn neighbours(&self) -> Vec<Geohash> {
+        let (width, height) = cell_size(self.precision());
+        let center = self.center();
+
+        [
+            (0., 1.),
+            (1., 1.),
+            (1., 0.),
+            (1., -1.),
+            (0., -1.),
+            (-1., -1.),
+            (-1., 0.),
+            (-1., 1.),
+        ]
+        .into_iter()
+        .filter_map(|(columns, rows): (f64, f64)| {
+            let latitude = center.latitude().to_degrees() + rows * height;
+            if latitude.abs() > 90. {
+                return None;
+            }
+
+            let longitude = center.longitude().to_degrees() + columns * width;
+            Some(Geohash::encode(
+                &GeographicPoint::default()
+                    .with_longitude(longitude.to_radians())
+                    .with_latitude(latitude.to_radians()),
+                self.precision(),
+            ))
+        })
+        .collect()
+    }
+}
+
+/// The cells of a given precision covering a bounding box.
+struct Grid {
+    /// The index of the westernmost column.
+    west: f64,
+    /// The index of the southernmost row.
+    south: f64,
+    columns: usize,
+    rows: usize,
+}
+
+impl Grid {
+    fn new(bounding_box: &GeoBoundingBox, precision: usize) -> Self {
+        let (width, height) = cell_size(precision);
+        let last_row = 180. / height - 1.;
+
+        let west = bounding_box.west().to_degrees() + 180.;
+        let east = west + bounding_box.width().to_degrees();
+        // the pole belongs to the last row, so no row may start beyond it
+        let south = ((bounding_box.south().to_degrees() + 90.) / height)
+            .floor()
+            .min(last_row);
+        let north = ((bounding_box.north().to_degrees() + 90.) / height)
+            .floor()
+            .min(last_row);
+
+        let columns = ((east / width).floor() - (west / width).floor()) as usize + 1;
+        Self {
+            west: (west / width).floor(),
+            south,
+            columns: columns.min((360. / width).round() as usize),
+            rows: (north - south) as usize + 1,
+        }
+    }
+}
+
+/// Returns the width and height (in degrees) of the cells of the given
+/// precision, clamped to __[0, 22]__ characters.
+fn cell_size(precision: usize) -> (f64, f64) {
+    let bits = 5 * precision.min(MAX_PRECISION) as i32;
+    let longitude_bits = (bits + 1) / 2;
+    let latitude_bits = bits / 2;
+
+    (
+        360. / 2_f64.powi(longitude_bits),
+        180. / 2_f64.powi(latitude_bits),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn encode_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            precision: usize,
+            hash: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "jutland",
+                point: from_degrees(10.40744, 57.64911),
+                precision: 11,
+                hash: "u4pruydqqvj",
+            },
+            TestCase {
+                name: "origin of coordinates",
+                point: from_degrees(0., 0.),
+                precision: 5,
+                hash: "s0000",
+            },
+            TestCase {
+                name: "south west corner",
+                point: from_degrees(-180., -90.),
+                precision: 3,
+                hash: "000",
+            },
+            TestCase {
+                name: "no precision is clamped",
+                point: from_degrees(12., 34.),
+                precision: 0,
+                hash: "s",
+            },
+            TestCase {
+                name: "huge precision is clamped",
+                point: from_degrees(0., 0.),
+                precision: usize::MAX,
+                hash: "s000000000000000000000",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let hash = Geohash::encode(&test_case.point, test_case.precision);
+            assert_eq!(hash.to_string(), test_case.hash, "{}", test_case.name);
+        });
+    }
+
+    #[test]
+    fn decode_must_not_fail() {
+        let geohash: Geohash = "ezs42".parse().unwrap();
+        let bounding_box = geohash.bounding_box();
+        let center = geohash.center();
+
+        assert!(
+            approx_eq!(
+                f64,
+                center.latitude().to_degrees(),
+                42.60498046875,
+                epsilon = 1e-9
+            ),
+            "latitude {}",
+            center.latitude().to_degrees()
+        );
+
+        assert!(
+            approx_eq!(
+                f64,
+                center.longitude().to_degrees(),
+                -5.60302734375,
+                epsilon = 1e-9
+            ),
+            "longitude {}",
+            center.longitude().to_degrees()
+        );
+
+        assert!(
+            approx_eq!(
+                f64,
+                (bounding_box.north() - bounding_box.south()).to_degrees(),
+                180. / 2_f64.powi(12),
+                epsilon = 1e-12
+            ),
+            "latitude error {:?}",
+            bounding_box
+        );
+
+        assert_eq!(
+            Geohash::encode(&center, 5),
+            geohash,
+            "center must be encoded into the same geohash"
+        );
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        assert_eq!("".parse::<Geohash>(), Err(GeohashError::Empty), "empty");
+        assert_eq!(
+            "u4pa".parse::<Geohash>(),
+            Err(GeohashError::InvalidCharacter('a', 3)),
+            "invalid character"
+        );
+        assert_eq!(
+            "U4PRU".parse::<Geohash>().map(|hash| hash.to_string()),
+            Ok("u4pru".to_string()),
+            "uppercase"
+        );
+    }
+
+    #[test]
+    fn neighbours_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            hash: &'static str,
+            neighbours: Vec<&'static str>,
+        }
+
+        vec![
+            TestCase {
+                name: "regular cell",
+                hash: "ezs42",
+                neighbours: vec![
+                    "ezs48", "ezs49", "ezs43", "ezs41", "ezs40", "ezefp", "ezefr", "ezefx",
+                ],
+            },
+            TestCase {
+                name: "cell on the antimeridian",
+                hash: "2",
+                neighbours: vec!["8", "9", "3", "1", "0", "p", "r", "x"],
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let geohash: Geohash = test_case.hash.parse().unwrap();
+            let neighbours: Vec<String> = geohash
+                .neighbours()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+
+            assert_eq!(neighbours, test_case.neighbours, "{}", test_case.name);
+        });
+    }
+
+    #[test]
+    fn cover_must_not_fail() {
+        let bounding_box = GeoBoundingBox::new(
+            170_f64.to_radians(),
+            -10_f64.to_radians(),
+            -170_f64.to_radians(),
+            10_f64.to_radians(),
+        );
+
+        let cover = Geohash::cover_box(&bounding_box, 2, usize::MAX);
+        assert!(!cover.is_empty(), "cover must not be empty");
+        cover.iter().for_each(|geohash| {
+            assert!(
+                geohash.bounding_box().intersects(&bounding_box),
+                "cell {} must intersect the box",
+                geohash
+            );
+        });
+
+        [
+            from_degrees(170., -10.),
+            from_degrees(180., 0.),
+            from_degrees(-170., 10.),
+        ]
+        .iter()
+        .for_each(|point| {
+            let geohash = Geohash::encode(point, 2);
+            assert!(
+                cover.contains(&geohash),
+                "cover must contain the cell {} of {:?}",
+                geohash,
+                point
+            );
+        });
+
+        let cover = Geohash::cover_box(&bounding_box, 0, usize::MAX);
+        assert_eq!(cover, Geohash::cover_box(&bounding_box, 1, usize::MAX));
+        assert!(cover.iter().all(|geohash| geohash.precision() == 1));
+
+        let cap = SphericalCap::new(from_degrees(10., 45.), 0.01);
+        let cover = Geohash::cover_cap(&cap, 5, usize::MAX);
+        assert!(
+            cover.contains(&Geohash::encode(&cap.center(), 5)),
+            "cover must contain the cell of the center"
+        );
+
+        cap.boundary(32).exterior().iter().for_each(|point| {
+            assert!(
+                cover.contains(&Geohash::encode(point, 5)),
+                "cover must contain the cell of {:?}",
+                point
+            );
+        });
+
+        assert!(
+            cover.len() < Geohash::cover_box(&cap.bounding_box(), 5, usize::MAX).len(),
+            "cover of a cap must skip the cells of its box outside of it"
+        );
+
+        let pole = GeoBoundingBox::new(0., FRAC_PI_2, 10_f64.to_radians(), FRAC_PI_2);
+        let cover = Geohash::cover_box(&pole, 3, usize::MAX);
+        assert!(!cover.is_empty(), "cover of the pole must not be empty");
+        cover.iter().for_each(|geohash| {
+            assert!(
+                approx_eq!(f64, geohash.bounding_box().north(), FRAC_PI_2),
+                "cell {} of the pole must be in the last row",
+                geohash
+            );
+        });
+    }
+
+    #[test]
+    fn cover_must_not_exceed_max_cells() {
+        let bounding_box = GeoBoundingBox::new(
+            -10_f64.to_radians(),
+            -10_f64.to_radians(),
+            10_f64.to_radians(),
+            10_f64.to_radians(),
+        );
+
+        [4, 8, 64, 1000].into_iter().for_each(|max_cells| {
+            let cover = Geohash::cover_box(&bounding_box, usize::MAX, max_cells);
+            assert!(
+                cover.len() <= max_cells,
+                "covering of {} cells must not exceed {max_cells}",
+                cover.len()
+            );
+
+            [from_degrees(-10., -10.), from_degrees(10., 10.)]
+                .iter()
+                .for_each(|point| {
+                    let geohash = Geohash::encode(point, cover[0].precision());
+                    assert!(
+                        cover.contains(&geohash),
+                        "covering of at most {max_cells} cells must contain {point:?}"
+                    );
+                });
+        });
+
+        let cover = Geohash::cover_box(&bounding_box, 5, 0);
+        assert!(
+            cover.iter().all(|geohash| geohash.precision() == 1),
+            "covering must not be finer than the lowest precision"
+        );
+    }
+}
@@ -0,0 +1,1198 @@
+use crate::{
+    geometry::{clip, point_from_degrees, TextReader, MAX_PRECISION},
+    GeographicPoint, Geometry,
+};
+use serde_json::{Map, Number, Value};
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+/// The longitude (in degrees) of the antimeridian.
+const ANTIMERIDIAN: f64 = 180.;
+
+/// A position as written in GeoJSON: longitude and latitude (in degrees), and
+/// altitude.
+type Position = [f64; 3];
+
+/// Represents an error while reading GeoJSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoJsonError {
+    /// The JSON at the given byte offset is not well formed.
+    InvalidJson(String, usize),
+    /// The object has no member of the given name.
+    MissingMember(&'static str),
+    /// The value is of the given type instead of the expected one.
+    UnexpectedType(String, &'static str),
+    /// The given position is not an array of a longitude and a latitude in
+    /// range, optionally followed by an altitude.
+    InvalidPosition(String),
+    /// The feature at the given byte offset is not valid.
+    InvalidFeature(usize, Box<GeoJsonError>),
+    /// The underlying reader failed.
+    Io(String),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::InvalidJson(message, offset) => {
+                write!(f, "invalid json at offset {offset}: {message}")
+            }
+            GeoJsonError::MissingMember(member) => {
+                write!(f, "missing member {member:?}")
+            }
+            GeoJsonError::UnexpectedType(found, expected) => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            GeoJsonError::InvalidPosition(position) => {
+                write!(f, "invalid position {position}")
+            }
+            GeoJsonError::InvalidFeature(offset, error) => {
+                write!(f, "invalid feature at offset {offset}: {error}")
+            }
+            GeoJsonError::Io(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl From<io::Error> for GeoJsonError {
+    fn from(error: io::Error) -> Self {
+        GeoJsonError::Io(error.to_string())
+    }
+}
+
+/// Represents a GeoJSON feature: an optional geometry with an optional
+/// identifier and any amount of properties.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Feature {
+    id: Option<Value>,
+    geometry: Option<Geometry>,
+    properties: Map<String, Value>,
+}
+
+impl Feature {
+    /// Sets the identifier of the feature, which should be either a string or
+    /// a number.
+    pub fn with_id(mut self, id: impl Into<Value>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the geometry of the feature.
+    pub fn with_geometry(mut self, geometry: Geometry) -> Self {
+        self.geometry = Some(geometry);
+        self
+    }
+
+    /// Sets the given property of the feature.
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the identifier of the feature, if any.
+    pub fn id(&self) -> Option<&Value> {
+        self.id.as_ref()
+    }
+
+    /// Returns the geometry of the feature, if any.
+    pub fn geometry(&self) -> Option<&Geometry> {
+        self.geometry.as_ref()
+    }
+
+    /// Returns the value of the given property, if any.
+    pub fn property(&self, key: &str) -> Option<&Value> {
+        self.properties.get(key)
+    }
+
+    /// Returns all the properties of the feature.
+    pub fn properties(&self) -> &Map<String, Value> {
+        &self.properties
+    }
+}
+
+/// Represents the way geometries and features are written as
+/// [GeoJSON](https://datatracker.ietf.org/doc/html/rfc7946).
+///
+/// Positions are written as longitude and latitude in degrees, optionally
+/// followed by the altitude. As recommended by the specification, geometries
+/// crossing the antimeridian are cut in two by default, so lines and polygons
+/// become their multi-variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeoJson {
+    precision: usize,
+    altitude: bool,
+    antimeridian_cut: bool,
+}
+
+impl Default for GeoJson {
+    fn default() -> Self {
+        Self {
+            precision: 9,
+            altitude: false,
+            antimeridian_cut: true,
+        }
+    }
+}
+
+impl GeoJson {
+    /// Sets the maximum amount of decimal places of the coordinates, up to 15.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(MAX_PRECISION);
+        self
+    }
+
+    /// Sets whether positions include the altitude.
+    pub fn with_altitude(mut self, altitude: bool) -> Self {
+        self.altitude = altitude;
+        self
+    }
+
+    /// Sets whether lines and polygons crossing the antimeridian are cut in
+    /// two.
+    pub fn with_antimeridian_cut(mut self, antimeridian_cut: bool) -> Self {
+        self.antimeridian_cut = antimeridian_cut;
+        self
+    }
+
+    /// Returns the given geometry as a GeoJSON object.
+    pub fn geometry(&self, geometry: &Geometry) -> Value {
+        let (kind, coordinates) = self.members(geometry);
+        let mut object = Map::new();
+        object.insert("type".to_string(), kind.into());
+        object.insert("coordinates".to_string(), coordinates);
+        Value::Object(object)
+    }
+
+    /// Returns the given geometry as GeoJSON, its type being the first member
+    /// as in features.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, GeoJson, Geometry};
+    ///
+    /// let from = GeographicPoint::default()
+    ///     .with_longitude(170_f64.to_radians());
+    /// let to = GeographicPoint::default()
+    ///     .with_longitude((-170_f64).to_radians())
+    ///     .with_latitude(10_f64.to_radians());
+    ///
+    /// assert_eq!(
+    ///     GeoJson::default().format_geometry(&Geometry::LineString(vec![from, to])),
+    ///     r#"{"type":"MultiLineString","coordinates":[[[170.0,0.0],[180.0,5.0]],[[-180.0,5.0],[-170.0,10.0]]]}"#
+    /// );
+    /// ```
+    pub fn format_geometry(&self, geometry: &Geometry) -> String {
+        let (kind, coordinates) = self.members(geometry);
+        format!(r#"{{"type":"{kind}","coordinates":{coordinates}}}"#)
+    }
+
+    /// Returns the type and the coordinates of the given geometry.
+    fn members(&self, geometry: &Geometry) -> (&'static str, Value) {
+        let points = |points: &[GeographicPoint]| points.iter().map(position).collect();
+        let lines =
+            |lines: &[Vec<GeographicPoint>]| lines.iter().map(|line| points(line)).collect();
+
+        let (kind, coordinates) = match geometry {
+            Geometry::Point(point) => ("Point", self.position(&position(point))),
+            Geometry::MultiPoint(points) => (
+                "MultiPoint",
+                Value::Array(points.iter().map(|p| self.position(&position(p))).collect()),
+            ),
+            Geometry::LineString(line) => {
+                let parts = self.cut_line(points(line));
+                match <[_; 1]>::try_from(parts) {
+                    Ok([line]) => ("LineString", self.positions(&line)),
+                    Err(parts) => ("MultiLineString", self.lines(&parts)),
+                }
+            }
+            Geometry::MultiLineString(lines) => (
+                "MultiLineString",
+                self.lines(
+                    &lines
+                        .iter()
+                        .flat_map(|line| self.cut_line(points(line)))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+            Geometry::Polygon(rings) => {
+                let parts = self.cut_polygon(lines(rings));
+                match <[_; 1]>::try_from(parts) {
+                    Ok([rings]) => ("Polygon", self.lines(&rings)),
+                    Err(parts) => ("MultiPolygon", self.polygons(&parts)),
+                }
+            }
+            Geometry::MultiPolygon(polygons) => (
+                "MultiPolygon",
+                self.polygons(
+                    &polygons
+                        .iter()
+                        .flat_map(|rings| self.cut_polygon(lines(rings)))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+        };
+
+        (kind, coordinates)
+    }
+
+    /// Returns the given feature as GeoJSON.
+    pub fn format_feature(&self, feature: &Feature) -> String {
+        let mut json = String::from(r#"{"type":"Feature""#);
+        if let Some(id) = &feature.id {
+            json.push_str(&format!(r#","id":{id}"#));
+        }
+
+        let geometry = feature
+            .geometry
+            .as_ref()
+            .map(|geometry| self.format_geometry(geometry))
+            .unwrap_or_else(|| Value::Null.to_string());
+
+        json.push_str(&format!(
+            r#","geometry":{geometry},"properties":{}}}"#,
+            Value::Object(feature.properties.clone())
+        ));
+
+        json
+    }
+
+    /// Returns the given features as a GeoJSON feature collection.
+    pub fn format_collection(&self, features: &[Feature]) -> String {
+        let features: Vec<String> = features
+            .iter()
+            .map(|feature| self.format_feature(feature))
+            .collect();
+
+        format!(
+            r#"{{"type":"FeatureCollection","features":[{}]}}"#,
+            features.join(",")
+        )
+    }
+
+    /// Writes the given features as a GeoJSON feature collection, one at a
+    /// time.
+    pub fn write_collection<'a, W: Write>(
+        &self,
+        mut writer: W,
+        features: impl IntoIterator<Item = &'a Feature>,
+    ) -> io::Result<()> {
+        writer.write_all(br#"{"type":"FeatureCollection","features":["#)?;
+        for (index, feature) in features.into_iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+
+            writer.write_all(self.format_feature(feature).as_bytes())?;
+        }
+
+        writer.write_all(b"]}")?;
+        writer.flush()
+    }
+
+    /// Returns the geometry in the given GeoJSON string.
+    pub fn parse_geometry(value: &str) -> Result<Geometry, GeoJsonError> {
+        let value = serde_json::from_str(value).map_err(json_error(value.as_bytes(), 0))?;
+        geometry_from_value(&value)
+    }
+
+    /// Returns the feature in the given GeoJSON string.
+    pub fn parse_feature(value: &str) -> Result<Feature, GeoJsonError> {
+        let value = serde_json::from_str(value).map_err(json_error(value.as_bytes(), 0))?;
+        feature_from_value(&value)
+    }
+
+    /// Returns an iterator over the features of the GeoJSON feature
+    /// collection in the given reader, which are parsed one at a time, so
+    /// only one of them is in memory at once.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::GeoJson;
+    ///
+    /// let collection = br#"{
+    ///     "type": "FeatureCollection",
+    ///     "features": [
+    ///         {"type": "Feature", "geometry": null, "properties": {"name": "first"}},
+    ///         {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]}, "properties": null}
+    ///     ]
+    /// }"#;
+    ///
+    /// let features: Vec<_> = GeoJson::read_features(&collection[..])
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(features.len(), 2);
+    /// assert_eq!(features[0].property("name").unwrap(), "first");
+    /// assert!(features[1].geometry().is_some());
+    /// ```
+    pub fn read_features<R: BufRead>(reader: R) -> FeatureReader<R> {
+        FeatureReader {
+            reader,
+            offset: 0,
+            state: ReaderState::Start,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn position(&self, position: &Position) -> Value {
+        let scale = 10_f64.powi(self.precision as i32);
+        let number = |value: f64| {
+            Number::from_f64((value * scale).round() / scale + 0.)
+                .map(Value::Number)
+                .unwrap_or_default()
+        };
+
+        let length = if self.altitude { 3 } else { 2 };
+        Value::Array(
+            position[..length]
+                .iter()
+                .map(|&value| number(value))
+                .collect(),
+        )
+    }
+
+    fn positions(&self, positions: &[Position]) -> Value {
+        Value::Array(positions.iter().map(|p| self.position(p)).collect())
+    }
+
+    fn lines(&self, lines: &[Vec<Position>]) -> Value {
+        Value::Array(lines.iter().map(|line| self.positions(line)).collect())
+    }
+
+    fn polygons(&self, polygons: &[Vec<Vec<Position>>]) -> Value {
+        Value::Array(polygons.iter().map(|rings| self.lines(rings)).collect())
+    }
+
+    /// Returns the parts of the given line at each side of the antimeridian.
+    fn cut_line(&self, line: Vec<Position>) -> Vec<Vec<Position>> {
+        if !self.antimeridian_cut {
+            return vec![line];
+        }
+
+        let mut parts = Vec::new();
+        let mut part: Vec<Position> = Vec::new();
+        for position in line {
+            if let Some(&previous) = part.last() {
+                // lines always take the shortest way, so any jump longer than
+                // half a turn crosses the antimeridian
+                let delta = position[0] - previous[0];
+                if delta.abs() > ANTIMERIDIAN {
+                    let side = ANTIMERIDIAN.copysign(-delta);
+                    let mut unwrapped = position;
+                    unwrapped[0] += 2. * side;
+
+                    let cut = intersection(&previous, &unwrapped, side);
+                    if cut != previous {
+                        part.push(cut);
+                    }
+
+                    parts.push(std::mem::take(&mut part));
+                    part.push([-side, cut[1], cut[2]]);
+                }
+            }
+
+            part.push(position);
+        }
+
+        parts.push(part);
+        if parts.len() > 1 {
+            parts.retain(|part| part.len() > 1);
+        }
+
+        parts
+    }
+
+    /// Returns the polygons at each side of the antimeridian whose union is
+    /// the given one.
+    fn cut_polygon(&self, rings: Vec<Vec<Position>>) -> Vec<Vec<Vec<Position>>> {
+        if !self.antimeridian_cut || rings.is_empty() {
+            return vec![rings];
+        }
+
+        let mut unwrapped: Vec<Vec<Position>> = rings.iter().map(|ring| unwrap(ring)).collect();
+        let exterior = &unwrapped[0];
+        let (Some(first), Some(last)) = (exterior.first(), exterior.last()) else {
+            return vec![rings];
+        };
+
+        if (last[0] - first[0]).abs() > ANTIMERIDIAN {
+            // rings around a pole cannot be cut in two
+            return vec![rings];
+        }
+
+        let (west, east) = exterior
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(west, east), p| {
+                (west.min(p[0]), east.max(p[0]))
+            });
+
+        let shift = match (west < -ANTIMERIDIAN, east > ANTIMERIDIAN) {
+            (false, false) => return vec![rings],
+            (true, _) => 2. * ANTIMERIDIAN,
+            (false, true) => 0.,
+        };
+
+        // all rings are moved so the cut is always at +180
+        let reference = first[0] + shift;
+        unwrapped.iter_mut().for_each(|ring| {
+            let offset = ring.first().map(|p| reference - p[0]).unwrap_or_default();
+            let turns = (offset / (2. * ANTIMERIDIAN)).round() * 2. * ANTIMERIDIAN;
+            ring.iter_mut().for_each(|p| p[0] += turns);
+        });
+
+        let side = |keep: fn(f64) -> bool, shift: f64| {
+            let rings: Vec<Vec<Position>> = unwrapped
+                .iter()
+                .map(|ring| {
+                    let mut ring = clip_ring(ring, keep);
+                    ring.iter_mut().for_each(|p| p[0] += shift);
+                    ring
+                })
+                .collect();
+
+            // a polygon with no exterior ring has no holes either
+            let exterior_len = rings.first().map(Vec::len).unwrap_or_default();
+            (exterior_len >= 4).then(|| rings.into_iter().filter(|ring| ring.len() >= 4).collect())
+        };
+
+        [
+            side(|x| x <= ANTIMERIDIAN, 0.),
+            side(|x| x >= ANTIMERIDIAN, -2. * ANTIMERIDIAN),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Represents the progress of a [`FeatureReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReaderState {
+    Start,
+    First,
+    Next,
+    Done,
+}
+
+/// An iterator over the features of a GeoJSON feature collection, which
+/// reads them one at a time.
+///
+/// Members of the collection other than its features are skipped. Once an
+/// error is returned, the iterator yields no more features.
+pub struct FeatureReader<R> {
+    reader: R,
+    offset: usize,
+    state: ReaderState,
+    buffer: Vec<u8>,
+}
+
+impl<R: BufRead> Iterator for FeatureReader<R> {
+    type Item = Result<Feature, GeoJsonError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.advance() {
+            Ok(Some(feature)) => Some(Ok(feature)),
+            Ok(None) => {
+                self.state = ReaderState::Done;
+                None
+            }
+            Err(error) => {
+                self.state = ReaderState::Done;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<R: BufRead> FeatureReader<R> {
+    /// Returns the next feature of the collection, if any.
+    fn advance(&mut self) -> Result<Option<Feature>, GeoJsonError> {
+        if self.state == ReaderState::Start {
+            if !self.find_features()? {
+                return Ok(None);
+            }
+
+            self.state = ReaderState::First;
+        }
+
+        if self.state == ReaderState::Done {
+            return Ok(None);
+        }
+
+        self.skip_whitespace()?;
+        match (self.state, self.peek()?) {
+            (_, Some(b']')) => return Ok(None),
+            (ReaderState::Next, Some(b',')) => {
+                self.bump()?;
+                self.skip_whitespace()?;
+            }
+            (ReaderState::Next, _) => return Err(self.unexpected("',' or ']'")),
+            _ => {}
+        }
+
+        self.state = ReaderState::Next;
+
+        let start = self.offset;
+        self.buffer.clear();
+        self.skip_value(true)?;
+
+        let value =
+            serde_json::from_slice(&self.buffer).map_err(json_error(&self.buffer, start))?;
+        feature_from_value(&value)
+            .map(Some)
+            .map_err(|error| GeoJsonError::InvalidFeature(start, Box::new(error)))
+    }
+
+    /// Skips the members of the collection up to the beginning of the array
+    /// of features. Returns false if there is no such array.
+    fn find_features(&mut self) -> Result<bool, GeoJsonError> {
+        self.expect(b'{', "'{'")?;
+
+        loop {
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b'}') => return Ok(false),
+                Some(b'"') => {}
+                _ => return Err(self.unexpected("member name")),
+            }
+
+            let start = self.offset;
+            self.buffer.clear();
+            self.skip_value(true)?;
+            let key: String =
+                serde_json::from_slice(&self.buffer).map_err(json_error(&self.buffer, start))?;
+
+            self.expect(b':', "':'")?;
+            self.skip_whitespace()?;
+
+            if key == "features" {
+                self.expect(b'[', "'['")?;
+                return Ok(true);
+            }
+
+            self.skip_value(false)?;
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b',') => {
+                    self.bump()?;
+                }
+                Some(b'}') => return Ok(false),
+                _ => return Err(self.unexpected("',' or '}'")),
+            }
+        }
+    }
+
+    /// Consumes the next JSON value, appending its bytes to the buffer if
+    /// required.
+    fn skip_value(&mut self, capture: bool) -> Result<(), GeoJsonError> {
+        let mut depth = 0_usize;
+        let mut string = false;
+        let mut escaped = false;
+        let mut started = false;
+
+        loop {
+            let Some(byte) = self.peek()? else {
+                return Err(self.unexpected("value"));
+            };
+
+            if started
+                && depth == 0
+                && !string
+                && (byte.is_ascii_whitespace() || matches!(byte, b',' | b'}' | b']'))
+            {
+                // scalars have no delimiter of their own
+                return Ok(());
+            }
+
+            if !started && matches!(byte, b',' | b'}' | b']' | b':') {
+                return Err(self.unexpected("value"));
+            }
+
+            self.bump()?;
+            if capture {
+                self.buffer.push(byte);
+            }
+
+            started = true;
+            if string {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    string = false;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+
+                continue;
+            }
+
+            match byte {
+                b'"' => string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<R: BufRead> TextReader for FeatureReader<R> {
+    type Error = GeoJsonError;
+
+    fn peek(&mut self) -> Result<Option<u8>, GeoJsonError> {
+        loop {
+            match self.reader.fill_buf() {
+                Ok(buffer) => return Ok(buffer.first().copied()),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    fn bump(&mut self) -> Result<Option<u8>, GeoJsonError> {
+        let byte = self.peek()?;
+        if byte.is_some() {
+            self.reader.consume(1);
+            self.offset += 1;
+        }
+
+        Ok(byte)
+    }
+
+    fn unexpected(&self, expected: &'static str) -> GeoJsonError {
+        GeoJsonError::InvalidJson(format!("expected {expected}"), self.offset)
+    }
+}
+
+/// Returns a function mapping JSON errors in the given bytes, which start at
+/// the given offset, into positioned errors.
+fn json_error(bytes: &[u8], start: usize) -> impl FnOnce(serde_json::Error) -> GeoJsonError + '_ {
+    move |error| {
+        // serde reports lines and columns, which are turned into offsets
+        let offset = bytes
+            .split(|&byte| byte == b'\n')
+            .take(error.line().saturating_sub(1))
+            .map(|line| line.len() + 1)
+            .sum::<usize>()
+            + error.column().saturating_sub(1);
+
+        GeoJsonError::InvalidJson(error.to_string(), start + offset)
+    }
+}
+
+/// Returns the position of the given point.
+fn position(point: &GeographicPoint) -> Position {
+    [
+        point.longitude().to_degrees(),
+        point.latitude().to_degrees(),
+        point.altitude(),
+    ]
+}
+
+/// Returns the point between the given ones whose longitude is the given one.
+fn intersection(from: &Position, to: &Position, longitude: f64) -> Position {
+    let t = (longitude - from[0]) / (to[0] - from[0]);
+    [
+        longitude,
+        from[1] + t * (to[1] - from[1]),
+        from[2] + t * (to[2] - from[2]),
+    ]
+}
+
+/// Returns the given ring with no jumps longer than half a turn in its
+/// longitudes, which may go out of the range __[-180, +180]__.
+fn unwrap(ring: &[Position]) -> Vec<Position> {
+    let mut unwrapped: Vec<Position> = Vec::with_capacity(ring.len());
+    ring.iter().for_each(|&position| {
+        let mut position = position;
+        if let Some(previous) = unwrapped.last() {
+            let delta = (position[0] - previous[0] + ANTIMERIDIAN).rem_euclid(2. * ANTIMERIDIAN)
+                - ANTIMERIDIAN;
+            position[0] = previous[0] + delta;
+        }
+
+        unwrapped.push(position);
+    });
+
+    unwrapped
+}
+
+/// Returns the part of the given closed ring whose longitudes satisfy the
+/// given condition, closed as well.
+fn clip_ring(ring: &[Position], keep: fn(f64) -> bool) -> Vec<Position> {
+    let vertices = match ring.split_last() {
+        Some((last, rest)) if rest.first() == Some(last) => rest,
+        _ => ring,
+    };
+
+    let mut clipped = clip(
+        vertices,
+        |position| keep(position[0]),
+        |from, to| intersection(from, to, ANTIMERIDIAN),
+    );
+
+    if let Some(&first) = clipped.first() {
+        clipped.push(first);
+    }
+
+    clipped
+}
+
+/// Returns the name of the type of the given value.
+fn type_of(value: &Value) -> String {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+    .to_string()
+}
+
+fn object(value: &Value) -> Result<&Map<String, Value>, GeoJsonError> {
+    value
+        .as_object()
+        .ok_or_else(|| GeoJsonError::UnexpectedType(type_of(value), "object"))
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>, GeoJsonError> {
+    value
+        .as_array()
+        .ok_or_else(|| GeoJsonError::UnexpectedType(type_of(value), "array"))
+}
+
+fn member<'a>(
+    object: &'a Map<String, Value>,
+    name: &'static str,
+) -> Result<&'a Value, GeoJsonError> {
+    object.get(name).ok_or(GeoJsonError::MissingMember(name))
+}
+
+fn kind(object: &Map<String, Value>) -> Result<&str, GeoJsonError> {
+    let kind = member(object, "type")?;
+    kind.as_str()
+        .ok_or_else(|| GeoJsonError::UnexpectedType(type_of(kind), "string"))
+}
+
+fn feature_from_value(value: &Value) -> Result<Feature, GeoJsonError> {
+    let object = object(value)?;
+    match kind(object)? {
+        "Feature" => {}
+        other => return Err(GeoJsonError::UnexpectedType(other.to_string(), "Feature")),
+    }
+
+    let geometry = match object.get("geometry") {
+        None | Some(Value::Null) => None,
+        Some(geometry) => Some(geometry_from_value(geometry)?),
+    };
+
+    let properties = match object.get("properties") {
+        None | Some(Value::Null) => Map::new(),
+        Some(properties) => self::object(properties)?.clone(),
+    };
+
+    Ok(Feature {
+        id: object.get("id").cloned(),
+        geometry,
+        properties,
+    })
+}
+
+fn geometry_from_value(value: &Value) -> Result<Geometry, GeoJsonError> {
+    let object = object(value)?;
+    let kind = kind(object)?;
+    let coordinates = member(object, "coordinates")?;
+
+    let points = |value: &Value| -> Result<Vec<GeographicPoint>, GeoJsonError> {
+        array(value)?.iter().map(point_from_value).collect()
+    };
+
+    let lines = |value: &Value| -> Result<Vec<Vec<GeographicPoint>>, GeoJsonError> {
+        array(value)?.iter().map(points).collect()
+    };
+
+    match kind {
+        "Point" => point_from_value(coordinates).map(Geometry::Point),
+        "LineString" => points(coordinates).map(Geometry::LineString),
+        "Polygon" => lines(coordinates).map(Geometry::Polygon),
+        "MultiPoint" => points(coordinates).map(Geometry::MultiPoint),
+        "MultiLineString" => lines(coordinates).map(Geometry::MultiLineString),
+        "MultiPolygon" => array(coordinates)?
+            .iter()
+            .map(lines)
+            .collect::<Result<_, _>>()
+            .map(Geometry::MultiPolygon),
+        other => Err(GeoJsonError::UnexpectedType(
+            other.to_string(),
+            "geometry type",
+        )),
+    }
+}
+
+fn point_from_value(value: &Value) -> Result<GeographicPoint, GeoJsonError> {
+    let invalid = || GeoJsonError::InvalidPosition(value.to_string());
+    let coordinates: Vec<f64> = array(value)?
+        .iter()
+        .map(|coordinate| coordinate.as_f64().ok_or_else(invalid))
+        .collect::<Result<_, _>>()?;
+
+    match coordinates[..] {
+        [longitude, latitude] => point_from_degrees(longitude, latitude, 0.),
+        [longitude, latitude, altitude, ..] => point_from_degrees(longitude, latitude, altitude),
+        _ => None,
+    }
+    .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use serde_json::json;
+
+    #[test]
+    fn geometry_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            format: GeoJson,
+            geometry: Geometry,
+            output: Value,
+        }
+
+        let square = |west: f64, east: f64| {
+            vec![
+                from_degrees(west, 0.),
+                from_degrees(east, 0.),
+                from_degrees(east, 10.),
+                from_degrees(west, 10.),
+                from_degrees(west, 0.),
+            ]
+        };
+
+        vec![
+            TestCase {
+                name: "point",
+                format: GeoJson::default(),
+                geometry: Geometry::Point(from_degrees(-3.7038, 40.4168)),
+                output: json!({"type": "Point", "coordinates": [-3.7038, 40.4168]}),
+            },
+            TestCase {
+                name: "point with altitude",
+                format: GeoJson::default().with_altitude(true).with_precision(1),
+                geometry: Geometry::Point(from_degrees(-3.7038, 40.4168).with_altitude(657.)),
+                output: json!({"type": "Point", "coordinates": [-3.7, 40.4, 657.]}),
+            },
+            TestCase {
+                name: "linestring",
+                format: GeoJson::default(),
+                geometry: Geometry::LineString(vec![
+                    from_degrees(170., 0.),
+                    from_degrees(179., 10.),
+                ]),
+                output: json!({"type": "LineString", "coordinates": [[170., 0.], [179., 10.]]}),
+            },
+            TestCase {
+                name: "linestring crossing the antimeridian",
+                format: GeoJson::default(),
+                geometry: Geometry::LineString(vec![
+                    from_degrees(-170., 0.),
+                    from_degrees(170., 10.),
+                    from_degrees(160., 10.),
+                ]),
+                output: json!({"type": "MultiLineString", "coordinates": [
+                    [[-170., 0.], [-180., 5.]],
+                    [[180., 5.], [170., 10.], [160., 10.]],
+                ]}),
+            },
+            TestCase {
+                name: "linestring crossing the antimeridian uncut",
+                format: GeoJson::default().with_antimeridian_cut(false),
+                geometry: Geometry::LineString(vec![
+                    from_degrees(170., 0.),
+                    from_degrees(-170., 10.),
+                ]),
+                output: json!({"type": "LineString", "coordinates": [[170., 0.], [-170., 10.]]}),
+            },
+            TestCase {
+                name: "polygon",
+                format: GeoJson::default(),
+                geometry: Geometry::Polygon(vec![square(0., 10.)]),
+                output: json!({"type": "Polygon", "coordinates": [
+                    [[0., 0.], [10., 0.], [10., 10.], [0., 10.], [0., 0.]],
+                ]}),
+            },
+            TestCase {
+                name: "polygon crossing the antimeridian",
+                format: GeoJson::default(),
+                geometry: Geometry::Polygon(vec![square(170., -170.), square(175., -175.)]),
+                output: json!({"type": "MultiPolygon", "coordinates": [
+                    [
+                        [[170., 0.], [180., 0.], [180., 10.], [170., 10.], [170., 0.]],
+                        [[175., 0.], [180., 0.], [180., 10.], [175., 10.], [175., 0.]],
+                    ],
+                    [
+                        [[-180., 0.], [-170., 0.], [-170., 10.], [-180., 10.], [-180., 0.]],
+                        [[-180., 0.], [-175., 0.], [-175., 10.], [-180., 10.], [-180., 0.]],
+                    ],
+                ]}),
+            },
+            TestCase {
+                name: "polygon around the pole",
+                format: GeoJson::default(),
+                geometry: Geometry::Polygon(vec![vec![
+                    from_degrees(0., 80.),
+                    from_degrees(90., 80.),
+                    from_degrees(-180., 80.),
+                    from_degrees(-90., 80.),
+                    from_degrees(0., 80.),
+                ]]),
+                output: json!({"type": "Polygon", "coordinates": [
+                    [[0., 80.], [90., 80.], [-180., 80.], [-90., 80.], [0., 80.]],
+                ]}),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.format.geometry(&test_case.geometry),
+                test_case.output,
+                "{}",
+                test_case.name
+            );
+
+            let json = test_case.format.format_geometry(&test_case.geometry);
+            assert!(
+                json.starts_with(r#"{"type":"#),
+                "{}: type must be the first member of {json}",
+                test_case.name
+            );
+            assert_eq!(
+                serde_json::from_str::<Value>(&json).ok(),
+                Some(test_case.output),
+                "{}: formatted",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn format_feature_must_not_fail() {
+        let feature = Feature::default()
+            .with_id(7)
+            .with_geometry(Geometry::Point(from_degrees(1., 2.)))
+            .with_property("name", "Madrid")
+            .with_property("population", 3_300_000);
+
+        let json = GeoJson::default().format_feature(&feature);
+        assert_eq!(
+            json,
+            r#"{"type":"Feature","id":7,"geometry":{"type":"Point","coordinates":[1.0,2.0]},"properties":{"name":"Madrid","population":3300000}}"#
+        );
+
+        assert_eq!(GeoJson::parse_feature(&json), Ok(feature.clone()));
+
+        let mut collection = Vec::new();
+        GeoJson::default()
+            .write_collection(&mut collection, [&feature, &Feature::default()])
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(collection).unwrap(),
+            GeoJson::default().format_collection(&[feature, Feature::default()])
+        );
+    }
+
+    #[test]
+    fn parse_geometry_must_not_fail() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<&'static str, GeoJsonError>,
+        }
+
+        vec![
+            TestCase {
+                input: r#"{"type": "MultiPolygon", "coordinates": [[[[0, 0], [1, 0], [0, 1], [0, 0]]]]}"#,
+                output: Ok("MultiPolygon"),
+            },
+            TestCase {
+                input: r#"{"type": "Point", "coordinates": [1, 2, 3, 4]}"#,
+                output: Ok("Point"),
+            },
+            TestCase {
+                input: r#"{"type": "Point", "coordinates": [1, 2}"#,
+                output: Err(GeoJsonError::InvalidJson(
+                    "expected `,` or `]` at line 1 column 39".to_string(),
+                    38,
+                )),
+            },
+            TestCase {
+                input: r#"{"coordinates": [1, 2]}"#,
+                output: Err(GeoJsonError::MissingMember("type")),
+            },
+            TestCase {
+                input: r#"{"type": "Circle", "coordinates": [1, 2]}"#,
+                output: Err(GeoJsonError::UnexpectedType(
+                    "Circle".to_string(),
+                    "geometry type",
+                )),
+            },
+            TestCase {
+                input: r#"{"type": "LineString", "coordinates": {}}"#,
+                output: Err(GeoJsonError::UnexpectedType(
+                    "object".to_string(),
+                    "array",
+                )),
+            },
+            TestCase {
+                input: r#"{"type": "Point", "coordinates": [1, 91]}"#,
+                output: Err(GeoJsonError::InvalidPosition("[1,91]".to_string())),
+            },
+            TestCase {
+                input: r#"{"type": "Point", "coordinates": [-181, 1]}"#,
+                output: Err(GeoJsonError::InvalidPosition("[-181,1]".to_string())),
+            },
+            TestCase {
+                input: r#"{"type": "Point", "coordinates": [1]}"#,
+                output: Err(GeoJsonError::InvalidPosition("[1]".to_string())),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                GeoJson::parse_geometry(test_case.input).map(|geometry| geometry.kind()),
+                test_case.output,
+                "{}",
+                test_case.input
+            );
+        });
+    }
+
+    #[test]
+    fn read_features_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            output: Vec<Result<&'static str, GeoJsonError>>,
+        }
+
+        vec![
+            TestCase {
+                name: "collection with other members",
+                input: r#" {
+                    "type": "FeatureCollection",
+                    "bbox": [-180, -90, 180, 90],
+                    "crs": {"name": "with } and ] and \" inside"},
+                    "features": [
+                        {"type": "Feature", "geometry": null, "properties": {"name": "a [{"}},
+                        {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]}, "properties": {"name": "b"}}
+                    ],
+                    "count": 2
+                }"#,
+                output: vec![Ok("a [{"), Ok("b")],
+            },
+            TestCase {
+                name: "empty collection",
+                input: r#"{"type": "FeatureCollection", "features": []}"#,
+                output: vec![],
+            },
+            TestCase {
+                name: "no features",
+                input: r#"{"type": "Feature", "geometry": null, "properties": null}"#,
+                output: vec![],
+            },
+            TestCase {
+                name: "invalid feature",
+                input: r#"{"features": [{"type": "Feature", "properties": {"name": "a"}}, {"type": "Point"}]}"#,
+                output: vec![
+                    Ok("a"),
+                    Err(GeoJsonError::InvalidFeature(
+                        64,
+                        Box::new(GeoJsonError::UnexpectedType(
+                            "Point".to_string(),
+                            "Feature",
+                        )),
+                    )),
+                ],
+            },
+            TestCase {
+                name: "malformed feature",
+                input: r#"{"features": [{"type": "Feature" "properties": {}}]}"#,
+                output: vec![Err(GeoJsonError::InvalidJson(
+                    "expected `,` or `}` at line 1 column 20".to_string(),
+                    33,
+                ))],
+            },
+            TestCase {
+                name: "missing separator",
+                input: r#"{"features": [{"type": "Feature", "properties": {"name": "a"}} {}]}"#,
+                output: vec![
+                    Ok("a"),
+                    Err(GeoJsonError::InvalidJson(
+                        "expected ',' or ']'".to_string(),
+                        63,
+                    )),
+                ],
+            },
+            TestCase {
+                name: "truncated",
+                input: r#"{"features": [{"type": "Feature", "properties": {"name": "a"}}, {"type""#,
+                output: vec![
+                    Ok("a"),
+                    Err(GeoJsonError::InvalidJson("expected value".to_string(), 71)),
+                ],
+            },
+            TestCase {
+                name: "missing member separator",
+                input: r#"{"type": "FeatureCollection" "features": []}"#,
+                output: vec![Err(GeoJsonError::InvalidJson(
+                    "expected ',' or '}'".to_string(),
+                    29,
+                ))],
+            },
+            TestCase {
+                name: "truncated member",
+                input: r#"{"type": "FeatureCollection""#,
+                output: vec![Err(GeoJsonError::InvalidJson(
+                    "expected ',' or '}'".to_string(),
+                    28,
+                ))],
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output: Vec<_> = GeoJson::read_features(test_case.input.as_bytes())
+                .map(|feature| {
+                    feature.map(|feature| {
+                        feature
+                            .property("name")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default()
+                            .to_string()
+                    })
+                })
+                .collect();
+
+            let want: Vec<_> = test_case
+                .output
+                .into_iter()
+                .map(|feature| feature.map(str::to_string))
+                .collect();
+
+            assert_eq!(output, want, "{}", test_case.name);
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{GeographicPoint, SphericalPolygon};
+
+/// Represents any of the geometries of the simple features specification,
+/// whose positions are geographic points.
+///
+/// Polygons are given as a list of rings, the first one being the exterior
+/// and the rest its holes. Unlike [`SphericalPolygon`], rings are explicitly
+/// closed, so their last point repeats the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(GeographicPoint),
+    LineString(Vec<GeographicPoint>),
+    Polygon(Vec<Vec<GeographicPoint>>),
+    MultiPoint(Vec<GeographicPoint>),
+    MultiLineString(Vec<Vec<GeographicPoint>>),
+    MultiPolygon(Vec<Vec<Vec<GeographicPoint>>>),
+}
+
+impl From<GeographicPoint> for Geometry {
+    fn from(point: GeographicPoint) -> Self {
+        Geometry::Point(point)
+    }
+}
+
+impl From<&SphericalPolygon> for Geometry {
+    fn from(polygon: &SphericalPolygon) -> Self {
+        let close = |mut ring: Vec<GeographicPoint>| {
+            if let Some(&first) = ring.first() {
+                ring.push(first);
+            }
+
+            ring
+        };
+
+        Geometry::Polygon(
+            std::iter::once(polygon.exterior())
+                .chain(polygon.holes().iter().cloned())
+                .map(close)
+                .collect(),
+        )
+    }
+}
+
+impl Geometry {
+    /// Returns the name of the kind of geometry, as written in WKT and
+    /// GeoJSON.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Geometry::Point(_) => "Point",
+            Geometry::LineString(_) => "LineString",
+            Geometry::Polygon(_) => "Polygon",
+            Geometry::MultiPoint(_) => "MultiPoint",
+            Geometry::MultiLineString(_) => "MultiLineString",
+            Geometry::MultiPolygon(_) => "MultiPolygon",
+        }
+    }
+
+    /// Returns all the positions of the geometry, in order.
+    pub fn points(&self) -> Vec<GeographicPoint> {
+        match self {
+            Geometry::Point(point) => vec![*point],
+            Geometry::LineString(points) | Geometry::MultiPoint(points) => points.clone(),
+            Geometry::Polygon(lines) | Geometry::MultiLineString(lines) => lines.concat(),
+            Geometry::MultiPolygon(polygons) => {
+                polygons.iter().flat_map(|rings| rings.concat()).collect()
+            }
+        }
+    }
+}
+
+/// Returns the point of the given longitude and latitude (in degrees) and
+/// altitude, if the altitude is finite, the longitude is in the range
+/// __[-180, +180]__ and the latitude in the range __[-90, +90]__.
+pub(crate) fn point_from_degrees(
+    longitude: f64,
+    latitude: f64,
+    altitude: f64,
+) -> Option<GeographicPoint> {
+    if !altitude.is_finite()
+        || !(-180. ..=180.).contains(&longitude)
+        || !(-90. ..=90.).contains(&latitude)
+    {
+        return None;
+    }
+
+    Some(
+        GeographicPoint::default()
+            .with_longitude(longitude.to_radians())
+            .with_latitude(latitude.to_radians())
+            .with_altitude(altitude),
+    )
+}
+
+/// The maximum amount of decimal places of formatted numbers.
+pub(crate) const MAX_PRECISION: usize = 15;
+
+/// Returns the given number with no more decimal places than the given
+/// precision, and no trailing zeros.
+pub(crate) fn format_number(value: f64, precision: usize) -> String {
+    let mut number = format!("{value:.precision$}");
+    if number.contains('.') {
+        number.truncate(number.trim_end_matches('0').trim_end_matches('.').len());
+    }
+
+    if number == "-0" {
+        number.remove(0);
+    }
+
+    number
+}
+
+/// A reader of text one byte at a time, as the parsers of textual formats are.
+pub(crate) trait TextReader {
+    type Error;
+
+    /// Returns the next byte, if any, without consuming it.
+    fn peek(&mut self) -> Result<Option<u8>, Self::Error>;
+
+    /// Consumes and returns the next byte, if any.
+    fn bump(&mut self) -> Result<Option<u8>, Self::Error>;
+
+    /// Returns the error of finding the next token instead of the expected
+    /// one.
+    fn unexpected(&self, expected: &'static str) -> Self::Error;
+
+    /// Consumes any whitespace before the next token.
+    fn skip_whitespace(&mut self) -> Result<(), Self::Error> {
+        while self.peek()?.is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.bump()?;
+        }
+
+        Ok(())
+    }
+
+    /// Consumes any whitespace followed by the given byte, which is otherwise
+    /// reported as the expected token.
+    fn expect(&mut self, byte: u8, expected: &'static str) -> Result<(), Self::Error> {
+        self.skip_whitespace()?;
+        if self.peek()? != Some(byte) {
+            return Err(self.unexpected(expected));
+        }
+
+        self.bump()?;
+        Ok(())
+    }
+}
+
+/// Returns the part of the given convex polygon, with no closing vertex, whose
+/// vertices satisfy the given condition, following the Sutherland–Hodgman
+/// algorithm. Edges leaving or entering that part are cut at the point returned
+/// by the given function.
+pub(crate) fn clip<P: Copy>(
+    polygon: &[P],
+    inside: impl Fn(&P) -> bool,
+    crossing: impl Fn(&P, &P) -> P,
+) -> Vec<P> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+    let previous = polygon.iter().cycle().skip(polygon.len().saturating_sub(1));
+    polygon
+        .iter()
+        .zip(previous)
+        .for_each(
+            |(current, previous)| match (inside(previous), inside(current)) {
+                (true, true) => clipped.push(*current),
+                (false, true) => {
+                    clipped.push(crossing(previous, current));
+                    clipped.push(*current);
+                }
+                (true, false) => clipped.push(crossing(previous, current)),
+                (false, false) => {}
+            },
+        );
+
+    clipped
+}
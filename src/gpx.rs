@@ -0,0 +1,1089 @@
+use crate::{
+    geometry::{format_number, point_from_degrees},
+    xml::{write_element, Element, XmlError, XmlReader},
+    GeographicPoint,
+};
+use quick_xml::escape::escape;
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The namespace of GPX 1.1 documents.
+const NAMESPACE: &str = "http://www.topografix.com/GPX/1/1";
+
+/// The creator of the documents that have none.
+const CREATOR: &str = "globe-rs";
+
+/// The maximum amount of decimal places of coordinates and elevations.
+const PRECISION: usize = 9;
+
+/// The amount of seconds in a day.
+const DAY: i64 = 86_400;
+
+/// Represents an error while reading a GPX document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GpxError {
+    /// The XML at the given byte offset is not well formed.
+    InvalidXml(String, usize),
+    /// The element at the given byte offset is not the expected one.
+    UnexpectedElement(String, usize),
+    /// The element at the given byte offset has no attribute of the given
+    /// name.
+    MissingAttribute(&'static str, usize),
+    /// The number in the element at the given byte offset is not valid.
+    InvalidNumber(String, usize),
+    /// The time in the element at the given byte offset is not a valid date
+    /// and time.
+    InvalidTime(String, usize),
+    /// The position of the element at the given byte offset is out of range.
+    OutOfRange(usize),
+    /// The underlying reader failed.
+    Io(String),
+}
+
+impl fmt::Display for GpxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpxError::InvalidXml(message, offset) => {
+                write!(f, "invalid xml at offset {offset}: {message}")
+            }
+            GpxError::UnexpectedElement(name, offset) => {
+                write!(f, "unexpected element {name:?} at offset {offset}")
+            }
+            GpxError::MissingAttribute(name, offset) => {
+                write!(f, "missing attribute {name:?} at offset {offset}")
+            }
+            GpxError::InvalidNumber(number, offset) => {
+                write!(f, "invalid number {number:?} at offset {offset}")
+            }
+            GpxError::InvalidTime(time, offset) => {
+                write!(f, "invalid time {time:?} at offset {offset}")
+            }
+            GpxError::OutOfRange(offset) => {
+                write!(f, "coordinates out of range at offset {offset}")
+            }
+            GpxError::Io(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for GpxError {}
+
+impl From<XmlError> for GpxError {
+    fn from(error: XmlError) -> Self {
+        match error {
+            XmlError::Invalid(message, offset) => GpxError::InvalidXml(message, offset),
+            XmlError::Io(message) => GpxError::Io(message),
+        }
+    }
+}
+
+/// Represents a waypoint, or a point of a route or track: a geographic point
+/// whose altitude is the elevation (in meters), with an optional time and
+/// name.
+///
+/// The content of the extensions element is kept as raw XML, so it can be
+/// written back as it was read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waypoint {
+    point: GeographicPoint,
+    elevation: bool,
+    time: Option<SystemTime>,
+    name: Option<String>,
+    extensions: Option<String>,
+}
+
+impl Waypoint {
+    pub fn new(point: GeographicPoint) -> Self {
+        Self {
+            point,
+            elevation: true,
+            time: None,
+            name: None,
+            extensions: None,
+        }
+    }
+
+    /// Sets whether the altitude of the point is written as its elevation.
+    pub fn with_elevation(mut self, elevation: bool) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    /// Sets the time of the waypoint.
+    pub fn with_time(mut self, time: SystemTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the name of the waypoint.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the raw XML content of the extensions of the waypoint.
+    pub fn with_extensions(mut self, extensions: impl Into<String>) -> Self {
+        self.extensions = Some(extensions.into());
+        self
+    }
+
+    /// Returns the point of the waypoint, whose altitude is its elevation.
+    pub fn point(&self) -> GeographicPoint {
+        self.point
+    }
+
+    /// Returns true if, and only if, the waypoint has an elevation.
+    pub fn has_elevation(&self) -> bool {
+        self.elevation
+    }
+
+    /// Returns the time of the waypoint, if any.
+    pub fn time(&self) -> Option<SystemTime> {
+        self.time
+    }
+
+    /// Returns the name of the waypoint, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the raw XML content of the extensions of the waypoint, if any.
+    pub fn extensions(&self) -> Option<&str> {
+        self.extensions.as_deref()
+    }
+}
+
+/// Represents a route: an ordered list of waypoints leading to a
+/// destination.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Route {
+    name: Option<String>,
+    points: Vec<Waypoint>,
+    extensions: Option<String>,
+}
+
+impl Route {
+    pub fn new(points: Vec<Waypoint>) -> Self {
+        Self {
+            points,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of the route.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the raw XML content of the extensions of the route.
+    pub fn with_extensions(mut self, extensions: impl Into<String>) -> Self {
+        self.extensions = Some(extensions.into());
+        self
+    }
+
+    /// Returns the name of the route, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the points of the route.
+    pub fn points(&self) -> &[Waypoint] {
+        &self.points
+    }
+
+    /// Returns the raw XML content of the extensions of the route, if any.
+    pub fn extensions(&self) -> Option<&str> {
+        self.extensions.as_deref()
+    }
+}
+
+/// Represents a continuous span of a track, with no gaps in the recording.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TrackSegment {
+    points: Vec<Waypoint>,
+    extensions: Option<String>,
+}
+
+impl TrackSegment {
+    pub fn new(points: Vec<Waypoint>) -> Self {
+        Self {
+            points,
+            extensions: None,
+        }
+    }
+
+    /// Sets the raw XML content of the extensions of the segment.
+    pub fn with_extensions(mut self, extensions: impl Into<String>) -> Self {
+        self.extensions = Some(extensions.into());
+        self
+    }
+
+    /// Returns the points of the segment.
+    pub fn points(&self) -> &[Waypoint] {
+        &self.points
+    }
+
+    /// Returns the raw XML content of the extensions of the segment, if any.
+    pub fn extensions(&self) -> Option<&str> {
+        self.extensions.as_deref()
+    }
+}
+
+/// Represents a track: an ordered list of segments of recorded points.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Track {
+    name: Option<String>,
+    segments: Vec<TrackSegment>,
+    extensions: Option<String>,
+}
+
+impl Track {
+    pub fn new(segments: Vec<TrackSegment>) -> Self {
+        Self {
+            segments,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of the track.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the raw XML content of the extensions of the track.
+    pub fn with_extensions(mut self, extensions: impl Into<String>) -> Self {
+        self.extensions = Some(extensions.into());
+        self
+    }
+
+    /// Returns the name of the track, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the segments of the track.
+    pub fn segments(&self) -> &[TrackSegment] {
+        &self.segments
+    }
+
+    /// Returns the raw XML content of the extensions of the track, if any.
+    pub fn extensions(&self) -> Option<&str> {
+        self.extensions.as_deref()
+    }
+}
+
+/// Represents a [GPX 1.1](https://www.topografix.com/GPX/1/1/) document:
+/// a collection of waypoints, routes and tracks.
+///
+/// Only names, times, elevations and extensions are kept from the optional
+/// elements of the specification. The namespaces declared in the root
+/// element are kept as well, so extensions can be written back.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Gpx {
+    creator: Option<String>,
+    name: Option<String>,
+    namespaces: Vec<(String, String)>,
+    waypoints: Vec<Waypoint>,
+    routes: Vec<Route>,
+    tracks: Vec<Track>,
+    extensions: Option<String>,
+}
+
+impl fmt::Display for Gpx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        write!(
+            f,
+            r#"<gpx version="1.1" creator="{}" xmlns="{NAMESPACE}""#,
+            escape(self.creator.as_deref().unwrap_or(CREATOR))
+        )?;
+
+        for (prefix, uri) in &self.namespaces {
+            write!(f, r#" xmlns:{prefix}="{}""#, escape(uri.as_str()))?;
+        }
+
+        writeln!(f, ">")?;
+        if let Some(name) = &self.name {
+            writeln!(f, "  <metadata>")?;
+            write_element(f, 2, "name", name)?;
+            writeln!(f, "  </metadata>")?;
+        }
+
+        for waypoint in &self.waypoints {
+            write_waypoint(f, 1, "wpt", waypoint)?;
+        }
+
+        for route in &self.routes {
+            writeln!(f, "  <rte>")?;
+            if let Some(name) = &route.name {
+                write_element(f, 2, "name", name)?;
+            }
+
+            write_extensions(f, 2, route.extensions.as_deref())?;
+            for point in &route.points {
+                write_waypoint(f, 2, "rtept", point)?;
+            }
+
+            writeln!(f, "  </rte>")?;
+        }
+
+        for track in &self.tracks {
+            writeln!(f, "  <trk>")?;
+            if let Some(name) = &track.name {
+                write_element(f, 2, "name", name)?;
+            }
+
+            write_extensions(f, 2, track.extensions.as_deref())?;
+            for segment in &track.segments {
+                writeln!(f, "    <trkseg>")?;
+                for point in &segment.points {
+                    write_waypoint(f, 3, "trkpt", point)?;
+                }
+
+                write_extensions(f, 3, segment.extensions.as_deref())?;
+                writeln!(f, "    </trkseg>")?;
+            }
+
+            writeln!(f, "  </trk>")?;
+        }
+
+        write_extensions(f, 1, self.extensions.as_deref())?;
+        writeln!(f, "</gpx>")
+    }
+}
+
+impl FromStr for Gpx {
+    type Err = GpxError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::read(value.as_bytes())
+    }
+}
+
+impl Gpx {
+    /// Sets the name of the document.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the name of the program that created the document.
+    pub fn with_creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Declares the given namespace prefix in the root element, so it can be
+    /// used by extensions.
+    pub fn with_namespace(mut self, prefix: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.namespaces.push((prefix.into(), uri.into()));
+        self
+    }
+
+    /// Adds the given waypoint to the document.
+    pub fn with_waypoint(mut self, waypoint: Waypoint) -> Self {
+        self.waypoints.push(waypoint);
+        self
+    }
+
+    /// Adds the given route to the document.
+    pub fn with_route(mut self, route: Route) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Adds the given track to the document.
+    pub fn with_track(mut self, track: Track) -> Self {
+        self.tracks.push(track);
+        self
+    }
+
+    /// Sets the raw XML content of the extensions of the document.
+    pub fn with_extensions(mut self, extensions: impl Into<String>) -> Self {
+        self.extensions = Some(extensions.into());
+        self
+    }
+
+    /// Returns the name of the document, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the name of the program that created the document, if any.
+    pub fn creator(&self) -> Option<&str> {
+        self.creator.as_deref()
+    }
+
+    /// Returns the namespace prefixes declared in the root element, and
+    /// their URIs.
+    pub fn namespaces(&self) -> &[(String, String)] {
+        &self.namespaces
+    }
+
+    /// Returns the waypoints of the document.
+    pub fn waypoints(&self) -> &[Waypoint] {
+        &self.waypoints
+    }
+
+    /// Returns the routes of the document.
+    pub fn routes(&self) -> &[Route] {
+        &self.routes
+    }
+
+    /// Returns the tracks of the document.
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Returns the raw XML content of the extensions of the document, if
+    /// any.
+    pub fn extensions(&self) -> Option<&str> {
+        self.extensions.as_deref()
+    }
+
+    /// Returns the GPX document in the given reader.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::Gpx;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let gpx: Gpx = r#"<gpx version="1.1" creator="example">
+    ///     <trk><name>Morning run</name><trkseg>
+    ///         <trkpt lat="40.4168" lon="-3.7038"><ele>657</ele><time>2024-05-01T07:30:00Z</time></trkpt>
+    ///     </trkseg></trk>
+    /// </gpx>"#.parse().unwrap();
+    ///
+    /// let track = &gpx.tracks()[0];
+    /// let point = track.segments()[0].points()[0].point();
+    ///
+    /// assert_eq!(track.name(), Some("Morning run"));
+    /// assert!(approx_eq!(f64, point.latitude().to_degrees(), 40.4168, epsilon = 1e-12));
+    /// assert!(approx_eq!(f64, point.altitude(), 657.));
+    /// ```
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, GpxError> {
+        document(&mut XmlReader::new(reader))
+    }
+
+    /// Writes the document as GPX into the given writer.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "{self}")?;
+        writer.flush()
+    }
+}
+
+/// Writes the extensions element with the given raw XML content, if any.
+fn write_extensions(
+    f: &mut fmt::Formatter<'_>,
+    depth: usize,
+    extensions: Option<&str>,
+) -> fmt::Result {
+    match extensions {
+        Some(extensions) => writeln!(
+            f,
+            "{:indent$}<extensions>{extensions}</extensions>",
+            "",
+            indent = depth * 2
+        ),
+        None => Ok(()),
+    }
+}
+
+fn write_waypoint(
+    f: &mut fmt::Formatter<'_>,
+    depth: usize,
+    tag: &str,
+    waypoint: &Waypoint,
+) -> fmt::Result {
+    write!(
+        f,
+        r#"{:indent$}<{tag} lat="{}" lon="{}""#,
+        "",
+        format_number(waypoint.point.latitude().to_degrees(), PRECISION),
+        format_number(waypoint.point.longitude().to_degrees(), PRECISION),
+        indent = depth * 2
+    )?;
+
+    if !waypoint.elevation
+        && waypoint.time.is_none()
+        && waypoint.name.is_none()
+        && waypoint.extensions.is_none()
+    {
+        return writeln!(f, "/>");
+    }
+
+    writeln!(f, ">")?;
+    if waypoint.elevation {
+        let elevation = format_number(waypoint.point.altitude(), PRECISION);
+        write_element(f, depth + 1, "ele", &elevation)?;
+    }
+
+    if let Some(time) = waypoint.time {
+        write_element(f, depth + 1, "time", &format_time(time))?;
+    }
+
+    if let Some(name) = &waypoint.name {
+        write_element(f, depth + 1, "name", name)?;
+    }
+
+    write_extensions(f, depth + 1, waypoint.extensions.as_deref())?;
+    writeln!(f, "{:indent$}</{tag}>", "", indent = depth * 2)
+}
+
+fn document<R: BufRead>(reader: &mut XmlReader<R>) -> Result<Gpx, GpxError> {
+    let root = reader.root()?;
+    if root.name() != b"gpx" {
+        return Err(GpxError::UnexpectedElement(
+            root.qualified_name(),
+            root.position,
+        ));
+    }
+
+    let mut gpx = Gpx::default();
+    for (key, value) in root.attributes()? {
+        if key == "creator" {
+            gpx.creator = Some(value);
+        } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+            gpx.namespaces.push((prefix.to_string(), value));
+        }
+    }
+
+    reader.children(&root, |reader, child| {
+        match child.name() {
+            b"metadata" => gpx.name = metadata(reader, &child)?,
+            b"wpt" => gpx.waypoints.push(waypoint(reader, &child)?),
+            b"rte" => gpx.routes.push(route(reader, &child)?),
+            b"trk" => gpx.tracks.push(track(reader, &child)?),
+            b"extensions" => gpx.extensions = Some(reader.raw(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    Ok(gpx)
+}
+
+fn metadata<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+) -> Result<Option<String>, GpxError> {
+    let mut name = None;
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"name" => name = Some(reader.text(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    Ok(name)
+}
+
+fn route<R: BufRead>(reader: &mut XmlReader<R>, element: &Element) -> Result<Route, GpxError> {
+    let mut route = Route::default();
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"name" => route.name = Some(reader.text(&child)?),
+            b"rtept" => route.points.push(waypoint(reader, &child)?),
+            b"extensions" => route.extensions = Some(reader.raw(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    Ok(route)
+}
+
+fn track<R: BufRead>(reader: &mut XmlReader<R>, element: &Element) -> Result<Track, GpxError> {
+    let mut track = Track::default();
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"name" => track.name = Some(reader.text(&child)?),
+            b"trkseg" => track.segments.push(segment(reader, &child)?),
+            b"extensions" => track.extensions = Some(reader.raw(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    Ok(track)
+}
+
+fn segment<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+) -> Result<TrackSegment, GpxError> {
+    let mut segment = TrackSegment::default();
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"trkpt" => segment.points.push(waypoint(reader, &child)?),
+            b"extensions" => segment.extensions = Some(reader.raw(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    Ok(segment)
+}
+
+fn waypoint<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+) -> Result<Waypoint, GpxError> {
+    let latitude = coordinate(element, "lat")?;
+    let longitude = coordinate(element, "lon")?;
+
+    let mut waypoint = Waypoint::new(GeographicPoint::default()).with_elevation(false);
+    let mut altitude = 0.;
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"ele" => {
+                let text = reader.text(&child)?;
+                altitude = text
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|value: &f64| value.is_finite())
+                    .ok_or(GpxError::InvalidNumber(text, child.position))?;
+
+                waypoint.elevation = true;
+            }
+            b"time" => {
+                let text = reader.text(&child)?;
+                let time =
+                    parse_time(text.trim()).ok_or(GpxError::InvalidTime(text, child.position))?;
+                waypoint.time = Some(time);
+            }
+            b"name" => waypoint.name = Some(reader.text(&child)?),
+            b"extensions" => waypoint.extensions = Some(reader.raw(&child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, GpxError>(())
+    })?;
+
+    waypoint.point = point_from_degrees(longitude, latitude, altitude)
+        .ok_or(GpxError::OutOfRange(element.position))?;
+
+    Ok(waypoint)
+}
+
+/// Returns the value (in degrees) of the given attribute of the given
+/// element.
+fn coordinate(element: &Element, name: &'static str) -> Result<f64, GpxError> {
+    let value = element
+        .attribute(name)?
+        .ok_or(GpxError::MissingAttribute(name, element.position))?;
+
+    value
+        .trim()
+        .parse()
+        .map_err(|_| GpxError::InvalidNumber(value, element.position))
+}
+
+/// Returns the time of the given date and time, as defined by XML schema
+/// (`YYYY-MM-DDThh:mm:ss[.s+][Z|(+|-)hh:mm]`). Times with no time zone are
+/// taken as UTC.
+fn parse_time(value: &str) -> Option<SystemTime> {
+    let digits = |value: &str, length: usize| {
+        (value.len() == length && value.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| value.parse::<i64>().ok())
+            .flatten()
+    };
+
+    let (date, time) = value.split_once('T')?;
+    let mut date = date.splitn(3, '-');
+    let year = digits(date.next()?, 4)?;
+    let month = digits(date.next()?, 2).filter(|month| (1..=12).contains(month))?;
+    let day =
+        digits(date.next()?, 2).filter(|&day| day >= 1 && day <= days_in_month(year, month))?;
+
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, 0)
+    } else if let Some(index) = time.rfind(['+', '-']) {
+        let (clock, zone) = time.split_at(index);
+        let (hours, minutes) = zone[1..].split_once(':')?;
+        let hours = digits(hours, 2).filter(|hours| *hours <= 14)?;
+        let minutes = digits(minutes, 2).filter(|minutes| *minutes < 60)?;
+        let sign = if zone.starts_with('-') { -1 } else { 1 };
+        (clock, sign * (hours * 3600 + minutes * 60))
+    } else {
+        (time, 0)
+    };
+
+    let mut clock = clock.splitn(3, ':');
+    let hours = digits(clock.next()?, 2).filter(|hours| *hours < 24)?;
+    let minutes = digits(clock.next()?, 2).filter(|minutes| *minutes < 60)?;
+    let seconds = clock.next()?;
+    let (seconds, fraction) = match seconds.split_once('.') {
+        Some((seconds, fraction)) => (seconds, Some(fraction)),
+        None => (seconds, None),
+    };
+
+    let seconds = digits(seconds, 2).filter(|seconds| *seconds < 60)?;
+    let nanos = match fraction {
+        Some(fraction) if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            fraction
+                .bytes()
+                .chain(std::iter::repeat(b'0'))
+                .take(9)
+                .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'))
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+
+    let seconds =
+        days_from_civil(year, month, day) * DAY + hours * 3600 + minutes * 60 + seconds - offset;
+
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(seconds as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(seconds.unsigned_abs()))?
+            .checked_add(Duration::from_nanos(nanos.into()))
+    }
+}
+
+/// Returns the given time as an XML schema date and time in UTC.
+fn format_time(time: SystemTime) -> String {
+    let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(error) => {
+            let duration = error.duration();
+            match duration.subsec_nanos() {
+                0 => (-(duration.as_secs() as i64), 0),
+                nanos => (-(duration.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY));
+    let clock = seconds.rem_euclid(DAY);
+    let fraction = if nanos > 0 {
+        format!(".{nanos:09}").trim_end_matches('0').to_string()
+    } else {
+        String::new()
+    };
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{fraction}Z",
+        clock / 3600,
+        clock % 3600 / 60,
+        clock % 60
+    )
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the amount of days since the unix epoch of the given date of the
+/// proleptic gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date of the proleptic gregorian calendar of the given amount
+/// of days since the unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    fn time(seconds: u64, nanos: u32) -> SystemTime {
+        UNIX_EPOCH + Duration::new(seconds, nanos)
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        let gpx: Gpx = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="device" xmlns="http://www.topografix.com/GPX/1/1"
+    xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">
+  <metadata><name>Weekend &amp; more</name><desc>ignored</desc></metadata>
+  <wpt lat="42.5" lon="1.5"><ele>2942.5</ele><name>Coma Pedrosa</name><sym>Summit</sym></wpt>
+  <rte>
+    <name>Approach</name>
+    <rtept lat="42.4" lon="1.4"/>
+    <rtept lat="42.45" lon="1.45"><name>Refuge</name></rtept>
+  </rte>
+  <trk>
+    <name>Ascent</name>
+    <trkseg>
+      <trkpt lat="42.4" lon="1.4">
+        <ele>1800</ele>
+        <time>2024-07-06T06:00:00Z</time>
+        <extensions><gpxtpx:TrackPointExtension><gpxtpx:hr>120</gpxtpx:hr></gpxtpx:TrackPointExtension></extensions>
+      </trkpt>
+      <trkpt lat="42.41" lon="1.41"><time>2024-07-06T08:00:01.250+02:00</time></trkpt>
+    </trkseg>
+    <trkseg/>
+  </trk>
+</gpx>"#
+            .parse()
+            .unwrap();
+
+        assert_eq!(gpx.creator(), Some("device"));
+        assert_eq!(gpx.name(), Some("Weekend & more"));
+        assert_eq!(
+            gpx.namespaces(),
+            [(
+                "gpxtpx".to_string(),
+                "http://www.garmin.com/xmlschemas/TrackPointExtension/v1".to_string()
+            )]
+        );
+
+        let waypoint = &gpx.waypoints()[0];
+        assert_eq!(waypoint.name(), Some("Coma Pedrosa"));
+        assert!(waypoint.has_elevation());
+        assert!(approx_eq!(f64, waypoint.point().altitude(), 2942.5));
+        assert!(approx_eq!(
+            f64,
+            waypoint.point().longitude(),
+            1.5_f64.to_radians()
+        ));
+
+        let route = &gpx.routes()[0];
+        assert_eq!(route.name(), Some("Approach"));
+        assert_eq!(route.points().len(), 2);
+        assert!(!route.points()[0].has_elevation());
+        assert_eq!(route.points()[1].name(), Some("Refuge"));
+
+        let track = &gpx.tracks()[0];
+        assert_eq!(track.name(), Some("Ascent"));
+        assert_eq!(track.segments().len(), 2);
+        assert!(track.segments()[1].points().is_empty());
+
+        let points = track.segments()[0].points();
+        assert_eq!(points[0].time(), Some(time(1720245600, 0)));
+        assert_eq!(points[1].time(), Some(time(1720245601, 250_000_000)));
+        assert_eq!(
+            points[0].extensions(),
+            Some("<gpxtpx:TrackPointExtension><gpxtpx:hr>120</gpxtpx:hr></gpxtpx:TrackPointExtension>")
+        );
+    }
+
+    #[test]
+    fn format_and_parse_must_round_trip() {
+        let gpx = Gpx::default()
+            .with_name("Trip")
+            .with_namespace("ext", "urn:example")
+            .with_waypoint(
+                Waypoint::new(from_degrees(-3.7038, 40.4168).with_altitude(657.)).with_name("Sol"),
+            )
+            .with_route(Route::new(vec![
+                Waypoint::new(from_degrees(0., 0.)).with_elevation(false)
+            ]))
+            .with_track(
+                Track::new(vec![TrackSegment::new(vec![Waypoint::new(
+                    from_degrees(2.1734, 41.3851).with_altitude(12.),
+                )
+                .with_time(time(1714548600, 500_000_000))
+                .with_extensions("<ext:speed>1.5</ext:speed>")])])
+                .with_name("A < B"),
+            );
+
+        let want = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="globe-rs" xmlns="http://www.topografix.com/GPX/1/1" xmlns:ext="urn:example">
+  <metadata>
+    <name>Trip</name>
+  </metadata>
+  <wpt lat="40.4168" lon="-3.7038">
+    <ele>657</ele>
+    <name>Sol</name>
+  </wpt>
+  <rte>
+    <rtept lat="0" lon="0"/>
+  </rte>
+  <trk>
+    <name>A &lt; B</name>
+    <trkseg>
+      <trkpt lat="41.3851" lon="2.1734">
+        <ele>12</ele>
+        <time>2024-05-01T07:30:00.5Z</time>
+        <extensions><ext:speed>1.5</ext:speed></extensions>
+      </trkpt>
+    </trkseg>
+  </trk>
+</gpx>
+"#;
+
+        assert_eq!(gpx.to_string(), want);
+
+        let mut bytes = Vec::new();
+        gpx.write(&mut bytes).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), want);
+
+        let read: Gpx = want.parse().unwrap();
+        assert_eq!(read.to_string(), want);
+    }
+
+    #[test]
+    fn parse_errors_must_be_positioned() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            output: GpxError,
+        }
+
+        vec![
+            TestCase {
+                name: "unexpected root",
+                input: r#"<kml></kml>"#,
+                output: GpxError::UnexpectedElement("kml".to_string(), 0),
+            },
+            TestCase {
+                name: "missing latitude",
+                input: r#"<gpx><wpt lon="1"/></gpx>"#,
+                output: GpxError::MissingAttribute("lat", 5),
+            },
+            TestCase {
+                name: "invalid longitude",
+                input: r#"<gpx><wpt lat="1" lon="east"/></gpx>"#,
+                output: GpxError::InvalidNumber("east".to_string(), 5),
+            },
+            TestCase {
+                name: "latitude out of range",
+                input: r#"<gpx><trk><trkseg><trkpt lat="91" lon="0"/></trkseg></trk></gpx>"#,
+                output: GpxError::OutOfRange(18),
+            },
+            TestCase {
+                name: "longitude out of range",
+                input: r#"<gpx><wpt lat="0" lon="181"/></gpx>"#,
+                output: GpxError::OutOfRange(5),
+            },
+            TestCase {
+                name: "invalid elevation",
+                input: r#"<gpx><wpt lat="1" lon="1"><ele>high</ele></wpt></gpx>"#,
+                output: GpxError::InvalidNumber("high".to_string(), 26),
+            },
+            TestCase {
+                name: "invalid time",
+                input: r#"<gpx><wpt lat="1" lon="1"><time>2024-02-30T00:00:00Z</time></wpt></gpx>"#,
+                output: GpxError::InvalidTime("2024-02-30T00:00:00Z".to_string(), 26),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let result = test_case.input.parse::<Gpx>();
+            assert_eq!(
+                result,
+                Err(test_case.output),
+                "{}: got gpx = {result:?}",
+                test_case.name,
+            );
+        });
+
+        assert!(matches!(
+            "<gpx><wpt lat=\"1\" lon=\"1\"></gpx>".parse::<Gpx>(),
+            Err(GpxError::InvalidXml(_, _))
+        ));
+
+        assert!(matches!(
+            "<gpx><trk>".parse::<Gpx>(),
+            Err(GpxError::InvalidXml(_, _))
+        ));
+    }
+
+    #[test]
+    fn parse_and_format_time_must_not_fail() {
+        struct TestCase {
+            input: &'static str,
+            output: Option<SystemTime>,
+            formatted: &'static str,
+        }
+
+        vec![
+            TestCase {
+                input: "1970-01-01T00:00:00Z",
+                output: Some(UNIX_EPOCH),
+                formatted: "1970-01-01T00:00:00Z",
+            },
+            TestCase {
+                input: "2000-02-29T23:59:59.123456789Z",
+                output: Some(time(951868799, 123456789)),
+                formatted: "2000-02-29T23:59:59.123456789Z",
+            },
+            TestCase {
+                input: "2024-01-01T00:30:00+01:00",
+                output: Some(time(1704065400, 0)),
+                formatted: "2023-12-31T23:30:00Z",
+            },
+            TestCase {
+                input: "2024-01-01T00:00:00",
+                output: Some(time(1704067200, 0)),
+                formatted: "2024-01-01T00:00:00Z",
+            },
+            TestCase {
+                input: "1969-12-31T23:59:59.5-00:00",
+                output: Some(UNIX_EPOCH - Duration::from_millis(500)),
+                formatted: "1969-12-31T23:59:59.5Z",
+            },
+            TestCase {
+                input: "1900-02-29T00:00:00Z",
+                output: None,
+                formatted: "",
+            },
+            TestCase {
+                input: "2024-01-01 00:00:00Z",
+                output: None,
+                formatted: "",
+            },
+            TestCase {
+                input: "2024-01-01T24:00:00Z",
+                output: None,
+                formatted: "",
+            },
+            TestCase {
+                input: "2024-01-01T00:00:00.Z",
+                output: None,
+                formatted: "",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let time = parse_time(test_case.input);
+            assert_eq!(time, test_case.output, "{}", test_case.input);
+
+            if let Some(time) = time {
+                assert_eq!(format_time(time), test_case.formatted);
+            }
+        });
+    }
+}
@@ -0,0 +1,485 @@
+use crate::{CartesianPoint, GeographicPoint};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Angular tolerance (in radiants) when deciding whether a point lies on an arc.
+const EPSILON: f64 = 1e-12;
+
+/// Represents the shortest arc of the great circle going from one point to
+/// another on the surface of a sphere. All distances are angular (in radiants),
+/// and so they must be multiplied by the radius of the sphere to get a length.
+///
+/// Since there are infinitely many great circles passing through two antipodal
+/// points, in such a case the one passing through the north pole is taken, or
+/// the prime meridian if both points are poles.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GreatCircleArc {
+    from: GeographicPoint,
+    to: GeographicPoint,
+}
+
+#[wasm_bindgen]
+impl GreatCircleArc {
+    #[wasm_bindgen(constructor)]
+    pub fn new(from: GeographicPoint, to: GeographicPoint) -> Self {
+        Self { from, to }
+    }
+
+    /// Returns the starting point of the arc.
+    pub fn from(&self) -> GeographicPoint {
+        self.from
+    }
+
+    /// Returns the ending point of the arc.
+    pub fn to(&self) -> GeographicPoint {
+        self.to
+    }
+
+    /// Returns the angular length of the arc (in radiants).
+    pub fn length(&self) -> f64 {
+        self.from.distance(&self.to)
+    }
+
+    /// Returns the pole of the great circle containing the arc, as a point on
+    /// the unit sphere, such that the arc goes counterclockwise around it. If
+    /// both ends of the arc are the same point, the origin is returned instead.
+    pub fn pole(&self) -> CartesianPoint {
+        let from = CartesianPoint::direction_of(&self.from);
+        let to = CartesianPoint::direction_of(&self.to);
+
+        let pole = from.cross(&to);
+        if pole.magnitude() > f64::EPSILON {
+            return pole.normalize();
+        }
+
+        // the ends of the arc are either coincident or antipodal
+        from.cross(&from.slerp(&to, 0.5)).normalize()
+    }
+
+    /// Returns the signed angular distance (in radiants) from the given point to
+    /// the great circle containing the arc, being positive when the point lies
+    /// to the right of the direction of travel, and negative otherwise.
+    ///
+    /// If both ends of the arc are the same point, the distance to that point is
+    /// returned instead.
+    pub fn cross_track_distance(&self, point: &GeographicPoint) -> f64 {
+        let pole = self.pole();
+        if pole.magnitude() == 0. {
+            return self.from.distance(point);
+        }
+
+        let point = CartesianPoint::direction_of(point);
+        -pole.dot(&point).clamp(-1., 1.).asin()
+    }
+
+    /// Returns the signed angular distance (in radiants) from the start of the
+    /// arc to the projection of the given point onto the great circle containing
+    /// it, being positive in the direction of travel, and negative otherwise.
+    ///
+    /// If both ends of the arc are the same point, or the given point is any of
+    /// the poles of the great circle, zero is returned.
+    pub fn along_track_distance(&self, point: &GeographicPoint) -> f64 {
+        let pole = self.pole();
+        let Some(projection) = self.project(point) else {
+            return 0.;
+        };
+
+        let from = CartesianPoint::direction_of(&self.from);
+        from.cross(&projection)
+            .dot(&pole)
+            .atan2(from.dot(&projection))
+    }
+
+    /// Returns the point of the arc that is the closest one to the given point.
+    pub fn closest_point(&self, point: &GeographicPoint) -> GeographicPoint {
+        let along_track = self.along_track_distance(point);
+        if self.project(point).is_some() && (0. ..=self.length()).contains(&along_track) {
+            return self.from.interpolate(&self.to, along_track / self.length());
+        }
+
+        if self.from.distance(point) <= self.to.distance(point) {
+            self.from
+        } else {
+            self.to
+        }
+    }
+
+    /// Returns the angular distance (in radiants) from the given point to the
+    /// closest point of the arc.
+    pub fn distance(&self, point: &GeographicPoint) -> f64 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns true if, and only if, the given point lies on the arc.
+    pub fn contains(&self, point: &GeographicPoint) -> bool {
+        if self.cross_track_distance(point).abs() > EPSILON {
+            return false;
+        }
+
+        let along_track = self.along_track_distance(point);
+        (-EPSILON..=self.length() + EPSILON).contains(&along_track)
+    }
+
+    /// Returns the points where the great circles containing self and the given
+    /// arc intersect, which are always two antipodal points. If both arcs lay
+    /// on the same great circle, or any of them is a single point, there are no
+    /// intersections.
+    pub fn circle_intersections(&self, other: &GreatCircleArc) -> Vec<GeographicPoint> {
+        let line = self.pole().cross(&other.pole());
+        if line.magnitude() <= EPSILON {
+            return Vec::new();
+        }
+
+        let point = line.normalize();
+        let antipode = CartesianPoint::new(-point.x(), -point.y(), -point.z());
+
+        vec![
+            GeographicPoint::from_cartesian(&point),
+            GeographicPoint::from_cartesian(&antipode),
+        ]
+    }
+
+    /// Returns the points where self and the given arc intersect, if any.
+    ///
+    /// If both arcs lay on the same great circle and overlap, the ends of the
+    /// overlapping section are returned instead, which may be a single point if
+    /// the arcs just touch each other.
+    pub fn intersections(&self, other: &GreatCircleArc) -> Vec<GeographicPoint> {
+        let candidates = if self.pole().cross(&other.pole()).magnitude() <= EPSILON {
+            vec![self.from, self.to, other.from, other.to]
+        } else {
+            self.circle_intersections(other)
+        };
+
+        candidates
+            .into_iter()
+            .filter(|point| self.contains(point) && other.contains(point))
+            .fold(Vec::new(), |mut points, point| {
+                if points
+                    .iter()
+                    .all(|found: &GeographicPoint| found.distance(&point) > EPSILON)
+                {
+                    points.push(point);
+                }
+
+                points
+            })
+    }
+}
+
+impl GreatCircleArc {
+    /// Returns the orthogonal projection of the given point onto the great
+    /// circle containing the arc, if any.
+    fn project(&self, point: &GeographicPoint) -> Option<CartesianPoint> {
+        let pole = self.pole();
+        if pole.magnitude() == 0. {
+            return None;
+        }
+
+        let point = CartesianPoint::direction_of(point);
+        let projection = CartesianPoint::new(
+            point.x() - pole.x() * pole.dot(&point),
+            point.y() - pole.y() * pole.dot(&point),
+            point.z() - pole.z() * pole.dot(&point),
+        );
+
+        (projection.magnitude() > f64::EPSILON).then(|| projection.normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn track_distances_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            arc: GreatCircleArc,
+            point: GeographicPoint,
+            cross_track: f64,
+            along_track: f64,
+        }
+
+        let equator = GreatCircleArc::new(
+            GeographicPoint::default(),
+            GeographicPoint::default().with_longitude(FRAC_PI_2),
+        );
+
+        vec![
+            TestCase {
+                name: "point on the arc",
+                arc: equator,
+                point: GeographicPoint::default().with_longitude(FRAC_PI_4),
+                cross_track: 0.,
+                along_track: FRAC_PI_4,
+            },
+            TestCase {
+                name: "point to the left of the arc",
+                arc: equator,
+                point: GeographicPoint::default()
+                    .with_longitude(0.5)
+                    .with_latitude(0.2),
+                cross_track: -0.2,
+                along_track: 0.5,
+            },
+            TestCase {
+                name: "point to the right of the arc",
+                arc: equator,
+                point: GeographicPoint::default()
+                    .with_longitude(0.5)
+                    .with_latitude(-0.2),
+                cross_track: 0.2,
+                along_track: 0.5,
+            },
+            TestCase {
+                name: "point behind the start of the arc",
+                arc: equator,
+                point: GeographicPoint::default()
+                    .with_longitude(-0.5)
+                    .with_latitude(-0.2),
+                cross_track: 0.2,
+                along_track: -0.5,
+            },
+            TestCase {
+                name: "point on the pole of the arc",
+                arc: equator,
+                point: GeographicPoint::default().with_latitude(FRAC_PI_2),
+                cross_track: -FRAC_PI_2,
+                along_track: 0.,
+            },
+            TestCase {
+                name: "arc of coincident points",
+                arc: GreatCircleArc::new(GeographicPoint::default(), GeographicPoint::default()),
+                point: GeographicPoint::default().with_latitude(0.3),
+                cross_track: 0.3,
+                along_track: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let cross_track = test_case.arc.cross_track_distance(&test_case.point);
+            assert!(
+                approx_eq!(f64, cross_track, test_case.cross_track, epsilon = EPSILON),
+                "{}: cross-track distance {} ±ε = {}",
+                test_case.name,
+                cross_track,
+                test_case.cross_track
+            );
+
+            let along_track = test_case.arc.along_track_distance(&test_case.point);
+            assert!(
+                approx_eq!(f64, along_track, test_case.along_track, epsilon = EPSILON),
+                "{}: along-track distance {} ±ε = {}",
+                test_case.name,
+                along_track,
+                test_case.along_track
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            arc: GreatCircleArc,
+            point: GeographicPoint,
+            closest: GeographicPoint,
+        }
+
+        let meridian = GreatCircleArc::new(
+            GeographicPoint::default().with_longitude(PI),
+            GeographicPoint::default()
+                .with_longitude(PI)
+                .with_latitude(FRAC_PI_4),
+        );
+
+        vec![
+            TestCase {
+                name: "projection within the arc",
+                arc: meridian,
+                point: GeographicPoint::default()
+                    .with_longitude(-PI + 0.1)
+                    .with_latitude(0.3),
+                closest: GeographicPoint::default().with_longitude(PI).with_latitude(
+                    meridian.along_track_distance(
+                        &GeographicPoint::default()
+                            .with_longitude(-PI + 0.1)
+                            .with_latitude(0.3),
+                    ),
+                ),
+            },
+            TestCase {
+                name: "projection before the start of the arc",
+                arc: meridian,
+                point: GeographicPoint::default()
+                    .with_longitude(PI - 0.1)
+                    .with_latitude(-0.3),
+                closest: meridian.from(),
+            },
+            TestCase {
+                name: "projection after the end of the arc",
+                arc: meridian,
+                point: GeographicPoint::default()
+                    .with_longitude(PI - 0.1)
+                    .with_latitude(1.),
+                closest: meridian.to(),
+            },
+            TestCase {
+                name: "projection on the opposite side of the circle",
+                arc: meridian,
+                point: GeographicPoint::default().with_latitude(0.1),
+                closest: meridian.to(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let closest = test_case.arc.closest_point(&test_case.point);
+            assert!(
+                closest.distance(&test_case.closest) < 1e-7,
+                "{}: {:?} ±ε = {:?}",
+                test_case.name,
+                closest,
+                test_case.closest
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.arc.distance(&test_case.point),
+                    closest.distance(&test_case.point),
+                    ulps = 2
+                ),
+                "{}: distance must be the one to the closest point",
+                test_case.name,
+            );
+        });
+    }
+
+    #[test]
+    fn intersections_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            arc: GreatCircleArc,
+            other: GreatCircleArc,
+            circle_intersections: usize,
+            intersections: Vec<GeographicPoint>,
+        }
+
+        let equator = GreatCircleArc::new(
+            GeographicPoint::default().with_longitude(-0.5),
+            GeographicPoint::default().with_longitude(0.5),
+        );
+
+        vec![
+            TestCase {
+                name: "crossing arcs",
+                arc: equator,
+                other: GreatCircleArc::new(
+                    GeographicPoint::default()
+                        .with_longitude(0.2)
+                        .with_latitude(-0.3),
+                    GeographicPoint::default()
+                        .with_longitude(0.2)
+                        .with_latitude(0.3),
+                ),
+                circle_intersections: 2,
+                intersections: vec![GeographicPoint::default().with_longitude(0.2)],
+            },
+            TestCase {
+                name: "crossing circles but not arcs",
+                arc: equator,
+                other: GreatCircleArc::new(
+                    GeographicPoint::default()
+                        .with_longitude(0.2)
+                        .with_latitude(0.1),
+                    GeographicPoint::default()
+                        .with_longitude(0.2)
+                        .with_latitude(0.3),
+                ),
+                circle_intersections: 2,
+                intersections: vec![],
+            },
+            TestCase {
+                name: "touching arcs",
+                arc: equator,
+                other: GreatCircleArc::new(
+                    GeographicPoint::default().with_longitude(0.5),
+                    GeographicPoint::default()
+                        .with_longitude(0.5)
+                        .with_latitude(0.3),
+                ),
+                circle_intersections: 2,
+                intersections: vec![GeographicPoint::default().with_longitude(0.5)],
+            },
+            TestCase {
+                name: "overlapping arcs on the same circle",
+                arc: equator,
+                other: GreatCircleArc::new(
+                    GeographicPoint::default().with_longitude(0.7),
+                    GeographicPoint::default().with_longitude(0.1),
+                ),
+                circle_intersections: 0,
+                intersections: vec![
+                    GeographicPoint::default().with_longitude(0.5),
+                    GeographicPoint::default().with_longitude(0.1),
+                ],
+            },
+            TestCase {
+                name: "disjoint arcs on the same circle",
+                arc: equator,
+                other: GreatCircleArc::new(
+                    GeographicPoint::default().with_longitude(PI - 0.5),
+                    GeographicPoint::default().with_longitude(-PI + 0.5),
+                ),
+                circle_intersections: 0,
+                intersections: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let circle_intersections = test_case.arc.circle_intersections(&test_case.other);
+            assert_eq!(
+                circle_intersections.len(),
+                test_case.circle_intersections,
+                "{}: circle intersections {:?}",
+                test_case.name,
+                circle_intersections
+            );
+
+            circle_intersections.iter().for_each(|point| {
+                assert!(
+                    test_case.arc.cross_track_distance(point).abs() < 1e-12
+                        && test_case.other.cross_track_distance(point).abs() < 1e-12,
+                    "{}: circle intersection {:?} must lay on both circles",
+                    test_case.name,
+                    point
+                );
+            });
+
+            let intersections = test_case.arc.intersections(&test_case.other);
+            assert_eq!(
+                intersections.len(),
+                test_case.intersections.len(),
+                "{}: intersections {:?}",
+                test_case.name,
+                intersections
+            );
+
+            intersections
+                .iter()
+                .zip(test_case.intersections.iter())
+                .for_each(|(got, want)| {
+                    assert!(
+                        got.distance(want) < 1e-7,
+                        "{}: {:?} ±ε = {:?}",
+                        test_case.name,
+                        got,
+                        want
+                    );
+                });
+        });
+    }
+}
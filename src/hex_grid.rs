@@ -0,0 +1,706 @@
+use crate::{geometry::clip, CartesianPoint, GeographicPoint, SphericalPolygon};
+use std::{
+    collections::HashSet,
+    f64::consts::{FRAC_PI_3, FRAC_PI_6},
+    sync::OnceLock,
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The resolution of the smallest cells of the grid.
+pub const MAX_HEX_RESOLUTION: u8 = 15;
+
+/// The amount of bits of a cell id taken by each lattice coordinate.
+const COORDINATE_BITS: u32 = 27;
+/// The amount of bits of a cell id taken by the face of the icosahedron.
+const FACE_BITS: u32 = 5;
+/// Tolerance when deciding if a point lies on the boundary of a face.
+const EPSILON: f64 = 1e-12;
+
+/// Represents a face of the icosahedron the grid is built upon, together with
+/// the frame of its gnomonic projection.
+struct Face {
+    center: CartesianPoint,
+    x_axis: CartesianPoint,
+    y_axis: CartesianPoint,
+    vertices: [CartesianPoint; 3],
+}
+
+impl Face {
+    /// Returns the gnomonic projection of the given point onto the face, if the
+    /// point is in the same hemisphere as the face.
+    fn project(&self, point: &CartesianPoint) -> Option<(f64, f64)> {
+        let distance = point.dot(&self.center);
+        (distance > 0.).then(|| {
+            (
+                point.dot(&self.x_axis) / distance,
+                point.dot(&self.y_axis) / distance,
+            )
+        })
+    }
+
+    /// Returns the point of the unit sphere whose gnomonic projection onto the
+    /// face is at the given coordinates.
+    fn unproject(&self, x: f64, y: f64) -> CartesianPoint {
+        self.center
+            .add_scaled(&self.x_axis, x)
+            .add_scaled(&self.y_axis, y)
+            .normalize()
+    }
+
+    /// Returns true if, and only if, the given point lies inside the spherical
+    /// triangle of the face or on its boundary.
+    fn contains(&self, point: &CartesianPoint) -> bool {
+        point.dot(&self.center) > 0.
+            && (0..3).all(|index| {
+                let from = &self.vertices[index];
+                let to = &self.vertices[(index + 1) % 3];
+                from.cross(to).dot(point) >= -EPSILON
+            })
+    }
+}
+
+/// Returns the faces of the icosahedron, each with its vertices in
+/// counterclockwise order.
+fn faces() -> &'static [Face] {
+    static FACES: OnceLock<Vec<Face>> = OnceLock::new();
+    FACES.get_or_init(|| {
+        let phi = (1. + 5_f64.sqrt()) / 2.;
+        let vertices: Vec<CartesianPoint> = [(1., phi), (-1., phi), (1., -phi), (-1., -phi)]
+            .into_iter()
+            .flat_map(|(a, b)| {
+                [
+                    CartesianPoint::new(0., a, b),
+                    CartesianPoint::new(a, b, 0.),
+                    CartesianPoint::new(b, 0., a),
+                ]
+            })
+            .map(|vertex| vertex.normalize())
+            .collect();
+
+        // adjacent vertices are the closest ones to each other
+        let adjacent = |a: usize, b: usize| vertices[a].dot(&vertices[b]) > 0.4;
+
+        let mut faces = Vec::new();
+        for a in 0..vertices.len() {
+            for b in a + 1..vertices.len() {
+                for c in b + 1..vertices.len() {
+                    if !(adjacent(a, b) && adjacent(b, c) && adjacent(a, c)) {
+                        continue;
+                    }
+
+                    let center = vertices[a]
+                        .add_scaled(&vertices[b], 1.)
+                        .add_scaled(&vertices[c], 1.)
+                        .normalize();
+
+                    let (b, c) = if vertices[b]
+                        .add_scaled(&vertices[a], -1.)
+                        .cross(&vertices[c].add_scaled(&vertices[a], -1.))
+                        .dot(&center)
+                        < 0.
+                    {
+                        (c, b)
+                    } else {
+                        (b, c)
+                    };
+
+                    let y_axis = vertices[a]
+                        .add_scaled(&center, -vertices[a].dot(&center))
+                        .normalize();
+
+                    faces.push(Face {
+                        center,
+                        x_axis: y_axis.cross(&center),
+                        y_axis,
+                        vertices: [vertices[a], vertices[b], vertices[c]],
+                    });
+                }
+            }
+        }
+
+        faces
+    })
+}
+
+/// Returns the distance between adjacent centers on the gnomonic projection
+/// of any face, and the angle of the lattice, at the given resolution.
+fn lattice(resolution: u8) -> (f64, f64) {
+    // at resolution 0 the centers are the ones of the faces and their vertices
+    let face = &faces()[0];
+    let (x, y) = face.project(&face.vertices[0]).unwrap_or_default();
+    let spacing = x.hypot(y) / 7_f64.sqrt().powi(resolution as i32);
+
+    // every resolution is rotated back and forth so that the centers of the
+    // previous one are still centers of the current one.
+    let angle = if resolution % 2 == 1 {
+        FRAC_PI_6 - (3_f64.sqrt() / 5.).atan()
+    } else {
+        FRAC_PI_6
+    };
+
+    (spacing, angle)
+}
+
+/// Represents a cell of a hexagonal grid over the sphere, in the fashion of
+/// [H3](https://h3geo.org/docs/core-library/overview).
+///
+/// The grid is built by projecting a hexagonal lattice onto each face of an
+/// icosahedron, every cell being the region of the sphere closer to its center
+/// than to any other. Each resolution is an aperture 7 refinement of the
+/// previous one: cells have about a seventh of the area of their parent, whose
+/// center they contain. All cells are hexagons, except the twelve pentagons
+/// centered at the vertices of the icosahedron. Resolution 0 has 32 cells.
+///
+/// The id of a cell packs the resolution, the face of the icosahedron the
+/// center of the cell belongs to, and its coordinates on the lattice of that
+/// face.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexCell(u64);
+
+#[wasm_bindgen]
+impl HexCell {
+    /// Returns the cell with the given id, if valid.
+    pub fn from_raw(id: u64) -> Option<HexCell> {
+        let cell = Self(id);
+        (cell.resolution() <= MAX_HEX_RESOLUTION && (cell.face() as usize) < faces().len())
+            .then(|| {
+                let (i, j) = cell.coordinates();
+                center_of(cell.face() as usize, cell.resolution(), i, j)
+            })
+            .flatten()
+            .map(|_| cell)
+    }
+
+    /// Returns the cell of the given resolution containing the direction of the
+    /// given [`CartesianPoint`], if it has any. That is, if its magnitude is
+    /// finite and positive.
+    pub fn from_cartesian(point: &CartesianPoint, resolution: u8) -> Option<HexCell> {
+        let magnitude = point.magnitude();
+        (magnitude.is_finite() && magnitude > 0.)
+            .then(|| Self::containing(&point.normalize(), resolution))
+    }
+
+    /// Returns the cell of the given resolution containing the given
+    /// [`GeographicPoint`], no matter its altitude.
+    pub fn from_geographic(point: &GeographicPoint, resolution: u8) -> HexCell {
+        Self::containing(&CartesianPoint::direction_of(point), resolution)
+    }
+
+    /// Returns the 64-bit id of the cell.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the resolution of the cell, being 0 the coarsest one.
+    pub fn resolution(&self) -> u8 {
+        (self.0 >> (2 * COORDINATE_BITS + FACE_BITS)) as u8
+    }
+
+    /// Returns true if, and only if, the cell is one of the twelve pentagons of
+    /// its resolution.
+    pub fn is_pentagon(&self) -> bool {
+        let center = self.center_point();
+        faces()[self.face() as usize]
+            .vertices
+            .iter()
+            .any(|vertex| vertex.distance(&center) <= EPSILON)
+    }
+
+    /// Returns the center of the cell.
+    pub fn center(&self) -> GeographicPoint {
+        GeographicPoint::from_cartesian(&self.center_point()).with_altitude(0.)
+    }
+
+    /// Returns the vertices of the cell, in counterclockwise order.
+    pub fn vertices(&self) -> Vec<GeographicPoint> {
+        let (center, tangent, bitangent, vertices) = self.planar_boundary();
+        vertices
+            .into_iter()
+            .map(|(x, y)| {
+                let vertex = center
+                    .add_scaled(&tangent, x)
+                    .add_scaled(&bitangent, y)
+                    .normalize();
+
+                GeographicPoint::from_cartesian(&vertex).with_altitude(0.)
+            })
+            .collect()
+    }
+
+    /// Returns the [`SphericalPolygon`] bounded by the cell.
+    pub fn boundary(&self) -> SphericalPolygon {
+        SphericalPolygon::new(self.vertices())
+    }
+
+    /// Returns the area of the cell, in steradians.
+    pub fn area(&self) -> f64 {
+        self.boundary().area()
+    }
+
+    /// Returns the cells of the same resolution sharing an edge with self, in
+    /// counterclockwise order.
+    pub fn neighbours(&self) -> Vec<HexCell> {
+        let (center, tangent, bitangent, vertices) = self.planar_boundary();
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(from, to)| {
+                // a point slightly beyond the middle of the edge belongs to the
+                // cell at the other side.
+                let (x, y) = ((from.0 + to.0) * 0.55, (from.1 + to.1) * 0.55);
+                let point = center.add_scaled(&tangent, x).add_scaled(&bitangent, y);
+                Self::containing(&point.normalize(), self.resolution())
+            })
+            .fold(Vec::new(), |mut neighbours, neighbour| {
+                if neighbour != *self && !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+
+                neighbours
+            })
+    }
+
+    /// Returns all the cells at a distance of at most `k` steps from self,
+    /// starting by self and followed by each ring of neighbours.
+    pub fn k_ring(&self, k: u32) -> Vec<HexCell> {
+        let mut cells = vec![*self];
+        let mut visited = HashSet::from([*self]);
+        let mut ring_start = 0;
+
+        for _ in 0..k {
+            let ring_end = cells.len();
+            if ring_start == ring_end {
+                break;
+            }
+
+            for index in ring_start..ring_end {
+                cells[index].neighbours().into_iter().for_each(|neighbour| {
+                    if visited.insert(neighbour) {
+                        cells.push(neighbour);
+                    }
+                });
+            }
+
+            ring_start = ring_end;
+        }
+
+        cells
+    }
+
+    /// Returns the cell one resolution coarser containing the center of self,
+    /// if any.
+    pub fn parent(&self) -> Option<HexCell> {
+        (self.resolution() > 0).then(|| self.ancestor(self.resolution() - 1))
+    }
+
+    /// Returns the cell of the given resolution containing the center of self,
+    /// which is self if the given resolution is not coarser than the one of the
+    /// cell.
+    pub fn ancestor(&self, resolution: u8) -> HexCell {
+        if resolution >= self.resolution() {
+            return *self;
+        }
+
+        Self::containing(&self.center_point(), resolution)
+    }
+
+    /// Returns the cells one resolution finer whose center lies inside self:
+    /// seven for hexagons and six for pentagons.
+    pub fn children(&self) -> Vec<HexCell> {
+        if self.resolution() >= MAX_HEX_RESOLUTION {
+            return Vec::new();
+        }
+
+        let mut children: Vec<HexCell> =
+            nearby_cells(&self.center_point(), self.resolution() + 1, 2)
+                .into_iter()
+                .map(|(cell, _)| cell)
+                .filter(|cell| cell.parent() == Some(*self))
+                .collect();
+
+        children.sort();
+        children
+    }
+}
+
+impl HexCell {
+    /// Returns the cell of the given resolution containing the given unit
+    /// vector.
+    fn containing(point: &CartesianPoint, resolution: u8) -> Self {
+        nearby_cells(point, resolution.min(MAX_HEX_RESOLUTION), 2)
+            .into_iter()
+            .max_by(|a, b| point.dot(&a.1).total_cmp(&point.dot(&b.1)))
+            .map(|(cell, _)| cell)
+            .unwrap_or_default()
+    }
+
+    /// Returns the cell of the given resolution, face and lattice coordinates.
+    fn new(resolution: u8, face: usize, i: i64, j: i64) -> Self {
+        let mask = (1 << COORDINATE_BITS) - 1;
+        Self(
+            (resolution as u64) << (2 * COORDINATE_BITS + FACE_BITS)
+                | (face as u64) << (2 * COORDINATE_BITS)
+                | (i as u64 & mask) << COORDINATE_BITS
+                | (j as u64 & mask),
+        )
+    }
+
+    /// Returns the face of the icosahedron the center of the cell belongs to.
+    fn face(&self) -> u8 {
+        (self.0 >> (2 * COORDINATE_BITS) & ((1 << FACE_BITS) - 1)) as u8
+    }
+
+    /// Returns the coordinates of the center of the cell on the lattice of its
+    /// face.
+    fn coordinates(&self) -> (i64, i64) {
+        // shifting left and then right extends the sign of each coordinate
+        let unused = u64::BITS - COORDINATE_BITS;
+        let i = ((self.0 >> COORDINATE_BITS) << unused) as i64 >> unused;
+        let j = (self.0 << unused) as i64 >> unused;
+        (i, j)
+    }
+
+    /// Returns the center of the cell on the unit sphere.
+    fn center_point(&self) -> CartesianPoint {
+        let (i, j) = self.coordinates();
+        let (x, y) = lattice_to_plane(self.resolution(), i, j);
+        faces()[self.face() as usize].unproject(x, y)
+    }
+
+    /// Returns the center of the cell, a pair of axis tangent to the sphere at
+    /// that center, and the vertices of the cell projected onto that tangent
+    /// plane in counterclockwise order.
+    fn planar_boundary(
+        &self,
+    ) -> (
+        CartesianPoint,
+        CartesianPoint,
+        CartesianPoint,
+        Vec<(f64, f64)>,
+    ) {
+        let center = self.center_point();
+        let reference = if center.x().abs() < 0.9 {
+            CartesianPoint::new(1., 0., 0.)
+        } else {
+            CartesianPoint::new(0., 1., 0.)
+        };
+
+        let tangent = reference.cross(&center).normalize();
+        let bitangent = center.cross(&tangent);
+
+        // The cell is the intersection of the hemispheres closer to its center
+        // than to any other. Great circles are straight lines on the gnomonic
+        // projection, so these hemispheres are half-planes on the tangent one.
+        let (spacing, _) = lattice(self.resolution());
+        let size = 3. * spacing;
+        let square = vec![(-size, -size), (size, -size), (size, size), (-size, size)];
+
+        let mut vertices = nearby_cells(&center, self.resolution(), 2)
+            .into_iter()
+            .filter(|(cell, _)| cell != self)
+            .fold(square, |polygon, (_, other)| {
+                let normal = center.add_scaled(&other, -1.);
+                let (offset, a, b) = (
+                    center.dot(&normal),
+                    tangent.dot(&normal),
+                    bitangent.dot(&normal),
+                );
+
+                let function = |(x, y): &(f64, f64)| offset + a * x + b * y;
+                clip(
+                    &polygon,
+                    |point| function(point) >= 0.,
+                    |from, to| {
+                        let t = function(from) / (function(from) - function(to));
+                        (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+                    },
+                )
+            });
+
+        vertices.dedup_by(|a, b| (a.0 - b.0).hypot(a.1 - b.1) <= spacing * 1e-9);
+        while vertices.len() > 1 && {
+            let (first, last) = (vertices[0], vertices[vertices.len() - 1]);
+            (first.0 - last.0).hypot(first.1 - last.1) <= spacing * 1e-9
+        } {
+            vertices.pop();
+        }
+
+        (center, tangent, bitangent, vertices)
+    }
+}
+
+/// Returns the coordinates on the gnomonic projection of any face of the
+/// lattice point at the given coordinates of the given resolution.
+fn lattice_to_plane(resolution: u8, i: i64, j: i64) -> (f64, f64) {
+    let (spacing, angle) = lattice(resolution);
+    let (i, j) = (i as f64, j as f64);
+
+    (
+        spacing * (i * angle.cos() + j * (angle + FRAC_PI_3).cos()),
+        spacing * (i * angle.sin() + j * (angle + FRAC_PI_3).sin()),
+    )
+}
+
+/// Returns the coordinates of the lattice point of the given resolution
+/// closest to the given coordinates on the gnomonic projection of any face.
+fn plane_to_lattice(resolution: u8, x: f64, y: f64) -> (i64, i64) {
+    let (spacing, angle) = lattice(resolution);
+    let (sin, cos) = angle.sin_cos();
+    let (x, y) = ((x * cos + y * sin) / spacing, (y * cos - x * sin) / spacing);
+
+    let i = x - y / 3_f64.sqrt();
+    let j = 2. * y / 3_f64.sqrt();
+    let k = -i - j;
+
+    // rounding all three cube coordinates and fixing the one with the largest
+    // error keeps their sum at zero.
+    let (mut ri, mut rj, rk) = (i.round(), j.round(), k.round());
+    let (di, dj, dk) = ((ri - i).abs(), (rj - j).abs(), (rk - k).abs());
+    if di > dj && di > dk {
+        ri = -rj - rk;
+    } else if dj > dk {
+        rj = -ri - rk;
+    }
+
+    (ri as i64, rj as i64)
+}
+
+/// Returns the center of the cell of the given face, resolution and lattice
+/// coordinates, if the face is the one owning that center. Centers on the
+/// boundary of several faces belong to the first of them.
+fn center_of(face: usize, resolution: u8, i: i64, j: i64) -> Option<CartesianPoint> {
+    let (x, y) = lattice_to_plane(resolution, i, j);
+    let center = faces()[face].unproject(x, y);
+
+    (faces()[face].contains(&center)
+        && !faces()[..face].iter().any(|other| other.contains(&center)))
+    .then_some(center)
+}
+
+/// Returns the cells of the given resolution, with their centers, whose
+/// lattice coordinates are at most the given amount of rings away from the
+/// ones of the given point on any face.
+fn nearby_cells(
+    point: &CartesianPoint,
+    resolution: u8,
+    rings: i64,
+) -> Vec<(HexCell, CartesianPoint)> {
+    faces()
+        .iter()
+        .enumerate()
+        .filter_map(|(face, frame)| frame.project(point).map(|(x, y)| (face, x, y)))
+        .flat_map(|(face, x, y)| {
+            let (i, j) = plane_to_lattice(resolution, x, y);
+            (-rings..=rings)
+                .flat_map(move |di| (-rings..=rings).map(move |dj| (di, dj)))
+                .filter(move |(di, dj)| (di + dj).abs() <= rings)
+                .filter_map(move |(di, dj)| {
+                    let (x, y) = lattice_to_plane(resolution, i + di, j + dj);
+                    let center = faces()[face].unproject(x, y);
+                    faces()[face].contains(&center).then_some(center)
+                })
+        })
+        .filter_map(|center| {
+            // the lattice point may belong to another face sharing the same
+            // edge or vertex.
+            let owner = faces().iter().position(|face| face.contains(&center))?;
+            let (x, y) = faces()[owner].project(&center)?;
+            let (i, j) = plane_to_lattice(resolution, x, y);
+            let center = center_of(owner, resolution, i, j)?;
+            Some((HexCell::new(resolution, owner, i, j), center))
+        })
+        .fold(Vec::new(), |mut cells, (cell, center)| {
+            if !cells.iter().any(|(other, _)| *other == cell) {
+                cells.push((cell, center));
+            }
+
+            cells
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn grid_must_cover_the_sphere() {
+        struct TestCase {
+            name: &'static str,
+            resolution: u8,
+            cells: usize,
+        }
+
+        vec![
+            TestCase {
+                name: "resolution 0",
+                resolution: 0,
+                cells: 32,
+            },
+            TestCase {
+                name: "resolution 1",
+                resolution: 1,
+                cells: 212,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let cells = HexCell::from_geographic(&GeographicPoint::default(), test_case.resolution)
+                .k_ring(u32::MAX)
+                .into_iter()
+                .take(test_case.cells + 1)
+                .collect::<Vec<_>>();
+
+            assert_eq!(cells.len(), test_case.cells, "{}: cells", test_case.name);
+            assert_eq!(
+                cells.iter().filter(|cell| cell.is_pentagon()).count(),
+                12,
+                "{}: pentagons",
+                test_case.name
+            );
+
+            let area: f64 = cells.iter().map(HexCell::area).sum();
+            assert!(
+                approx_eq!(f64, area, 4. * PI, epsilon = 1e-9),
+                "{}: area {} ±ε = {}",
+                test_case.name,
+                area,
+                4. * PI
+            );
+
+            cells.iter().for_each(|cell| {
+                let sides = if cell.is_pentagon() { 5 } else { 6 };
+                assert_eq!(
+                    cell.vertices().len(),
+                    sides,
+                    "{}: vertices of {:?}",
+                    test_case.name,
+                    cell
+                );
+
+                assert_eq!(
+                    cell.neighbours().len(),
+                    sides,
+                    "{}: neighbours of {:?}",
+                    test_case.name,
+                    cell
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn from_geographic_must_not_fail() {
+        [
+            from_degrees(0., 0.),
+            from_degrees(-3.7, 40.4),
+            from_degrees(179.9, -89.9),
+            from_degrees(-120., 60.),
+            from_degrees(31.7, 58.3),
+        ]
+        .into_iter()
+        .for_each(|point| {
+            (0..=MAX_HEX_RESOLUTION).for_each(|resolution| {
+                let cell = HexCell::from_geographic(&point, resolution);
+                assert_eq!(cell.resolution(), resolution);
+                assert_eq!(HexCell::from_raw(cell.id()), Some(cell), "{point:?} id");
+                assert!(
+                    cell.boundary().contains(&point),
+                    "{point:?} must be inside its cell of resolution {resolution}"
+                );
+
+                assert_eq!(
+                    HexCell::from_geographic(&cell.center(), resolution),
+                    cell,
+                    "{point:?} center of resolution {resolution}"
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn hierarchy_must_not_fail() {
+        let cell = HexCell::from_geographic(&from_degrees(-3.7, 40.4), 5);
+        let children = cell.children();
+
+        assert_eq!(children.len(), 7, "children of a hexagon");
+        children.iter().for_each(|child| {
+            assert_eq!(child.parent(), Some(cell), "parent of {child:?}");
+        });
+
+        assert!(
+            children.contains(&HexCell::from_geographic(&cell.center(), 6)),
+            "the central child must share the center"
+        );
+
+        let area: f64 = children.iter().map(HexCell::area).sum();
+        assert!(
+            approx_eq!(f64, area, cell.area(), epsilon = cell.area() * 0.05),
+            "area of children {} ±ε = {}",
+            area,
+            cell.area()
+        );
+
+        let vertex = CartesianPoint::new(0., 1., (1. + 5_f64.sqrt()) / 2.);
+        let pentagon = HexCell::from_cartesian(&vertex, 0).unwrap();
+        assert!(pentagon.is_pentagon(), "vertices of the icosahedron");
+
+        assert_eq!(pentagon.children().len(), 6, "children of a pentagon");
+        assert_eq!(pentagon.parent(), None, "parent at resolution 0");
+        assert_eq!(cell.ancestor(0).resolution(), 0, "ancestor");
+    }
+
+    #[test]
+    fn from_cartesian_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: CartesianPoint,
+            want: Option<HexCell>,
+        }
+
+        vec![
+            TestCase {
+                name: "any magnitude must be the cell of its direction",
+                point: CartesianPoint::new(0., 0., 42.),
+                want: Some(HexCell::from_geographic(&from_degrees(0., 90.), 5)),
+            },
+            TestCase {
+                name: "zero vector has no direction",
+                point: CartesianPoint::new(0., 0., 0.),
+                want: None,
+            },
+            TestCase {
+                name: "infinite vector has no direction",
+                point: CartesianPoint::new(f64::INFINITY, 0., 0.),
+                want: None,
+            },
+            TestCase {
+                name: "not a number vector has no direction",
+                point: CartesianPoint::new(f64::NAN, 0., 0.),
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                HexCell::from_cartesian(&test_case.point, 5),
+                test_case.want,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn k_ring_must_not_fail() {
+        let cell = HexCell::from_geographic(&from_degrees(10., 10.), 7);
+        [(0, 1), (1, 7), (2, 19), (3, 37)]
+            .into_iter()
+            .for_each(|(k, size)| {
+                assert_eq!(cell.k_ring(k).len(), size, "k-ring of size {k}");
+            });
+    }
+}
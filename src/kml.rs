@@ -0,0 +1,1354 @@
+use crate::{
+    geometry::{format_number, point_from_degrees, MAX_PRECISION},
+    xml::{write_element, Element, XmlError, XmlReader},
+    GeographicPoint, Geometry,
+};
+use quick_xml::escape::escape;
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Read, Seek, Write},
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+use zip::{result::ZipError, write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+/// The namespace of KML 2.2 documents.
+const NAMESPACE: &str = "http://www.opengis.net/kml/2.2";
+
+/// The name of the main document of KMZ archives.
+const KMZ_DOCUMENT: &str = "doc.kml";
+
+/// The maximum nesting depth of containers and geometries.
+const MAX_DEPTH: usize = 64;
+
+/// Represents an error while reading a KML document or KMZ archive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KmlError {
+    /// The XML at the given byte offset is not well formed.
+    InvalidXml(String, usize),
+    /// The element at the given byte offset is not the expected one.
+    UnexpectedElement(String, usize),
+    /// The coordinates in the element at the given byte offset are not valid.
+    InvalidCoordinates(String, usize),
+    /// The value in the element at the given byte offset is not valid.
+    InvalidValue(String, usize),
+    /// The coordinates in the element at the given byte offset are out of
+    /// range.
+    OutOfRange(usize),
+    /// The KMZ archive has no KML document.
+    MissingDocument,
+    /// The KMZ archive is not valid.
+    InvalidArchive(String),
+    /// The underlying reader failed.
+    Io(String),
+}
+
+impl fmt::Display for KmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KmlError::InvalidXml(message, offset) => {
+                write!(f, "invalid xml at offset {offset}: {message}")
+            }
+            KmlError::UnexpectedElement(name, offset) => {
+                write!(f, "unexpected element {name:?} at offset {offset}")
+            }
+            KmlError::InvalidCoordinates(coordinates, offset) => {
+                write!(f, "invalid coordinates {coordinates:?} at offset {offset}")
+            }
+            KmlError::InvalidValue(value, offset) => {
+                write!(f, "invalid value {value:?} at offset {offset}")
+            }
+            KmlError::OutOfRange(offset) => {
+                write!(f, "coordinates out of range at offset {offset}")
+            }
+            KmlError::MissingDocument => write!(f, "missing kml document in archive"),
+            KmlError::InvalidArchive(message) => write!(f, "invalid archive: {message}"),
+            KmlError::Io(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for KmlError {}
+
+impl From<XmlError> for KmlError {
+    fn from(error: XmlError) -> Self {
+        match error {
+            XmlError::Invalid(message, offset) => KmlError::InvalidXml(message, offset),
+            XmlError::Io(message) => KmlError::Io(message),
+        }
+    }
+}
+
+impl From<ZipError> for KmlError {
+    fn from(error: ZipError) -> Self {
+        match error {
+            ZipError::Io(error) => KmlError::Io(error.to_string()),
+            error => KmlError::InvalidArchive(error.to_string()),
+        }
+    }
+}
+
+/// Represents how the altitude of the coordinates of a placemark is
+/// interpreted.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AltitudeMode {
+    /// The altitude is ignored, and the geometry is draped over the terrain.
+    #[default]
+    ClampToGround,
+    /// The altitude is relative to the terrain.
+    RelativeToGround,
+    /// The altitude is relative to the sea level.
+    Absolute,
+}
+
+impl AltitudeMode {
+    fn name(self) -> &'static str {
+        match self {
+            AltitudeMode::ClampToGround => "clampToGround",
+            AltitudeMode::RelativeToGround => "relativeToGround",
+            AltitudeMode::Absolute => "absolute",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "clampToGround" => Some(AltitudeMode::ClampToGround),
+            "relativeToGround" => Some(AltitudeMode::RelativeToGround),
+            "absolute" => Some(AltitudeMode::Absolute),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a color with transparency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: u8,
+}
+
+impl Color {
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Returns the opacity of the color, being `0` fully transparent.
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /// Returns the color as written in KML, which is `aabbggrr` in
+    /// hexadecimal.
+    fn to_kml(self) -> String {
+        format!(
+            "{:02x}{:02x}{:02x}{:02x}",
+            self.alpha, self.blue, self.green, self.red
+        )
+    }
+
+    fn from_kml(value: &str) -> Option<Self> {
+        if value.len() != 8 || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let [alpha, blue, green, red] = u32::from_str_radix(value, 16).ok()?.to_be_bytes();
+        Some(Self::new(red, green, blue, alpha))
+    }
+}
+
+/// Represents a shared style, referenced by placemarks through its id.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Style {
+    id: String,
+    icon: Option<String>,
+    icon_color: Option<Color>,
+    line_color: Option<Color>,
+    line_width: Option<f64>,
+    fill_color: Option<Color>,
+}
+
+impl Style {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the URL of the icon of points.
+    pub fn with_icon(mut self, href: impl Into<String>) -> Self {
+        self.icon = Some(href.into());
+        self
+    }
+
+    /// Sets the color the icon of points is tinted with.
+    pub fn with_icon_color(mut self, color: Color) -> Self {
+        self.icon_color = Some(color);
+        self
+    }
+
+    /// Sets the color of paths and polygon outlines.
+    pub fn with_line_color(mut self, color: Color) -> Self {
+        self.line_color = Some(color);
+        self
+    }
+
+    /// Sets the width (in pixels) of paths and polygon outlines.
+    pub fn with_line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// Sets the color polygons are filled with.
+    pub fn with_fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    pub fn icon_color(&self) -> Option<Color> {
+        self.icon_color
+    }
+
+    pub fn line_color(&self) -> Option<Color> {
+        self.line_color
+    }
+
+    pub fn line_width(&self) -> Option<f64> {
+        self.line_width
+    }
+
+    pub fn fill_color(&self) -> Option<Color> {
+        self.fill_color
+    }
+}
+
+/// Represents a placemark: a geometry with a name, a description and a
+/// style.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Placemark {
+    name: Option<String>,
+    description: Option<String>,
+    style: Option<String>,
+    altitude_mode: AltitudeMode,
+    extrude: bool,
+    geometry: Option<Geometry>,
+}
+
+impl Placemark {
+    pub fn new(geometry: impl Into<Geometry>) -> Self {
+        Self {
+            geometry: Some(geometry.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of the placemark.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the placemark.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the id of the style of the placemark.
+    pub fn with_style(mut self, id: impl Into<String>) -> Self {
+        self.style = Some(id.into());
+        self
+    }
+
+    /// Sets how the altitude of the coordinates of the placemark is
+    /// interpreted.
+    pub fn with_altitude_mode(mut self, altitude_mode: AltitudeMode) -> Self {
+        self.altitude_mode = altitude_mode;
+        self
+    }
+
+    /// Sets whether the geometry is connected to the ground by walls.
+    pub fn with_extrude(mut self, extrude: bool) -> Self {
+        self.extrude = extrude;
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the id of the style of the placemark, if any.
+    pub fn style(&self) -> Option<&str> {
+        self.style.as_deref()
+    }
+
+    pub fn altitude_mode(&self) -> AltitudeMode {
+        self.altitude_mode
+    }
+
+    pub fn extrude(&self) -> bool {
+        self.extrude
+    }
+
+    pub fn geometry(&self) -> Option<&Geometry> {
+        self.geometry.as_ref()
+    }
+}
+
+/// Represents a KML document: a collection of placemarks and the styles they
+/// share.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct KmlDocument {
+    name: Option<String>,
+    styles: Vec<Style>,
+    placemarks: Vec<Placemark>,
+}
+
+impl KmlDocument {
+    /// Sets the name of the document.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds the given style to the document.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.styles.push(style);
+        self
+    }
+
+    /// Adds the given placemark to the document.
+    pub fn with_placemark(mut self, placemark: Placemark) -> Self {
+        self.placemarks.push(placemark);
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn styles(&self) -> &[Style] {
+        &self.styles
+    }
+
+    pub fn placemarks(&self) -> &[Placemark] {
+        &self.placemarks
+    }
+}
+
+/// Represents the way documents are written as
+/// [KML 2.2](https://www.ogc.org/standard/kml/), or as zipped KMZ archives.
+///
+/// Paths and polygon rings are densified along great circles, so they are
+/// drawn as the shortest path between their points no matter how the viewer
+/// interpolates them. Altitudes are only written for placemarks not clamped
+/// to the ground.
+///
+/// Only the subset written is read back: folders are flattened, and any other
+/// element is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Kml {
+    precision: usize,
+    step: f64,
+}
+
+impl Default for Kml {
+    fn default() -> Self {
+        Self {
+            precision: 9,
+            step: 1_f64.to_radians(),
+        }
+    }
+}
+
+impl Kml {
+    /// Sets the maximum amount of decimal places of coordinates and
+    /// altitudes, up to 15.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(MAX_PRECISION);
+        self
+    }
+
+    /// Sets the maximum angular distance (in radiants) between consecutive
+    /// points of paths and polygon rings. Zero disables the densification, as
+    /// does any step requiring more than [`MAX_DENSIFY_SEGMENTS`] segments
+    /// between a pair of points.
+    ///
+    /// [`MAX_DENSIFY_SEGMENTS`]: crate::MAX_DENSIFY_SEGMENTS
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns the given document as KML.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{AltitudeMode, GeographicPoint, Kml, KmlDocument, Placemark};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(1_f64.to_radians())
+    ///     .with_latitude(2_f64.to_radians())
+    ///     .with_altitude(3.);
+    ///
+    /// let document = KmlDocument::default().with_placemark(
+    ///     Placemark::new(point).with_altitude_mode(AltitudeMode::Absolute),
+    /// );
+    ///
+    /// let kml = Kml::default().format(&document);
+    /// assert!(kml.contains("<altitudeMode>absolute</altitudeMode>"));
+    /// assert!(kml.contains("<coordinates>1,2,3</coordinates>"));
+    /// ```
+    pub fn format(&self, document: &KmlDocument) -> String {
+        let mut kml = String::new();
+        let _ = self.write_document(&mut kml, document);
+        kml
+    }
+
+    /// Writes the given document as KML into the given writer.
+    pub fn write<W: Write>(&self, mut writer: W, document: &KmlDocument) -> io::Result<()> {
+        writer.write_all(self.format(document).as_bytes())?;
+        writer.flush()
+    }
+
+    /// Writes the given document as a KMZ archive into the given writer.
+    pub fn write_kmz<W: Write + Seek>(&self, writer: W, document: &KmlDocument) -> io::Result<()> {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let mut archive = ZipWriter::new(writer);
+        archive.start_file(KMZ_DOCUMENT, options)?;
+        self.write(&mut archive, document)?;
+        archive.finish()?.flush()
+    }
+
+    /// Returns the document in the given KML string.
+    pub fn parse(value: &str) -> Result<KmlDocument, KmlError> {
+        Self::read(value.as_bytes())
+    }
+
+    /// Returns the document in the given KML reader.
+    pub fn read<R: BufRead>(reader: R) -> Result<KmlDocument, KmlError> {
+        let mut reader = XmlReader::new(reader);
+        let root = reader.root()?;
+        if root.name() != b"kml" {
+            return Err(KmlError::UnexpectedElement(
+                root.qualified_name(),
+                root.position,
+            ));
+        }
+
+        let mut document = KmlDocument::default();
+        container(&mut reader, &root, &mut document, 0)?;
+        Ok(document)
+    }
+
+    /// Returns the document in the given KMZ archive, which is the one named
+    /// `doc.kml`, or else the first KML file.
+    pub fn read_kmz<R: Read + Seek>(reader: R) -> Result<KmlDocument, KmlError> {
+        let mut archive = ZipArchive::new(reader)?;
+        let name = archive
+            .file_names()
+            .filter(|name| name.to_ascii_lowercase().ends_with(".kml"))
+            .min_by_key(|name| *name != KMZ_DOCUMENT)
+            .map(str::to_string)
+            .ok_or(KmlError::MissingDocument)?;
+
+        let file = archive.by_name(&name)?;
+        Self::read(BufReader::new(file))
+    }
+
+    fn write_document(&self, out: &mut impl fmt::Write, document: &KmlDocument) -> fmt::Result {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<kml xmlns="{NAMESPACE}">"#)?;
+        writeln!(out, "  <Document>")?;
+        if let Some(name) = &document.name {
+            write_element(out, 2, "name", name)?;
+        }
+
+        for style in &document.styles {
+            write_style(out, style)?;
+        }
+
+        for placemark in &document.placemarks {
+            writeln!(out, "    <Placemark>")?;
+            if let Some(name) = &placemark.name {
+                write_element(out, 3, "name", name)?;
+            }
+
+            if let Some(description) = &placemark.description {
+                write_element(out, 3, "description", description)?;
+            }
+
+            if let Some(style) = &placemark.style {
+                write_element(out, 3, "styleUrl", &format!("#{style}"))?;
+            }
+
+            if let Some(geometry) = &placemark.geometry {
+                self.write_geometry(out, 3, placemark, geometry)?;
+            }
+
+            writeln!(out, "    </Placemark>")?;
+        }
+
+        writeln!(out, "  </Document>")?;
+        writeln!(out, "</kml>")
+    }
+
+    fn write_geometry(
+        &self,
+        out: &mut impl fmt::Write,
+        depth: usize,
+        placemark: &Placemark,
+        geometry: &Geometry,
+    ) -> fmt::Result {
+        match geometry {
+            Geometry::Point(point) => self.write_point(out, depth, placemark, point),
+            Geometry::LineString(points) => self.write_line(out, depth, placemark, points),
+            Geometry::Polygon(rings) => self.write_polygon(out, depth, placemark, rings),
+            Geometry::MultiPoint(points) => {
+                writeln!(out, "{:indent$}<MultiGeometry>", "", indent = depth * 2)?;
+                for point in points {
+                    self.write_point(out, depth + 1, placemark, point)?;
+                }
+
+                writeln!(out, "{:indent$}</MultiGeometry>", "", indent = depth * 2)
+            }
+            Geometry::MultiLineString(lines) => {
+                writeln!(out, "{:indent$}<MultiGeometry>", "", indent = depth * 2)?;
+                for points in lines {
+                    self.write_line(out, depth + 1, placemark, points)?;
+                }
+
+                writeln!(out, "{:indent$}</MultiGeometry>", "", indent = depth * 2)
+            }
+            Geometry::MultiPolygon(polygons) => {
+                writeln!(out, "{:indent$}<MultiGeometry>", "", indent = depth * 2)?;
+                for rings in polygons {
+                    self.write_polygon(out, depth + 1, placemark, rings)?;
+                }
+
+                writeln!(out, "{:indent$}</MultiGeometry>", "", indent = depth * 2)
+            }
+        }
+    }
+
+    fn write_point(
+        &self,
+        out: &mut impl fmt::Write,
+        depth: usize,
+        placemark: &Placemark,
+        point: &GeographicPoint,
+    ) -> fmt::Result {
+        writeln!(out, "{:indent$}<Point>", "", indent = depth * 2)?;
+        write_options(out, depth + 1, placemark, false)?;
+        self.write_coordinates(out, depth + 1, placemark, &[*point])?;
+        writeln!(out, "{:indent$}</Point>", "", indent = depth * 2)
+    }
+
+    fn write_line(
+        &self,
+        out: &mut impl fmt::Write,
+        depth: usize,
+        placemark: &Placemark,
+        points: &[GeographicPoint],
+    ) -> fmt::Result {
+        writeln!(out, "{:indent$}<LineString>", "", indent = depth * 2)?;
+        write_options(out, depth + 1, placemark, true)?;
+        self.write_coordinates(out, depth + 1, placemark, &self.densify(points))?;
+        writeln!(out, "{:indent$}</LineString>", "", indent = depth * 2)
+    }
+
+    fn write_polygon(
+        &self,
+        out: &mut impl fmt::Write,
+        depth: usize,
+        placemark: &Placemark,
+        rings: &[Vec<GeographicPoint>],
+    ) -> fmt::Result {
+        writeln!(out, "{:indent$}<Polygon>", "", indent = depth * 2)?;
+        write_options(out, depth + 1, placemark, true)?;
+        for (index, ring) in rings.iter().enumerate() {
+            let boundary = match index {
+                0 => "outerBoundaryIs",
+                _ => "innerBoundaryIs",
+            };
+
+            writeln!(out, "{:indent$}<{boundary}>", "", indent = (depth + 1) * 2)?;
+            writeln!(out, "{:indent$}<LinearRing>", "", indent = (depth + 2) * 2)?;
+            self.write_coordinates(out, depth + 3, placemark, &self.densify(ring))?;
+            writeln!(out, "{:indent$}</LinearRing>", "", indent = (depth + 2) * 2)?;
+            writeln!(out, "{:indent$}</{boundary}>", "", indent = (depth + 1) * 2)?;
+        }
+
+        writeln!(out, "{:indent$}</Polygon>", "", indent = depth * 2)
+    }
+
+    fn write_coordinates(
+        &self,
+        out: &mut impl fmt::Write,
+        depth: usize,
+        placemark: &Placemark,
+        points: &[GeographicPoint],
+    ) -> fmt::Result {
+        let coordinates: Vec<String> = points
+            .iter()
+            .map(|point| {
+                let mut coordinates = format!(
+                    "{},{}",
+                    format_number(point.longitude().to_degrees(), self.precision),
+                    format_number(point.latitude().to_degrees(), self.precision)
+                );
+
+                if placemark.altitude_mode != AltitudeMode::ClampToGround {
+                    coordinates.push(',');
+                    coordinates.push_str(&format_number(point.altitude(), self.precision));
+                }
+
+                coordinates
+            })
+            .collect();
+
+        write_element(out, depth, "coordinates", &coordinates.join(" "))
+    }
+
+    /// Returns the given path with as many points in between each pair of
+    /// consecutive ones as required for them to be no farther than the step.
+    fn densify(&self, points: &[GeographicPoint]) -> Vec<GeographicPoint> {
+        points
+            .first()
+            .copied()
+            .into_iter()
+            .chain(points.windows(2).flat_map(|pair| {
+                pair[0]
+                    .densify_by_step(&pair[1], self.step)
+                    .unwrap_or_else(|| pair.to_vec())
+                    .into_iter()
+                    .skip(1)
+            }))
+            .collect()
+    }
+}
+
+fn write_style(out: &mut impl fmt::Write, style: &Style) -> fmt::Result {
+    writeln!(out, r#"    <Style id="{}">"#, escape(style.id.as_str()))?;
+    if style.icon.is_some() || style.icon_color.is_some() {
+        writeln!(out, "      <IconStyle>")?;
+        if let Some(color) = style.icon_color {
+            write_element(out, 4, "color", &color.to_kml())?;
+        }
+
+        if let Some(icon) = &style.icon {
+            writeln!(out, "        <Icon>")?;
+            write_element(out, 5, "href", icon)?;
+            writeln!(out, "        </Icon>")?;
+        }
+
+        writeln!(out, "      </IconStyle>")?;
+    }
+
+    if style.line_color.is_some() || style.line_width.is_some() {
+        writeln!(out, "      <LineStyle>")?;
+        if let Some(color) = style.line_color {
+            write_element(out, 4, "color", &color.to_kml())?;
+        }
+
+        if let Some(width) = style.line_width {
+            write_element(out, 4, "width", &format_number(width, MAX_PRECISION))?;
+        }
+
+        writeln!(out, "      </LineStyle>")?;
+    }
+
+    if let Some(color) = style.fill_color {
+        writeln!(out, "      <PolyStyle>")?;
+        write_element(out, 4, "color", &color.to_kml())?;
+        writeln!(out, "      </PolyStyle>")?;
+    }
+
+    writeln!(out, "    </Style>")
+}
+
+/// Writes the elements telling how the geometry of the given placemark is
+/// drawn. Tessellation only applies to surfaces, being paths and polygons.
+fn write_options(
+    out: &mut impl fmt::Write,
+    depth: usize,
+    placemark: &Placemark,
+    surface: bool,
+) -> fmt::Result {
+    if placemark.extrude {
+        write_element(out, depth, "extrude", "1")?;
+    }
+
+    if placemark.altitude_mode == AltitudeMode::ClampToGround {
+        if surface {
+            write_element(out, depth, "tessellate", "1")?;
+        }
+
+        return Ok(());
+    }
+
+    write_element(out, depth, "altitudeMode", placemark.altitude_mode.name())
+}
+
+/// Reads the styles and placemarks of the given container into the given
+/// document, recursively.
+fn container<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+    document: &mut KmlDocument,
+    depth: usize,
+) -> Result<(), KmlError> {
+    let is_document = element.name() == b"Document";
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"name" if is_document && document.name.is_none() => {
+                document.name = Some(reader.text(&child)?)
+            }
+            b"Document" | b"Folder" => container(reader, &child, document, nested(&child, depth)?)?,
+            b"Style" => document.styles.push(style(reader, &child)?),
+            b"Placemark" => document.placemarks.push(placemark(reader, &child)?),
+            _ => reader.skip(&child)?,
+        }
+
+        Ok(())
+    })
+}
+
+fn style<R: BufRead>(reader: &mut XmlReader<R>, element: &Element) -> Result<Style, KmlError> {
+    let mut style = Style::new(element.attribute("id")?.unwrap_or_default());
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"IconStyle" => reader.children(&child, |reader, item| {
+                match item.name() {
+                    b"color" => style.icon_color = Some(color(reader, &item)?),
+                    b"Icon" => reader.children(&item, |reader, href| {
+                        match href.name() {
+                            b"href" => style.icon = Some(reader.text(&href)?.trim().to_string()),
+                            _ => reader.skip(&href)?,
+                        }
+
+                        Ok::<_, KmlError>(())
+                    })?,
+                    _ => reader.skip(&item)?,
+                }
+
+                Ok::<_, KmlError>(())
+            })?,
+            b"LineStyle" => reader.children(&child, |reader, item| {
+                match item.name() {
+                    b"color" => style.line_color = Some(color(reader, &item)?),
+                    b"width" => {
+                        let text = reader.text(&item)?;
+                        let width = text
+                            .trim()
+                            .parse()
+                            .ok()
+                            .filter(|width: &f64| width.is_finite() && *width >= 0.)
+                            .ok_or(KmlError::InvalidValue(text, item.position))?;
+
+                        style.line_width = Some(width);
+                    }
+                    _ => reader.skip(&item)?,
+                }
+
+                Ok::<_, KmlError>(())
+            })?,
+            b"PolyStyle" => reader.children(&child, |reader, item| {
+                match item.name() {
+                    b"color" => style.fill_color = Some(color(reader, &item)?),
+                    _ => reader.skip(&item)?,
+                }
+
+                Ok::<_, KmlError>(())
+            })?,
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, KmlError>(())
+    })?;
+
+    Ok(style)
+}
+
+fn color<R: BufRead>(reader: &mut XmlReader<R>, element: &Element) -> Result<Color, KmlError> {
+    let text = reader.text(element)?;
+    Color::from_kml(text.trim()).ok_or(KmlError::InvalidValue(text, element.position))
+}
+
+fn placemark<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+) -> Result<Placemark, KmlError> {
+    let mut placemark = Placemark::default();
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"name" => placemark.name = Some(reader.text(&child)?),
+            b"description" => placemark.description = Some(reader.text(&child)?),
+            b"styleUrl" => {
+                let url = reader.text(&child)?;
+                placemark.style = Some(url.trim().trim_start_matches('#').to_string());
+            }
+            name if is_geometry(name) => {
+                let geometry = geometry(reader, &child, &mut placemark, 0)?;
+                placemark.geometry = Some(geometry);
+            }
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, KmlError>(())
+    })?;
+
+    Ok(placemark)
+}
+
+fn is_geometry(name: &[u8]) -> bool {
+    matches!(
+        name,
+        b"Point" | b"LineString" | b"LinearRing" | b"Polygon" | b"MultiGeometry"
+    )
+}
+
+/// Returns the geometry of the given element, setting how it is drawn into
+/// the given placemark. The members of a multi-geometry must all be of the
+/// same kind.
+fn geometry<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+    placemark: &mut Placemark,
+    depth: usize,
+) -> Result<Geometry, KmlError> {
+    let is_point = element.name() == b"Point";
+    let is_multi = element.name() == b"MultiGeometry";
+
+    let mut points = Vec::new();
+    let mut rings = Vec::new();
+    let mut members = None;
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"coordinates" => {
+                let text = reader.text(&child)?;
+                points = coordinates(&text, child.position)?;
+                if is_point && points.len() != 1 {
+                    return Err(KmlError::InvalidCoordinates(text, child.position));
+                }
+            }
+            b"extrude" => {
+                let text = reader.text(&child)?;
+                placemark.extrude = match text.trim() {
+                    "1" | "true" => true,
+                    "0" | "false" => false,
+                    _ => return Err(KmlError::InvalidValue(text, child.position)),
+                };
+            }
+            b"altitudeMode" => {
+                let text = reader.text(&child)?;
+                placemark.altitude_mode = AltitudeMode::from_name(text.trim())
+                    .ok_or(KmlError::InvalidValue(text, child.position))?;
+            }
+            b"outerBoundaryIs" => rings.insert(0, ring(reader, &child, nested(&child, depth)?)?),
+            b"innerBoundaryIs" => rings.push(ring(reader, &child, nested(&child, depth)?)?),
+            name if is_multi && is_geometry(name) => {
+                let member = geometry(reader, &child, placemark, nested(&child, depth)?)?;
+                members = Some(match (members.take(), member) {
+                    (None, Geometry::Point(point)) => Geometry::MultiPoint(vec![point]),
+                    (Some(Geometry::MultiPoint(mut points)), Geometry::Point(point)) => {
+                        points.push(point);
+                        Geometry::MultiPoint(points)
+                    }
+                    (None, Geometry::LineString(line)) => Geometry::MultiLineString(vec![line]),
+                    (Some(Geometry::MultiLineString(mut lines)), Geometry::LineString(line)) => {
+                        lines.push(line);
+                        Geometry::MultiLineString(lines)
+                    }
+                    (None, Geometry::Polygon(rings)) => Geometry::MultiPolygon(vec![rings]),
+                    (Some(Geometry::MultiPolygon(mut polygons)), Geometry::Polygon(rings)) => {
+                        polygons.push(rings);
+                        Geometry::MultiPolygon(polygons)
+                    }
+                    _ => {
+                        return Err(KmlError::UnexpectedElement(
+                            child.qualified_name(),
+                            child.position,
+                        ))
+                    }
+                });
+            }
+            _ => reader.skip(&child)?,
+        }
+
+        Ok(())
+    })?;
+
+    match element.name() {
+        b"Point" => match points[..] {
+            [point] => Ok(Geometry::Point(point)),
+            _ => Err(KmlError::InvalidCoordinates(
+                String::new(),
+                element.position,
+            )),
+        },
+        b"LineString" | b"LinearRing" => Ok(Geometry::LineString(points)),
+        b"Polygon" => Ok(Geometry::Polygon(rings)),
+        _ => Ok(members.unwrap_or(Geometry::MultiPoint(Vec::new()))),
+    }
+}
+
+/// Returns the coordinates of the linear ring in the given boundary.
+fn ring<R: BufRead>(
+    reader: &mut XmlReader<R>,
+    element: &Element,
+    depth: usize,
+) -> Result<Vec<GeographicPoint>, KmlError> {
+    let mut points = Vec::new();
+    reader.children(element, |reader, child| {
+        match child.name() {
+            b"LinearRing" => points = ring(reader, &child, nested(&child, depth)?)?,
+            b"coordinates" => points = coordinates(&reader.text(&child)?, child.position)?,
+            _ => reader.skip(&child)?,
+        }
+
+        Ok::<_, KmlError>(())
+    })?;
+
+    Ok(points)
+}
+
+/// Returns the depth of the given child element of an element at the given
+/// depth, unless it is nested too deep.
+fn nested(child: &Element, depth: usize) -> Result<usize, KmlError> {
+    if depth >= MAX_DEPTH {
+        return Err(KmlError::UnexpectedElement(
+            child.qualified_name(),
+            child.position,
+        ));
+    }
+
+    Ok(depth + 1)
+}
+
+/// Returns the points of the given whitespace-separated tuples of longitude,
+/// latitude and optional altitude.
+fn coordinates(text: &str, position: usize) -> Result<Vec<GeographicPoint>, KmlError> {
+    text.split_whitespace()
+        .map(|tuple| {
+            let invalid = || KmlError::InvalidCoordinates(tuple.to_string(), position);
+            let values = tuple
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<f64>, _>>()
+                .map_err(|_| invalid())?;
+
+            let (longitude, latitude, altitude) = match values[..] {
+                [longitude, latitude] => (longitude, latitude, 0.),
+                [longitude, latitude, altitude] => (longitude, latitude, altitude),
+                _ => return Err(invalid()),
+            };
+
+            point_from_degrees(longitude, latitude, altitude).ok_or(KmlError::OutOfRange(position))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use std::io::Cursor;
+
+    fn document() -> KmlDocument {
+        KmlDocument::default()
+            .with_name("Survey & plan")
+            .with_style(
+                Style::new("site")
+                    .with_icon("https://example.com/pin.png")
+                    .with_icon_color(Color::new(255, 255, 0, 255)),
+            )
+            .with_style(
+                Style::new("area")
+                    .with_line_color(Color::new(255, 0, 0, 255))
+                    .with_line_width(2.5)
+                    .with_fill_color(Color::new(0, 255, 0, 127)),
+            )
+            .with_placemark(
+                Placemark::new(from_degrees(1., 2.).with_altitude(3.))
+                    .with_name("Base")
+                    .with_description("<b>camp</b>")
+                    .with_style("site")
+                    .with_altitude_mode(AltitudeMode::Absolute)
+                    .with_extrude(true),
+            )
+            .with_placemark(Placemark::new(Geometry::LineString(vec![
+                from_degrees(0., 0.),
+                from_degrees(1.5, 0.),
+            ])))
+            .with_placemark(
+                Placemark::new(Geometry::Polygon(vec![
+                    vec![
+                        from_degrees(10., 10.).with_altitude(5.),
+                        from_degrees(10.5, 10.).with_altitude(5.),
+                        from_degrees(10., 10.5).with_altitude(5.),
+                        from_degrees(10., 10.).with_altitude(5.),
+                    ],
+                    vec![
+                        from_degrees(10.1, 10.1).with_altitude(5.),
+                        from_degrees(10.2, 10.1).with_altitude(5.),
+                        from_degrees(10.1, 10.2).with_altitude(5.),
+                        from_degrees(10.1, 10.1).with_altitude(5.),
+                    ],
+                ]))
+                .with_name("Field")
+                .with_style("area")
+                .with_altitude_mode(AltitudeMode::RelativeToGround),
+            )
+    }
+
+    #[test]
+    fn format_must_not_fail() {
+        let want = r##"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2">
+  <Document>
+    <name>Survey &amp; plan</name>
+    <Style id="site">
+      <IconStyle>
+        <color>ff00ffff</color>
+        <Icon>
+          <href>https://example.com/pin.png</href>
+        </Icon>
+      </IconStyle>
+    </Style>
+    <Style id="area">
+      <LineStyle>
+        <color>ff0000ff</color>
+        <width>2.5</width>
+      </LineStyle>
+      <PolyStyle>
+        <color>7f00ff00</color>
+      </PolyStyle>
+    </Style>
+    <Placemark>
+      <name>Base</name>
+      <description>&lt;b&gt;camp&lt;/b&gt;</description>
+      <styleUrl>#site</styleUrl>
+      <Point>
+        <extrude>1</extrude>
+        <altitudeMode>absolute</altitudeMode>
+        <coordinates>1,2,3</coordinates>
+      </Point>
+    </Placemark>
+    <Placemark>
+      <LineString>
+        <tessellate>1</tessellate>
+        <coordinates>0,0 0.75,0 1.5,0</coordinates>
+      </LineString>
+    </Placemark>
+    <Placemark>
+      <name>Field</name>
+      <styleUrl>#area</styleUrl>
+      <Polygon>
+        <altitudeMode>relativeToGround</altitudeMode>
+        <outerBoundaryIs>
+          <LinearRing>
+            <coordinates>10,10,5 10.5,10,5 10,10.5,5 10,10,5</coordinates>
+          </LinearRing>
+        </outerBoundaryIs>
+        <innerBoundaryIs>
+          <LinearRing>
+            <coordinates>10.1,10.1,5 10.2,10.1,5 10.1,10.2,5 10.1,10.1,5</coordinates>
+          </LinearRing>
+        </innerBoundaryIs>
+      </Polygon>
+    </Placemark>
+  </Document>
+</kml>
+"##;
+
+        assert_eq!(Kml::default().format(&document()), want);
+    }
+
+    #[test]
+    fn format_must_densify_paths() {
+        let line = Geometry::LineString(vec![from_degrees(0., 0.), from_degrees(90., 0.)]);
+        let document = KmlDocument::default().with_placemark(Placemark::new(line));
+
+        struct TestCase {
+            step: f64,
+            count: usize,
+        }
+
+        vec![
+            TestCase {
+                step: 10_f64.to_radians(),
+                count: 10,
+            },
+            TestCase {
+                step: 7_f64.to_radians(),
+                count: 14,
+            },
+            TestCase { step: 0., count: 2 },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let kml = Kml::default().with_step(test_case.step).format(&document);
+            let document = Kml::parse(&kml).unwrap();
+
+            let Some(Geometry::LineString(points)) = document.placemarks()[0].geometry() else {
+                panic!("step {}: got kml = {kml}", test_case.step);
+            };
+
+            assert_eq!(points.len(), test_case.count, "step {}", test_case.step);
+            assert!(points.windows(2).all(|pair| {
+                test_case.step == 0. || pair[0].distance(&pair[1]) <= test_case.step + 1e-9
+            }));
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        let document = Kml::parse(
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+  <Document>
+    <name>Export</name>
+    <open>1</open>
+    <StyleMap id="pair"><Pair><key>normal</key><styleUrl>#line</styleUrl></Pair></StyleMap>
+    <Style id="line"><LineStyle><color>80ff0000</color><width>4</width></LineStyle></Style>
+    <Folder>
+      <name>Tracks</name>
+      <Placemark>
+        <name>Ferries</name>
+        <styleUrl>#line</styleUrl>
+        <MultiGeometry>
+          <LineString><coordinates>
+            2.1,41.3 3.2,39.5
+          </coordinates></LineString>
+          <LineString><gx:altitudeOffset>0</gx:altitudeOffset><coordinates>3.2,39.5 4.2,39.9</coordinates></LineString>
+        </MultiGeometry>
+      </Placemark>
+    </Folder>
+    <Placemark>
+      <Point><extrude>true</extrude><altitudeMode>relativeToGround</altitudeMode><coordinates>-3.7,40.4,120</coordinates></Point>
+    </Placemark>
+  </Document>
+</kml>"##,
+        )
+        .unwrap();
+
+        assert_eq!(document.name(), Some("Export"));
+        assert_eq!(
+            document.styles(),
+            [Style::new("line")
+                .with_line_color(Color::new(0, 0, 255, 128))
+                .with_line_width(4.)]
+        );
+
+        let placemarks = document.placemarks();
+        assert_eq!(placemarks.len(), 2);
+        assert_eq!(placemarks[0].name(), Some("Ferries"));
+        assert_eq!(placemarks[0].style(), Some("line"));
+        assert_eq!(placemarks[0].altitude_mode(), AltitudeMode::ClampToGround);
+        assert_eq!(
+            placemarks[0].geometry(),
+            Some(&Geometry::MultiLineString(vec![
+                vec![from_degrees(2.1, 41.3), from_degrees(3.2, 39.5)],
+                vec![from_degrees(3.2, 39.5), from_degrees(4.2, 39.9)],
+            ]))
+        );
+
+        assert!(placemarks[1].extrude());
+        assert_eq!(
+            placemarks[1].altitude_mode(),
+            AltitudeMode::RelativeToGround
+        );
+        assert_eq!(
+            placemarks[1].geometry(),
+            Some(&Geometry::Point(
+                from_degrees(-3.7, 40.4).with_altitude(120.)
+            ))
+        );
+    }
+
+    #[test]
+    fn format_and_parse_must_round_trip() {
+        let kml = Kml::default();
+        let document = document();
+        let want = kml.format(&document);
+
+        let read = Kml::parse(&want).unwrap();
+        assert_eq!(read.styles(), document.styles());
+        assert_eq!(kml.format(&read), want);
+
+        let mut archive = Cursor::new(Vec::new());
+        kml.write_kmz(&mut archive, &document).unwrap();
+        assert!(archive.get_ref().starts_with(b"PK"));
+
+        archive.set_position(0);
+        let read = Kml::read_kmz(archive).unwrap();
+        assert_eq!(kml.format(&read), want);
+    }
+
+    #[test]
+    fn parse_errors_must_be_positioned() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            output: KmlError,
+        }
+
+        vec![
+            TestCase {
+                name: "unexpected root",
+                input: "<gpx/>",
+                output: KmlError::UnexpectedElement("gpx".to_string(), 0),
+            },
+            TestCase {
+                name: "invalid coordinates",
+                input: "<kml><Placemark><Point><coordinates>1;2</coordinates></Point></Placemark></kml>",
+                output: KmlError::InvalidCoordinates("1;2".to_string(), 23),
+            },
+            TestCase {
+                name: "point with many coordinates",
+                input: "<kml><Placemark><Point><coordinates>1,2 3,4</coordinates></Point></Placemark></kml>",
+                output: KmlError::InvalidCoordinates("1,2 3,4".to_string(), 23),
+            },
+            TestCase {
+                name: "latitude out of range",
+                input: "<kml><Placemark><Point><coordinates>0,91</coordinates></Point></Placemark></kml>",
+                output: KmlError::OutOfRange(23),
+            },
+            TestCase {
+                name: "longitude out of range",
+                input: "<kml><Placemark><Point><coordinates>-181,0</coordinates></Point></Placemark></kml>",
+                output: KmlError::OutOfRange(23),
+            },
+            TestCase {
+                name: "unknown altitude mode",
+                input: "<kml><Placemark><Point><altitudeMode>floating</altitudeMode></Point></Placemark></kml>",
+                output: KmlError::InvalidValue("floating".to_string(), 23),
+            },
+            TestCase {
+                name: "invalid color",
+                input: r#"<kml><Document><Style id="s"><LineStyle><color>red</color></LineStyle></Style></Document></kml>"#,
+                output: KmlError::InvalidValue("red".to_string(), 40),
+            },
+            TestCase {
+                name: "mixed multi-geometry",
+                input: "<kml><Placemark><MultiGeometry><Point><coordinates>0,0</coordinates></Point><LineString><coordinates>0,0 1,1</coordinates></LineString></MultiGeometry></Placemark></kml>",
+                output: KmlError::UnexpectedElement("LineString".to_string(), 76),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let result = Kml::parse(test_case.input);
+            assert_eq!(
+                result,
+                Err(test_case.output),
+                "{}: got document = {result:?}",
+                test_case.name,
+            );
+        });
+
+        let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+        archive
+            .start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        archive.write_all(b"no kml here").unwrap();
+
+        let mut archive = archive.finish().unwrap();
+        archive.set_position(0);
+        assert_eq!(Kml::read_kmz(archive), Err(KmlError::MissingDocument));
+
+        assert!(matches!(
+            Kml::read_kmz(Cursor::new(b"<kml/>".to_vec())),
+            Err(KmlError::InvalidArchive(_))
+        ));
+    }
+
+    #[test]
+    fn nesting_must_be_bounded() {
+        struct TestCase {
+            name: &'static str,
+            prefix: &'static str,
+            tag: &'static str,
+            suffix: &'static str,
+            // the amount of nested tags read before the error
+            limit: usize,
+        }
+
+        vec![
+            TestCase {
+                name: "folders",
+                prefix: "<kml>",
+                tag: "Folder",
+                suffix: "</kml>",
+                limit: 64,
+            },
+            TestCase {
+                name: "multi-geometries",
+                prefix: "<kml><Placemark>",
+                tag: "MultiGeometry",
+                suffix: "</Placemark></kml>",
+                limit: 65,
+            },
+            TestCase {
+                name: "linear rings",
+                prefix: "<kml><Placemark><Polygon><outerBoundaryIs>",
+                tag: "LinearRing",
+                suffix: "</outerBoundaryIs></Polygon></Placemark></kml>",
+                limit: 63,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let input = format!(
+                "{}{}{}{}",
+                test_case.prefix,
+                format!("<{}>", test_case.tag).repeat(20_000),
+                format!("</{}>", test_case.tag).repeat(20_000),
+                test_case.suffix
+            );
+
+            let position = test_case.prefix.len() + (test_case.tag.len() + 2) * test_case.limit;
+            assert_eq!(
+                Kml::parse(&input),
+                Err(KmlError::UnexpectedElement(
+                    test_case.tag.to_string(),
+                    position
+                )),
+                "{}",
+                test_case.name
+            );
+        });
+    }
+}
@@ -1,5 +1,92 @@
+mod bounding_box;
+pub use bounding_box::*;
+
+mod cap;
+pub use cap::*;
+
 mod cartesian;
 pub use cartesian::*;
 
+mod cell;
+pub use cell::*;
+
+mod coordinate_format;
+pub use coordinate_format::*;
+
+mod ellipsoid;
+pub use ellipsoid::*;
+
+mod geodesic;
+pub use geodesic::*;
+
 mod geographic;
 pub use geographic::*;
+
+mod geohash;
+pub use geohash::*;
+
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "geojson")]
+pub use geojson::*;
+
+mod geometry;
+pub use geometry::*;
+
+#[cfg(feature = "gpx")]
+mod gpx;
+#[cfg(feature = "gpx")]
+pub use gpx::*;
+
+mod great_circle;
+pub use great_circle::*;
+
+mod hex_grid;
+pub use hex_grid::*;
+
+#[cfg(feature = "kml")]
+mod kml;
+#[cfg(feature = "kml")]
+pub use kml::*;
+
+mod maidenhead;
+pub use maidenhead::*;
+
+mod mgrs;
+pub use mgrs::*;
+
+mod plus_code;
+pub use plus_code::*;
+
+mod point_index;
+pub use point_index::*;
+
+mod polygon;
+pub use polygon::*;
+
+mod projection;
+pub use projection::*;
+
+mod region_index;
+pub use region_index::*;
+
+mod rhumb;
+pub use rhumb::*;
+
+mod utm;
+pub use utm::*;
+
+mod web_mercator;
+pub use web_mercator::*;
+
+mod wkb;
+pub use wkb::*;
+
+mod wkt;
+pub use wkt::*;
+
+#[cfg(any(feature = "gpx", feature = "kml"))]
+mod xml;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,362 @@
+use crate::{GeoBoundingBox, GeographicPoint};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The maximum amount of characters of a locator.
+const MAX_LENGTH: usize = 10;
+
+/// The amount of divisions of each pair of characters of a locator, from the
+/// field to the extended subsquare.
+const DIVISIONS: [u32; MAX_LENGTH / 2] = [18, 10, 24, 10, 24];
+
+/// Represents an error while parsing a [`Maidenhead`] locator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaidenheadError {
+    /// The locator has an odd amount of characters, or more than 10.
+    InvalidLength(usize),
+    /// The character at the given position is out of the range of its pair.
+    InvalidCharacter(char, usize),
+}
+
+impl fmt::Display for MaidenheadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaidenheadError::InvalidLength(length) => write!(
+                f,
+                "maidenhead locator must have 2, 4, 6, 8 or 10 characters, got {length}"
+            ),
+            MaidenheadError::InvalidCharacter(character, position) => write!(
+                f,
+                "invalid maidenhead character {character:?} at position {position}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MaidenheadError {}
+
+/// Represents a [Maidenhead locator](https://en.wikipedia.org/wiki/Maidenhead_Locator_System):
+/// a cell of a grid of longitudes and latitudes encoded as pairs of fields
+/// (`AA`-`RR`), squares (`00`-`99`), subsquares (`aa`-`xx`), extended squares
+/// and extended subsquares.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Maidenhead(String);
+
+impl fmt::Display for Maidenhead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Maidenhead {
+    type Err = MaidenheadError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let length = value.chars().count();
+        if length == 0 || length > MAX_LENGTH || !length.is_multiple_of(2) {
+            return Err(MaidenheadError::InvalidLength(length));
+        }
+
+        value
+            .char_indices()
+            .enumerate()
+            .map(|(index, (position, character))| {
+                digit(character, index / 2)
+                    .map(|value| symbol(value, index / 2))
+                    .ok_or(MaidenheadError::InvalidCharacter(character, position))
+            })
+            .collect::<Result<String, _>>()
+            .map(Self)
+    }
+}
+
+#[wasm_bindgen]
+impl Maidenhead {
+    /// Returns the locator of the given amount of characters containing the
+    /// given point. The length is clamped into the range __[2, 10]__, and
+    /// rounded up to the next even number.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Maidenhead};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(11.60833_f64.to_radians())
+    ///     .with_latitude(48.14666_f64.to_radians());
+    ///
+    /// assert_eq!(Maidenhead::encode(&point, 6).to_string(), "JN58td");
+    /// ```
+    pub fn encode(point: &GeographicPoint, length: usize) -> Maidenhead {
+        let pairs = length.clamp(2, MAX_LENGTH).div_ceil(2);
+
+        // the northern pole belongs to the last row, while the antimeridian
+        // wraps around to the first column, as -180 and +180 are the same
+        let mut longitude = (point.longitude().to_degrees() + 180.) / 360.;
+        let mut latitude = ((point.latitude().to_degrees() + 90.) / 180.).min(1. - f64::EPSILON);
+        if longitude >= 1. {
+            longitude -= 1.;
+        }
+
+        let mut locator = String::with_capacity(pairs * 2);
+        DIVISIONS
+            .into_iter()
+            .take(pairs)
+            .enumerate()
+            .for_each(|(pair, divisions)| {
+                longitude *= divisions as f64;
+                latitude *= divisions as f64;
+
+                let (column, row) = (longitude.floor(), latitude.floor());
+                locator.push(symbol(column as u32, pair));
+                locator.push(symbol(row as u32, pair));
+
+                longitude -= column;
+                latitude -= row;
+            });
+
+        Self(locator)
+    }
+
+    /// Returns the amount of characters of the locator.
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the cell of the locator.
+    pub fn bounding_box(&self) -> GeoBoundingBox {
+        let (mut west, mut south) = (-180., -90.);
+        let (mut width, mut height) = (360., 180.);
+
+        self.0
+            .as_bytes()
+            .chunks(2)
+            .enumerate()
+            .for_each(|(pair, characters)| {
+                let divisions = DIVISIONS[pair] as f64;
+                width /= divisions;
+                height /= divisions;
+
+                let value = |character: u8| digit(character as char, pair).unwrap_or_default();
+                west += value(characters[0]) as f64 * width;
+                south += value(characters[1]) as f64 * height;
+            });
+
+        GeoBoundingBox::new(
+            f64::to_radians(west),
+            f64::to_radians(south),
+            f64::to_radians(west + width),
+            f64::to_radians(south + height),
+        )
+    }
+
+    /// Returns the center of the cell of the locator.
+    pub fn center(&self) -> GeographicPoint {
+        let bounding_box = self.bounding_box();
+        GeographicPoint::default()
+            .with_longitude(bounding_box.west() + bounding_box.width() / 2.)
+            .with_latitude((bounding_box.south() + bounding_box.north()) / 2.)
+    }
+}
+
+/// Returns the value of the given character in the given pair, if any.
+fn digit(character: char, pair: usize) -> Option<u32> {
+    let value = if pair.is_multiple_of(2) {
+        (character.to_ascii_uppercase() as u32).checked_sub('A' as u32)?
+    } else {
+        character.to_digit(10)?
+    };
+
+    (value < DIVISIONS[pair]).then_some(value)
+}
+
+/// Returns the character of the given value in the given pair: uppercase
+/// letters for fields, digits for squares and lowercase letters for
+/// subsquares.
+fn symbol(value: u32, pair: usize) -> char {
+    match pair {
+        0 => char::from(b'A' + value as u8),
+        _ if pair.is_multiple_of(2) => char::from(b'a' + value as u8),
+        _ => char::from(b'0' + value as u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn encode_must_not_fail() {
+        struct TestCase {
+            point: GeographicPoint,
+            length: usize,
+            locator: &'static str,
+        }
+
+        vec![
+            TestCase {
+                point: from_degrees(11.60833, 48.14666),
+                length: 6,
+                locator: "JN58td",
+            },
+            TestCase {
+                point: from_degrees(-77.065, 38.92),
+                length: 6,
+                locator: "FM18lw",
+            },
+            TestCase {
+                point: from_degrees(-72.72726, 41.714775),
+                length: 5,
+                locator: "FN31pr",
+            },
+            TestCase {
+                point: from_degrees(-72.72726, 41.714775),
+                length: 2,
+                locator: "FN",
+            },
+            TestCase {
+                point: from_degrees(-180., -90.),
+                length: 10,
+                locator: "AA00aa00aa",
+            },
+            TestCase {
+                point: from_degrees(180., 90.),
+                length: 4,
+                locator: "AR09",
+            },
+            TestCase {
+                point: from_degrees(179.99999, 90.),
+                length: 12,
+                locator: "RR99xx99xx",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let locator = Maidenhead::encode(&test_case.point, test_case.length);
+            assert_eq!(locator.to_string(), test_case.locator);
+
+            let bounding_box = locator.bounding_box();
+            let point = test_case.point;
+            let latitude = point.latitude().min(bounding_box.north());
+            assert!(
+                bounding_box
+                    .expand(1e-12)
+                    .contains(&point.with_latitude(latitude)),
+                "{}: {:?} must contain {:?}",
+                test_case.locator,
+                bounding_box,
+                point
+            );
+        });
+    }
+
+    #[test]
+    fn decode_must_not_fail() {
+        struct TestCase {
+            locator: &'static str,
+            bounds: (f64, f64, f64, f64),
+        }
+
+        vec![
+            TestCase {
+                locator: "JN",
+                bounds: (0., 40., 20., 50.),
+            },
+            TestCase {
+                locator: "jn58",
+                bounds: (10., 48., 12., 49.),
+            },
+            TestCase {
+                locator: "JN58TD",
+                bounds: (11.583333333, 48.125, 11.666666667, 48.166666667),
+            },
+            TestCase {
+                locator: "JN58td25",
+                bounds: (11.6, 48.145833333, 11.608333333, 48.15),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let locator: Maidenhead = test_case.locator.parse().unwrap();
+            let bounding_box = locator.bounding_box();
+            let (west, south, east, north) = test_case.bounds;
+
+            [
+                (bounding_box.west(), west),
+                (bounding_box.south(), south),
+                (bounding_box.east(), east),
+                (bounding_box.north(), north),
+            ]
+            .into_iter()
+            .for_each(|(got, want)| {
+                assert!(
+                    approx_eq!(f64, got.to_degrees(), want, epsilon = 1e-8),
+                    "{}: got {:?}",
+                    test_case.locator,
+                    bounding_box
+                );
+            });
+
+            assert_eq!(
+                Maidenhead::encode(&locator.center(), locator.length()),
+                locator,
+                "{}",
+                test_case.locator
+            );
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<&'static str, MaidenheadError>,
+        }
+
+        vec![
+            TestCase {
+                input: "fn31PR",
+                output: Ok("FN31pr"),
+            },
+            TestCase {
+                input: "",
+                output: Err(MaidenheadError::InvalidLength(0)),
+            },
+            TestCase {
+                input: "FN3",
+                output: Err(MaidenheadError::InvalidLength(3)),
+            },
+            TestCase {
+                input: "FN31pr00aa00",
+                output: Err(MaidenheadError::InvalidLength(12)),
+            },
+            TestCase {
+                input: "SN31",
+                output: Err(MaidenheadError::InvalidCharacter('S', 0)),
+            },
+            TestCase {
+                input: "FNA1",
+                output: Err(MaidenheadError::InvalidCharacter('A', 2)),
+            },
+            TestCase {
+                input: "FN31py",
+                output: Err(MaidenheadError::InvalidCharacter('y', 5)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = test_case
+                .input
+                .parse::<Maidenhead>()
+                .map(|locator| locator.to_string());
+            assert_eq!(
+                output,
+                test_case.output.map(str::to_string),
+                "{}",
+                test_case.input
+            );
+        });
+    }
+}
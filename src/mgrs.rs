@@ -0,0 +1,664 @@
+use crate::{GeoBoundingBox, GeographicPoint, Hemisphere, UtmPoint};
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    fmt,
+    str::FromStr,
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The latitude bands of the UTM zones, from south to north, each 8° tall
+/// except the last one, which is 12° tall.
+const BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+
+/// The column letters of the 100 km squares of the UTM zones, in sets of 8
+/// that repeat every 3 zones.
+const UTM_COLUMNS: [&[u8; 8]; 3] = [b"STUVWXYZ", b"ABCDEFGH", b"JKLMNPQR"];
+
+/// The row letters of the 100 km squares of the UTM zones, which repeat every
+/// 2000 km.
+const UTM_ROWS: &[u8; 20] = b"ABCDEFGHJKLMNPQRSTUV";
+
+/// The column letters of the 100 km squares of the UPS zones, together with
+/// the easting (in 100 km) of their first column, for the bands A, B, Y and Z.
+const UPS_COLUMNS: [(&[u8], u8); 4] = [
+    (b"JKLPQRSTUXYZ", 8),
+    (b"ABCFGHJKLPQR", 20),
+    (b"RSTUXYZ", 13),
+    (b"ABCFGHJ", 20),
+];
+
+/// The row letters of the 100 km squares of the UPS zones, together with the
+/// northing (in 100 km) of their first row, for the southern and northern
+/// hemispheres.
+const UPS_ROWS: [(&[u8], u8); 2] = [(b"ABCDEFGHJKLMNPQRSTUVWXYZ", 8), (b"ABCDEFGHJKLMNP", 13)];
+
+/// The size (in meters) of the largest squares.
+const SQUARE_SIZE: f64 = 100_000.;
+
+/// Both the easting and northing (in meters) of the poles in the UPS zones.
+const POLE: f64 = 2_000_000.;
+
+/// The maximum amount of digits per coordinate.
+const MAX_PRECISION: usize = 5;
+
+/// Represents an error while parsing a [`Mgrs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MgrsError {
+    /// The string has no characters.
+    Empty,
+    /// The string ends before the given position, where the given component
+    /// was expected.
+    Incomplete(&'static str, usize),
+    /// The character at the given position is not expected there.
+    InvalidCharacter(char, usize),
+    /// The zone is not in the range [1, 60].
+    InvalidZone(u32),
+    /// The latitude band does not exist, or does not match the zone.
+    InvalidBand(char),
+    /// The 100 km square does not exist in the zone.
+    InvalidSquare(char, char),
+    /// The amount of digits is odd or greater than 10.
+    InvalidDigits(usize),
+    /// The 100 km square is not in the latitude band.
+    OutOfBand(char, char, char),
+}
+
+impl fmt::Display for MgrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MgrsError::Empty => write!(f, "mgrs reference must not be empty"),
+            MgrsError::Incomplete(component, position) => {
+                write!(f, "missing {component} at position {position}")
+            }
+            MgrsError::InvalidCharacter(character, position) => {
+                write!(
+                    f,
+                    "unexpected character {character:?} at position {position}"
+                )
+            }
+            MgrsError::InvalidZone(zone) => {
+                write!(f, "invalid zone {zone}, it must be in the range [1, 60]")
+            }
+            MgrsError::InvalidBand(band) => write!(f, "invalid latitude band {band:?}"),
+            MgrsError::InvalidSquare(column, row) => {
+                write!(f, "invalid 100 km square \"{column}{row}\"")
+            }
+            MgrsError::InvalidDigits(count) => write!(
+                f,
+                "invalid amount of digits {count}, it must be even and at most 10"
+            ),
+            MgrsError::OutOfBand(column, row, band) => write!(
+                f,
+                "100 km square \"{column}{row}\" is not in the latitude band {band:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MgrsError {}
+
+/// Represents a cell of the
+/// [Military Grid Reference System](https://en.wikipedia.org/wiki/Military_Grid_Reference_System)
+/// (MGRS), which is also the
+/// [U.S. National Grid](https://en.wikipedia.org/wiki/United_States_National_Grid)
+/// (USNG) one: a square of the UTM or UPS coordinate systems on the WGS84
+/// ellipsoid, whose precision is the amount of digits of each coordinate, from
+/// 0 (100 km) to 5 (1 m).
+///
+/// It is displayed as a single word, like `31NAA6602100000`, or with spaces
+/// between its components if formatted with the alternate flag (`{:#}`), like
+/// `31N AA 66021 00000`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mgrs {
+    corner: UtmPoint,
+    band: u8,
+    precision: usize,
+}
+
+impl fmt::Display for Mgrs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if f.alternate() { " " } else { "" };
+        if self.corner.zone() != 0 {
+            write!(f, "{}", self.corner.zone())?;
+        }
+
+        let (column, row) = self.square();
+        write!(f, "{}{separator}{column}{row}", self.band as char)?;
+        if self.precision == 0 {
+            return Ok(());
+        }
+
+        let unit = self.size();
+        let digits = |value: f64| (value.rem_euclid(SQUARE_SIZE) / unit).round() as u64;
+        write!(
+            f,
+            "{separator}{:0width$}{separator}{:0width$}",
+            digits(self.corner.easting()),
+            digits(self.corner.northing()),
+            width = self.precision
+        )
+    }
+}
+
+impl FromStr for Mgrs {
+    type Err = MgrsError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let characters: Vec<(usize, char)> = value
+            .char_indices()
+            .filter(|(_, character)| !character.is_whitespace())
+            .collect();
+
+        if characters.is_empty() {
+            return Err(MgrsError::Empty);
+        }
+
+        let zone_digits = characters
+            .iter()
+            .take(2)
+            .take_while(|(_, character)| character.is_ascii_digit())
+            .count();
+
+        let zone = characters[..zone_digits]
+            .iter()
+            .filter_map(|(_, character)| character.to_digit(10))
+            .fold(0, |zone, digit| zone * 10 + digit);
+
+        if zone_digits > 0 && !(1..=60).contains(&zone) {
+            return Err(MgrsError::InvalidZone(zone));
+        }
+
+        let mut letters = characters[zone_digits..].iter().copied();
+        let mut letter = |component| {
+            let (position, character) = letters
+                .next()
+                .ok_or(MgrsError::Incomplete(component, value.len()))?;
+
+            let uppercase = character.to_ascii_uppercase();
+            if uppercase.is_ascii_uppercase() {
+                Ok(uppercase)
+            } else {
+                Err(MgrsError::InvalidCharacter(character, position))
+            }
+        };
+
+        let band = letter("latitude band")?;
+        let column = letter("100 km square")?;
+        let row = letter("100 km square")?;
+
+        let digits = &characters[zone_digits + 3..];
+        if let Some(&(position, character)) = digits
+            .iter()
+            .find(|(_, character)| !character.is_ascii_digit())
+        {
+            return Err(MgrsError::InvalidCharacter(character, position));
+        }
+
+        if !digits.len().is_multiple_of(2) || digits.len() > 2 * MAX_PRECISION {
+            return Err(MgrsError::InvalidDigits(digits.len()));
+        }
+
+        let precision = digits.len() / 2;
+        let unit = 10_f64.powi((MAX_PRECISION - precision) as i32);
+        let number = |digits: &[(usize, char)]| {
+            digits
+                .iter()
+                .filter_map(|(_, character)| character.to_digit(10))
+                .fold(0., |number, digit| number * 10. + digit as f64)
+                * unit
+        };
+
+        let (easting, northing) = (number(&digits[..precision]), number(&digits[precision..]));
+        let (hemisphere, square_easting, square_northing) = if zone_digits == 0 {
+            ups_square(band, column, row)?
+        } else {
+            utm_square(zone, band, column, row)?
+        };
+
+        let corner = UtmPoint::new(
+            zone as u8,
+            hemisphere,
+            square_easting + easting,
+            square_northing + northing,
+        )
+        .map_err(|_| MgrsError::OutOfBand(column, row, band))?;
+
+        Ok(Self {
+            corner,
+            band: band as u8,
+            precision,
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl Mgrs {
+    /// Returns the cell of the given precision containing the given point, in
+    /// its standard UTM or UPS zone. The precision is clamped into the range
+    /// __[0, 5]__.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Mgrs};
+    ///
+    /// let point = GeographicPoint::default();
+    ///
+    /// assert_eq!(Mgrs::encode(&point, 5).to_string(), "31NAA6602100000");
+    /// assert_eq!(format!("{:#}", Mgrs::encode(&point, 2)), "31N AA 66 00");
+    /// ```
+    pub fn encode(point: &GeographicPoint, precision: usize) -> Mgrs {
+        let precision = precision.min(MAX_PRECISION);
+        let unit = 10_f64.powi((MAX_PRECISION - precision) as i32);
+        let utm = UtmPoint::from_geographic(point);
+
+        // a tiny margin prevents rounding errors from moving the point into
+        // the previous cell.
+        let truncate = |value: f64| ((value + 1e-6) / unit).floor() * unit;
+        let easting = truncate(utm.easting());
+        let northing = truncate(utm.northing());
+
+        let band = if utm.zone() != 0 {
+            let index = ((point.latitude().to_degrees() + 80.) / 8.).floor();
+            BANDS[index.clamp(0., 19.) as usize]
+        } else {
+            match (utm.hemisphere(), easting < POLE) {
+                (Hemisphere::South, true) => b'A',
+                (Hemisphere::South, false) => b'B',
+                (Hemisphere::North, true) => b'Y',
+                (Hemisphere::North, false) => b'Z',
+            }
+        };
+
+        Self {
+            corner: UtmPoint::new(utm.zone(), utm.hemisphere(), easting, northing).unwrap_or(utm),
+            band,
+            precision,
+        }
+    }
+
+    /// Returns the amount of digits of each coordinate.
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Returns the length (in meters) of the sides of the cell.
+    pub fn size(&self) -> f64 {
+        10_f64.powi((MAX_PRECISION - self.precision) as i32)
+    }
+
+    /// Returns the south-western corner of the cell.
+    pub fn corner(&self) -> UtmPoint {
+        self.corner
+    }
+
+    /// Returns the center of the cell.
+    pub fn center(&self) -> GeographicPoint {
+        self.at(0.5, 0.5)
+    }
+
+    /// Returns the bounds of the cell.
+    pub fn bounding_box(&self) -> GeoBoundingBox {
+        const STEPS: usize = 8;
+
+        let size = self.size();
+        if self.corner.zone() == 0
+            && (self.corner.easting()..=self.corner.easting() + size).contains(&POLE)
+            && (self.corner.northing()..=self.corner.northing() + size).contains(&POLE)
+        {
+            // a cell containing the pole covers all longitudes
+            let boundary = (0..4).map(|corner| self.at((corner % 2) as f64, (corner / 2) as f64));
+            return match self.corner.hemisphere() {
+                Hemisphere::North => {
+                    let south = boundary
+                        .map(|point| point.latitude())
+                        .fold(FRAC_PI_2, f64::min);
+                    GeoBoundingBox::new(-PI, south, PI, FRAC_PI_2)
+                }
+                Hemisphere::South => {
+                    let north = boundary
+                        .map(|point| point.latitude())
+                        .fold(-FRAC_PI_2, f64::max);
+                    GeoBoundingBox::new(-PI, -FRAC_PI_2, PI, north)
+                }
+            };
+        }
+
+        let boundary: Vec<GeographicPoint> = (0..STEPS)
+            .flat_map(|step| {
+                let fraction = step as f64 / STEPS as f64;
+                [
+                    (fraction, 0.),
+                    (1., fraction),
+                    (1. - fraction, 1.),
+                    (0., 1. - fraction),
+                ]
+            })
+            .map(|(x, y)| self.at(x, y))
+            .collect();
+
+        GeoBoundingBox::from_points(&boundary).unwrap_or_default()
+    }
+}
+
+impl Mgrs {
+    /// Returns the point at the given fractions of the sides of the cell, from
+    /// its south-western corner.
+    fn at(&self, x: f64, y: f64) -> GeographicPoint {
+        let size = self.size();
+        UtmPoint::new(
+            self.corner.zone(),
+            self.corner.hemisphere(),
+            self.corner.easting() + x * size,
+            self.corner.northing() + y * size,
+        )
+        .unwrap_or(self.corner)
+        .to_geographic()
+    }
+
+    /// Returns the column and row letters of the 100 km square of the cell.
+    fn square(&self) -> (char, char) {
+        let column = (self.corner.easting() / SQUARE_SIZE).floor() as usize;
+        let row = (self.corner.northing() / SQUARE_SIZE).floor() as usize;
+
+        let (columns, rows, column, row): (&[u8], &[u8], usize, usize) = if self.corner.zone() == 0
+        {
+            let (columns, first_column) =
+                UPS_COLUMNS[ups_band_index(self.band as char).unwrap_or(0)];
+            let (rows, first_row) =
+                UPS_ROWS[(self.corner.hemisphere() == Hemisphere::North) as usize];
+            (
+                columns,
+                rows,
+                column.saturating_sub(first_column as usize),
+                row.saturating_sub(first_row as usize),
+            )
+        } else {
+            let zone = self.corner.zone() as usize;
+            let offset = if zone.is_multiple_of(2) { 5 } else { 0 };
+            (
+                UTM_COLUMNS[zone % 3],
+                UTM_ROWS,
+                column.saturating_sub(1),
+                (row + offset) % UTM_ROWS.len(),
+            )
+        };
+
+        (
+            columns[column.min(columns.len() - 1)] as char,
+            rows[row.min(rows.len() - 1)] as char,
+        )
+    }
+}
+
+/// Returns the position of the given UPS band in [`UPS_COLUMNS`], if any.
+fn ups_band_index(band: char) -> Option<usize> {
+    ['A', 'B', 'Y', 'Z'].iter().position(|&other| other == band)
+}
+
+/// Returns the hemisphere, easting and northing (in meters) of the given 100
+/// km square of an UPS zone.
+fn ups_square(band: char, column: char, row: char) -> Result<(Hemisphere, f64, f64), MgrsError> {
+    let band_index = ups_band_index(band).ok_or(MgrsError::InvalidBand(band))?;
+    let hemisphere = if band_index < 2 {
+        Hemisphere::South
+    } else {
+        Hemisphere::North
+    };
+
+    let (columns, first_column) = UPS_COLUMNS[band_index];
+    let (rows, first_row) = UPS_ROWS[(hemisphere == Hemisphere::North) as usize];
+    let position = |letters: &[u8], letter: char| letters.iter().position(|&c| c as char == letter);
+
+    match (position(columns, column), position(rows, row)) {
+        (Some(column), Some(row)) => Ok((
+            hemisphere,
+            (column + first_column as usize) as f64 * SQUARE_SIZE,
+            (row + first_row as usize) as f64 * SQUARE_SIZE,
+        )),
+        _ => Err(MgrsError::InvalidSquare(column, row)),
+    }
+}
+
+/// Returns the hemisphere, easting and northing (in meters) of the given 100
+/// km square of an UTM zone.
+fn utm_square(
+    zone: u32,
+    band: char,
+    column: char,
+    row: char,
+) -> Result<(Hemisphere, f64, f64), MgrsError> {
+    let band_index = BANDS
+        .iter()
+        .position(|&other| other as char == band)
+        .ok_or(MgrsError::InvalidBand(band))?;
+
+    let columns = UTM_COLUMNS[zone as usize % 3];
+    let offset = if zone.is_multiple_of(2) { 5 } else { 0 };
+    let (Some(column_index), Some(row_index)) = (
+        columns.iter().position(|&c| c as char == column),
+        UTM_ROWS.iter().position(|&c| c as char == row),
+    ) else {
+        return Err(MgrsError::InvalidSquare(column, row));
+    };
+
+    // the rows repeat every 2000 km, and so the northing is the closest one to
+    // the center of the band, on its central meridian
+    let south = -80. + 8. * band_index as f64;
+    let height = if band == 'X' { 12. } else { 8. };
+    let center = GeographicPoint::default()
+        .with_longitude((6. * zone as f64 - 183.).to_radians())
+        .with_latitude((south + height / 2.).to_radians());
+
+    let center = UtmPoint::from_geographic_in_zone(&center, zone as u8)
+        .map_err(|_| MgrsError::InvalidZone(zone))?;
+
+    const CYCLE: f64 = 20. * SQUARE_SIZE;
+    let base = ((row_index + UTM_ROWS.len() - offset) % UTM_ROWS.len()) as f64 * SQUARE_SIZE;
+    let northing = base + CYCLE * ((center.northing() - base) / CYCLE).round();
+
+    // a degree of latitude is at most 112 km long, and the square may start up
+    // to 100 km away from the band it belongs to
+    let tolerance = height / 2. * 112_000. + 2. * SQUARE_SIZE;
+    if (northing - center.northing()).abs() > tolerance {
+        return Err(MgrsError::OutOfBand(column, row, band));
+    }
+
+    Ok((
+        center.hemisphere(),
+        (column_index + 1) as f64 * SQUARE_SIZE,
+        northing,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+
+    #[test]
+    fn encode_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            precision: usize,
+            output: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "origin of coordinates",
+                point: from_degrees(0., 0.),
+                precision: 5,
+                output: "31NAA6602100000",
+            },
+            TestCase {
+                name: "100 km",
+                point: from_degrees(0., 0.),
+                precision: 0,
+                output: "31NAA",
+            },
+            TestCase {
+                name: "even zone",
+                point: from_degrees(44.4, 33.3),
+                precision: 2,
+                output: "38SMB4484",
+            },
+            TestCase {
+                name: "southern hemisphere",
+                point: from_degrees(21., -45.),
+                precision: 5,
+                output: "34GER0000017049",
+            },
+            TestCase {
+                name: "north pole",
+                point: from_degrees(0., 90.),
+                precision: 5,
+                output: "ZAH0000000000",
+            },
+            TestCase {
+                name: "south pole",
+                point: from_degrees(0., -90.),
+                precision: 3,
+                output: "BAN000000",
+            },
+            TestCase {
+                name: "precision is clamped",
+                point: from_degrees(0., 0.),
+                precision: 8,
+                output: "31NAA6602100000",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let mgrs = Mgrs::encode(&test_case.point, test_case.precision);
+            assert_eq!(mgrs.to_string(), test_case.output, "{}", test_case.name);
+        });
+    }
+
+    #[test]
+    fn parse_must_revert_encode() {
+        (-90..=90).step_by(5).for_each(|latitude| {
+            (-180..180).step_by(13).for_each(|longitude| {
+                let point = from_degrees(longitude as f64 + 0.123, latitude as f64);
+                (0..=MAX_PRECISION).for_each(|precision| {
+                    let mgrs = Mgrs::encode(&point, precision);
+                    let parsed = mgrs.to_string().parse::<Mgrs>();
+                    assert_eq!(
+                        parsed.as_ref().map(ToString::to_string),
+                        Ok(mgrs.to_string()),
+                        "{point:?} with precision {precision}"
+                    );
+
+                    let bounding_box = parsed.unwrap().bounding_box();
+                    assert!(
+                        bounding_box.expand(1e-12).contains(&point),
+                        "{mgrs} must contain {point:?} in {bounding_box:?}"
+                    );
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            output: Result<&'static str, MgrsError>,
+        }
+
+        vec![
+            TestCase {
+                name: "usng with spaces and lowercase",
+                input: " 18s uj 23487 06483 ",
+                output: Ok("18SUJ2348706483"),
+            },
+            TestCase {
+                name: "zone with leading zero",
+                input: "04QFJ1234567890",
+                output: Ok("4QFJ1234567890"),
+            },
+            TestCase {
+                name: "polar",
+                input: "ZAH",
+                output: Ok("ZAH"),
+            },
+            TestCase {
+                name: "empty",
+                input: "   ",
+                output: Err(MgrsError::Empty),
+            },
+            TestCase {
+                name: "zone out of range",
+                input: "61NAA",
+                output: Err(MgrsError::InvalidZone(61)),
+            },
+            TestCase {
+                name: "band missing",
+                input: "31",
+                output: Err(MgrsError::Incomplete("latitude band", 2)),
+            },
+            TestCase {
+                name: "square missing",
+                input: "31N A",
+                output: Err(MgrsError::Incomplete("100 km square", 5)),
+            },
+            TestCase {
+                name: "invalid band",
+                input: "31IAA",
+                output: Err(MgrsError::InvalidBand('I')),
+            },
+            TestCase {
+                name: "polar band with zone",
+                input: "31ZAA",
+                output: Err(MgrsError::InvalidBand('Z')),
+            },
+            TestCase {
+                name: "utm band without zone",
+                input: "NAA",
+                output: Err(MgrsError::InvalidBand('N')),
+            },
+            TestCase {
+                name: "column of another zone",
+                input: "31NJA",
+                output: Err(MgrsError::InvalidSquare('J', 'A')),
+            },
+            TestCase {
+                name: "square out of band",
+                input: "31NAP",
+                output: Err(MgrsError::OutOfBand('A', 'P', 'N')),
+            },
+            TestCase {
+                name: "odd amount of digits",
+                input: "31NAA123",
+                output: Err(MgrsError::InvalidDigits(3)),
+            },
+            TestCase {
+                name: "too many digits",
+                input: "31NAA123456123456",
+                output: Err(MgrsError::InvalidDigits(12)),
+            },
+            TestCase {
+                name: "letter among digits",
+                input: "31NAA12x4",
+                output: Err(MgrsError::InvalidCharacter('x', 7)),
+            },
+            TestCase {
+                name: "non ascii character",
+                input: "31Ñ",
+                output: Err(MgrsError::InvalidCharacter('Ñ', 2)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = test_case.input.parse::<Mgrs>().map(|mgrs| mgrs.to_string());
+            assert_eq!(
+                output,
+                test_case.output.map(ToString::to_string),
+                "{}",
+                test_case.name
+            );
+        });
+    }
+}
@@ -0,0 +1,742 @@
+use crate::{geographic::longitude_offset, GeoBoundingBox, GeographicPoint};
+use std::{fmt, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The alphabet of the base 20 encoding used by plus codes.
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+
+/// The character separating the first 8 digits from the rest.
+const SEPARATOR: char = '+';
+
+/// The character filling the digits up to the separator in codes shorter than
+/// 8 digits.
+const PADDING: char = '0';
+
+/// The position of the separator in full codes.
+const SEPARATOR_POSITION: usize = 8;
+
+/// The amount of digits encoded in pairs of latitude and longitude.
+const PAIR_LENGTH: usize = 10;
+
+/// The maximum amount of digits of a code.
+const MAX_LENGTH: usize = 15;
+
+/// The amount of rows and columns of the grid refining the pair digits.
+const GRID_ROWS: i64 = 5;
+const GRID_COLUMNS: i64 = 4;
+
+/// The amount of units per degree of the pair digits.
+const PAIR_PRECISION: i64 = 8000;
+
+/// The amount of units per degree of the longest codes.
+const FINAL_LATITUDE_PRECISION: i64 = PAIR_PRECISION * GRID_ROWS.pow(5);
+const FINAL_LONGITUDE_PRECISION: i64 = PAIR_PRECISION * GRID_COLUMNS.pow(5);
+
+/// Represents an error while parsing a [`PlusCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlusCodeError {
+    /// The string has no characters.
+    Empty,
+    /// The string has no separator.
+    MissingSeparator,
+    /// The separator at the given position is misplaced or repeated.
+    InvalidSeparator(usize),
+    /// The padding at the given position is misplaced or has an odd length.
+    InvalidPadding(usize),
+    /// The character at the given position is not part of the alphabet, or
+    /// out of range.
+    InvalidCharacter(char, usize),
+    /// The code has a single digit after the separator.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for PlusCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlusCodeError::Empty => write!(f, "plus code must not be empty"),
+            PlusCodeError::MissingSeparator => {
+                write!(f, "plus code must have a {SEPARATOR:?} separator")
+            }
+            PlusCodeError::InvalidSeparator(position) => {
+                write!(f, "invalid separator at position {position}")
+            }
+            PlusCodeError::InvalidPadding(position) => {
+                write!(f, "invalid padding at position {position}")
+            }
+            PlusCodeError::InvalidCharacter(character, position) => write!(
+                f,
+                "invalid plus code character {character:?} at position {position}"
+            ),
+            PlusCodeError::InvalidLength(length) => {
+                write!(f, "invalid plus code of {length} digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlusCodeError {}
+
+/// Represents an [Open Location Code](https://github.com/google/open-location-code)
+/// (plus code): a cell of a grid of latitudes and longitudes encoded as a base
+/// 20 string, with a separator after the eighth digit.
+///
+/// Full codes identify a cell on their own, while short codes, which lack some
+/// of the leading digits, must be recovered from a nearby reference point.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlusCode(String);
+
+impl fmt::Display for PlusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for PlusCode {
+    type Err = PlusCodeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(PlusCodeError::Empty);
+        }
+
+        let code = value.to_ascii_uppercase();
+        let separator = code
+            .find(SEPARATOR)
+            .ok_or(PlusCodeError::MissingSeparator)?;
+        if let Some(other) = code[separator + 1..].find(SEPARATOR) {
+            return Err(PlusCodeError::InvalidSeparator(separator + 1 + other));
+        }
+
+        if separator > SEPARATOR_POSITION || separator % 2 != 0 {
+            return Err(PlusCodeError::InvalidSeparator(separator));
+        }
+
+        if let Some(padding) = code.find(PADDING) {
+            // padding is only allowed in full codes, as an even amount of
+            // characters right before the separator
+            let length = code[padding..]
+                .chars()
+                .take_while(|&c| c == PADDING)
+                .count();
+            if separator < SEPARATOR_POSITION
+                || padding == 0
+                || !length.is_multiple_of(2)
+                || padding + length != separator
+                || separator + 1 != code.len()
+            {
+                return Err(PlusCodeError::InvalidPadding(padding));
+            }
+        }
+
+        if code.len() - separator - 1 == 1 {
+            return Err(PlusCodeError::InvalidLength(code.len() - 1));
+        }
+
+        if let Some((position, character)) = value
+            .char_indices()
+            .filter(|&(position, _)| position != separator)
+            .find(|&(_, character)| {
+                character != PADDING && digit(character.to_ascii_uppercase()).is_none()
+            })
+        {
+            return Err(PlusCodeError::InvalidCharacter(character, position));
+        }
+
+        if separator == SEPARATOR_POSITION {
+            // the first latitude digit must not exceed 90°, nor the first
+            // longitude digit 180°
+            let mut leading = code.chars().filter_map(digit);
+            if let Some(position) = [9, 18]
+                .into_iter()
+                .position(|limit| leading.next().is_some_and(|value| value >= limit))
+            {
+                return Err(PlusCodeError::InvalidCharacter(
+                    value[position..].chars().next().unwrap_or_default(),
+                    position,
+                ));
+            }
+        }
+
+        Ok(Self(code))
+    }
+}
+
+#[wasm_bindgen]
+impl PlusCode {
+    /// Returns the full code of the given amount of digits containing the
+    /// given point. The length is clamped into the range __[2, 15]__, and
+    /// rounded up to the next even number if shorter than 10.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, PlusCode};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(174.7859375_f64.to_radians())
+    ///     .with_latitude((-41.2730625_f64).to_radians());
+    ///
+    /// assert_eq!(PlusCode::encode(&point, 10).to_string(), "4VCPPQGP+Q9");
+    /// ```
+    pub fn encode(point: &GeographicPoint, length: usize) -> PlusCode {
+        let mut length = length.clamp(2, MAX_LENGTH);
+        if length < PAIR_LENGTH && !length.is_multiple_of(2) {
+            length += 1;
+        }
+
+        let mut latitude = point.latitude().to_degrees().clamp(-90., 90.);
+        if latitude == 90. {
+            // the northern pole belongs to the cell right below it
+            latitude -= latitude_precision(length);
+        }
+
+        let mut longitude = point.longitude().to_degrees();
+        if longitude >= 180. {
+            longitude -= 360.;
+        }
+
+        // rounding before flooring prevents floating point errors from moving
+        // the point into the previous cell
+        let units = |degrees: f64, precision: i64| {
+            ((degrees * precision as f64 * 1e6).round() / 1e6).floor() as i64
+        };
+
+        let mut latitude = units(latitude + 90., FINAL_LATITUDE_PRECISION);
+        let mut longitude = units(longitude + 180., FINAL_LONGITUDE_PRECISION);
+
+        let mut digits = Vec::with_capacity(MAX_LENGTH);
+        if length > PAIR_LENGTH {
+            (PAIR_LENGTH..MAX_LENGTH).for_each(|_| {
+                let index = (latitude % GRID_ROWS) * GRID_COLUMNS + longitude % GRID_COLUMNS;
+                digits.push(ALPHABET[index as usize]);
+                latitude /= GRID_ROWS;
+                longitude /= GRID_COLUMNS;
+            });
+        } else {
+            latitude /= GRID_ROWS.pow(5);
+            longitude /= GRID_COLUMNS.pow(5);
+        }
+
+        (0..PAIR_LENGTH / 2).for_each(|_| {
+            digits.push(ALPHABET[(longitude % 20) as usize]);
+            digits.push(ALPHABET[(latitude % 20) as usize]);
+            latitude /= 20;
+            longitude /= 20;
+        });
+
+        let mut code: String = digits.into_iter().rev().map(char::from).collect();
+        code.truncate(length);
+        while code.len() < SEPARATOR_POSITION {
+            code.push(PADDING);
+        }
+
+        code.insert(SEPARATOR_POSITION, SEPARATOR);
+        Self(code)
+    }
+
+    /// Returns the amount of digits of the code, excluding the separator and
+    /// any padding.
+    pub fn length(&self) -> usize {
+        self.digits().count()
+    }
+
+    /// Returns true if, and only if, the code identifies a cell on its own.
+    pub fn is_full(&self) -> bool {
+        self.0.find(SEPARATOR) == Some(SEPARATOR_POSITION)
+    }
+
+    /// Returns true if, and only if, the code lacks some of its leading digits.
+    pub fn is_short(&self) -> bool {
+        !self.is_full()
+    }
+
+    /// Returns the cell of the code, if full.
+    pub fn bounding_box(&self) -> Option<GeoBoundingBox> {
+        if !self.is_full() {
+            return None;
+        }
+
+        let digits: Vec<i64> = self.digits().take(MAX_LENGTH).collect();
+        let mut place = 20_i64.pow(4);
+        let (mut south, mut west) = (0, 0);
+
+        digits
+            .chunks(2)
+            .take(PAIR_LENGTH / 2)
+            .enumerate()
+            .for_each(|(index, pair)| {
+                if index > 0 {
+                    place /= 20;
+                }
+
+                south += pair[0] * place;
+                west += pair.get(1).copied().unwrap_or_default() * place;
+            });
+
+        // pairs are in units of the pair precision, while the grid digits are
+        // in units of the final one
+        let mut height = place as f64 / PAIR_PRECISION as f64;
+        let mut width = height;
+        let mut south = south as f64 / PAIR_PRECISION as f64 - 90.;
+        let mut west = west as f64 / PAIR_PRECISION as f64 - 180.;
+
+        if digits.len() > PAIR_LENGTH {
+            let (mut row_place, mut column_place) = (GRID_ROWS.pow(4), GRID_COLUMNS.pow(4));
+            let (mut rows, mut columns) = (0, 0);
+            digits[PAIR_LENGTH..]
+                .iter()
+                .enumerate()
+                .for_each(|(index, value)| {
+                    if index > 0 {
+                        row_place /= GRID_ROWS;
+                        column_place /= GRID_COLUMNS;
+                    }
+
+                    rows += value / GRID_COLUMNS * row_place;
+                    columns += value % GRID_COLUMNS * column_place;
+                });
+
+            south += rows as f64 / FINAL_LATITUDE_PRECISION as f64;
+            west += columns as f64 / FINAL_LONGITUDE_PRECISION as f64;
+            height = row_place as f64 / FINAL_LATITUDE_PRECISION as f64;
+            width = column_place as f64 / FINAL_LONGITUDE_PRECISION as f64;
+        }
+
+        Some(GeoBoundingBox::new(
+            west.to_radians(),
+            south.to_radians(),
+            (west + width).to_radians(),
+            (south + height).min(90.).to_radians(),
+        ))
+    }
+
+    /// Returns the center of the cell of the code, if full.
+    pub fn center(&self) -> Option<GeographicPoint> {
+        self.bounding_box().map(|bounding_box| {
+            GeographicPoint::default()
+                .with_longitude(bounding_box.west() + bounding_box.width() / 2.)
+                .with_latitude((bounding_box.south() + bounding_box.north()) / 2.)
+        })
+    }
+
+    /// Returns the shortest code that recovers self from the given reference
+    /// point, if self is full and has no padding.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, PlusCode};
+    ///
+    /// let code: PlusCode = "9C3W9QCJ+2VX".parse().unwrap();
+    /// let reference = GeographicPoint::default()
+    ///     .with_longitude((-1.217765625_f64).to_radians())
+    ///     .with_latitude(51.3708675_f64.to_radians());
+    ///
+    /// let short = code.shorten(&reference).unwrap();
+    /// assert_eq!(short.to_string(), "CJ+2VX");
+    /// assert_eq!(short.recover(&reference), code);
+    /// ```
+    pub fn shorten(&self, reference: &GeographicPoint) -> Option<PlusCode> {
+        if !self.is_full() || self.0.contains(PADDING) {
+            return None;
+        }
+
+        let center = self.center()?;
+        let range = (center.latitude() - reference.latitude())
+            .to_degrees()
+            .abs()
+            .max(
+                longitude_offset(center.longitude(), reference.longitude())
+                    .to_degrees()
+                    .abs(),
+            );
+
+        // the reference must be close enough to the center for the recovery
+        // to be unambiguous, with some margin
+        let short = [(8, 0.0025), (6, 0.05), (4, 1.)]
+            .into_iter()
+            .find(|&(_, resolution)| range < resolution * 0.3)
+            .map(|(removed, _)| Self(self.0[removed..].to_string()))
+            .unwrap_or_else(|| self.clone());
+
+        Some(short)
+    }
+
+    /// Returns the full code closest to the given reference point whose
+    /// trailing digits are the ones of self. Full codes are returned as they
+    /// are.
+    pub fn recover(&self, reference: &GeographicPoint) -> PlusCode {
+        if self.is_full() {
+            return self.clone();
+        }
+
+        let padding = SEPARATOR_POSITION - self.0.find(SEPARATOR).unwrap_or_default();
+        let resolution = 20_f64.powi(2 - padding as i32 / 2);
+
+        let prefix = Self::encode(reference, PAIR_LENGTH).0;
+        let candidate = Self(format!("{}{}", &prefix[..padding], self.0));
+        let Some(center) = candidate.center() else {
+            return candidate;
+        };
+
+        // the closest cell may be the next or previous one of the resolution
+        // of the missing digits
+        let reference_latitude = reference.latitude().to_degrees();
+        let mut latitude = center.latitude().to_degrees();
+        if reference_latitude + resolution / 2. < latitude && latitude - resolution >= -90. {
+            latitude -= resolution;
+        } else if reference_latitude - resolution / 2. > latitude && latitude + resolution <= 90. {
+            latitude += resolution;
+        }
+
+        let offset = longitude_offset(center.longitude(), reference.longitude()).to_degrees();
+        let mut longitude = center.longitude().to_degrees();
+        if offset > resolution / 2. {
+            longitude -= resolution;
+        } else if offset < -resolution / 2. {
+            longitude += resolution;
+        }
+
+        Self::encode(
+            &GeographicPoint::default()
+                .with_longitude(longitude.to_radians())
+                .with_latitude(latitude.to_radians()),
+            candidate.length(),
+        )
+    }
+}
+
+impl PlusCode {
+    /// Returns the values of the digits of the code.
+    fn digits(&self) -> impl Iterator<Item = i64> + '_ {
+        self.0.chars().filter_map(digit)
+    }
+}
+
+/// Returns the value of the given digit, if any.
+fn digit(character: char) -> Option<i64> {
+    ALPHABET
+        .iter()
+        .position(|&c| c as char == character)
+        .map(|value| value as i64)
+}
+
+/// Returns the height (in degrees) of the cells of the given length.
+fn latitude_precision(length: usize) -> f64 {
+    if length <= PAIR_LENGTH {
+        20_f64.powi(2 - (length / 2) as i32)
+    } else {
+        20_f64.powi(-3) / (GRID_ROWS as f64).powi((length - PAIR_LENGTH) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn encode_must_not_fail() {
+        struct TestCase {
+            point: GeographicPoint,
+            length: usize,
+            code: &'static str,
+        }
+
+        vec![
+            TestCase {
+                point: from_degrees(2.775, 20.375),
+                length: 6,
+                code: "7FG49Q00+",
+            },
+            TestCase {
+                point: from_degrees(2.7821875, 20.3700625),
+                length: 10,
+                code: "7FG49QCJ+2V",
+            },
+            TestCase {
+                point: from_degrees(2.782234375, 20.3701125),
+                length: 11,
+                code: "7FG49QCJ+2VX",
+            },
+            TestCase {
+                point: from_degrees(2.78223535156, 20.3701135),
+                length: 13,
+                code: "7FG49QCJ+2VXGJ",
+            },
+            TestCase {
+                point: from_degrees(8.0000625, 47.0000625),
+                length: 10,
+                code: "8FVC2222+22",
+            },
+            TestCase {
+                point: from_degrees(-179.5, 0.5),
+                length: 4,
+                code: "62G20000+",
+            },
+            TestCase {
+                point: from_degrees(-179.5, -89.5),
+                length: 4,
+                code: "22220000+",
+            },
+            TestCase {
+                point: from_degrees(179.5, 0.5),
+                length: 4,
+                code: "6VGX0000+",
+            },
+            TestCase {
+                point: from_degrees(1., 1.),
+                length: 11,
+                code: "6FH32222+222",
+            },
+            TestCase {
+                point: from_degrees(1., 90.),
+                length: 4,
+                code: "CFX30000+",
+            },
+            TestCase {
+                point: from_degrees(180., 1.),
+                length: 4,
+                code: "62H20000+",
+            },
+            TestCase {
+                point: from_degrees(1., 1.),
+                length: 3,
+                code: "6FH30000+",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let code = PlusCode::encode(&test_case.point, test_case.length);
+            assert_eq!(code.to_string(), test_case.code);
+
+            let bounding_box = code.bounding_box().unwrap();
+            let point = test_case.point;
+            let latitude = point.latitude().min(bounding_box.north());
+            assert!(
+                bounding_box
+                    .expand(1e-12)
+                    .contains(&point.with_latitude(latitude)),
+                "{}: {:?} must contain {:?}",
+                test_case.code,
+                bounding_box,
+                point
+            );
+        });
+    }
+
+    #[test]
+    fn decode_must_not_fail() {
+        struct TestCase {
+            code: &'static str,
+            bounds: (f64, f64, f64, f64),
+        }
+
+        vec![
+            TestCase {
+                code: "7FG49Q00+",
+                bounds: (2.75, 20.35, 2.8, 20.4),
+            },
+            TestCase {
+                code: "7fg49qcj+2vx",
+                bounds: (2.78221875, 20.3701, 2.78225, 20.370125),
+            },
+            TestCase {
+                code: "CFX30000+",
+                bounds: (1., 89., 2., 90.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let code: PlusCode = test_case.code.parse().unwrap();
+            let bounding_box = code.bounding_box().unwrap();
+            let (west, south, east, north) = test_case.bounds;
+
+            [
+                (bounding_box.west(), west),
+                (bounding_box.south(), south),
+                (bounding_box.east(), east),
+                (bounding_box.north(), north),
+            ]
+            .into_iter()
+            .for_each(|(got, want)| {
+                assert!(
+                    approx_eq!(f64, got.to_degrees(), want, epsilon = 1e-9),
+                    "{}: got {:?}",
+                    test_case.code,
+                    bounding_box
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        struct TestCase {
+            input: &'static str,
+            output: Result<(), PlusCodeError>,
+        }
+
+        vec![
+            TestCase {
+                input: "8FWC2345+G6",
+                output: Ok(()),
+            },
+            TestCase {
+                input: "8fwc2345+",
+                output: Ok(()),
+            },
+            TestCase {
+                input: "WC2345+G6G",
+                output: Ok(()),
+            },
+            TestCase {
+                input: "",
+                output: Err(PlusCodeError::Empty),
+            },
+            TestCase {
+                input: "8FWC2345G6",
+                output: Err(PlusCodeError::MissingSeparator),
+            },
+            TestCase {
+                input: "8FWC2345+G6+",
+                output: Err(PlusCodeError::InvalidSeparator(11)),
+            },
+            TestCase {
+                input: "8FWC234+5G6",
+                output: Err(PlusCodeError::InvalidSeparator(7)),
+            },
+            TestCase {
+                input: "8FWC2300+G6",
+                output: Err(PlusCodeError::InvalidPadding(6)),
+            },
+            TestCase {
+                input: "8FWC2000+",
+                output: Err(PlusCodeError::InvalidPadding(5)),
+            },
+            TestCase {
+                input: "WC2300+",
+                output: Err(PlusCodeError::InvalidPadding(4)),
+            },
+            TestCase {
+                input: "8FWC2345+G",
+                output: Err(PlusCodeError::InvalidLength(9)),
+            },
+            TestCase {
+                input: "8FWC2345+GI",
+                output: Err(PlusCodeError::InvalidCharacter('I', 10)),
+            },
+            TestCase {
+                input: "F2222222+",
+                output: Err(PlusCodeError::InvalidCharacter('F', 0)),
+            },
+            TestCase {
+                input: "8X222222+",
+                output: Err(PlusCodeError::InvalidCharacter('X', 1)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = test_case.input.parse::<PlusCode>().map(|_| ());
+            assert_eq!(output, test_case.output, "{}", test_case.input);
+        });
+    }
+
+    #[test]
+    fn shorten_and_recover_must_not_fail() {
+        struct TestCase {
+            code: &'static str,
+            reference: GeographicPoint,
+            short: &'static str,
+        }
+
+        vec![
+            TestCase {
+                code: "9C3W9QCJ+2VX",
+                reference: from_degrees(-1.217765625, 51.3701125),
+                short: "+2VX",
+            },
+            TestCase {
+                code: "9C3W9QCJ+2VX",
+                reference: from_degrees(-1.217765625, 51.3708675),
+                short: "CJ+2VX",
+            },
+            TestCase {
+                code: "9C3W9QCJ+2VX",
+                reference: from_degrees(-1.217765625, 51.3958675),
+                short: "9QCJ+2VX",
+            },
+            TestCase {
+                code: "9C3W9QCJ+2VX",
+                reference: from_degrees(-3.917765625, 51.3701125),
+                short: "9C3W9QCJ+2VX",
+            },
+            TestCase {
+                code: "62H22222+22",
+                reference: from_degrees(179.9, 1.0),
+                short: "2222+22",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let code: PlusCode = test_case.code.parse().unwrap();
+            let short = code.shorten(&test_case.reference).unwrap();
+            assert_eq!(
+                short.to_string(),
+                test_case.short,
+                "{} shortened",
+                test_case.code
+            );
+
+            let short: PlusCode = test_case.short.parse().unwrap();
+            assert_eq!(
+                short.recover(&test_case.reference),
+                code,
+                "{} recovered",
+                test_case.short
+            );
+        });
+    }
+
+    #[test]
+    fn recover_must_not_fail() {
+        struct TestCase {
+            short: &'static str,
+            reference: GeographicPoint,
+            code: &'static str,
+        }
+
+        vec![
+            TestCase {
+                short: "2222+22",
+                reference: from_degrees(0., 89.6),
+                code: "CFX22222+22",
+            },
+            TestCase {
+                short: "2222+22",
+                reference: from_degrees(-179.9, -89.6),
+                code: "22222222+22",
+            },
+            TestCase {
+                short: "XXXX+XX",
+                reference: from_degrees(-179.9, 1.),
+                code: "6VGXXXXX+XX",
+            },
+            TestCase {
+                short: "8FVC2222+22",
+                reference: from_degrees(0., 0.),
+                code: "8FVC2222+22",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let short: PlusCode = test_case.short.parse().unwrap();
+            assert_eq!(
+                short.recover(&test_case.reference).to_string(),
+                test_case.code,
+                "{}",
+                test_case.short
+            );
+        });
+    }
+}
@@ -0,0 +1,463 @@
+use crate::{CartesianPoint, GeographicPoint};
+use std::f64::consts::PI;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Represents a point found in a [`PointIndex`], together with its angular
+/// distance (in radiants) to the queried one.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Neighbour {
+    key: usize,
+    point: GeographicPoint,
+    distance: f64,
+}
+
+#[wasm_bindgen]
+impl Neighbour {
+    /// Returns the key the point was given when inserted into the index.
+    pub fn key(&self) -> usize {
+        self.key
+    }
+
+    /// Returns the point found.
+    pub fn point(&self) -> GeographicPoint {
+        self.point
+    }
+
+    /// Returns the angular distance (in radiants) from the queried point, as
+    /// given by [`GeographicPoint::distance`].
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+}
+
+/// A node of the k-d tree.
+#[derive(Debug, Clone)]
+struct Node {
+    key: usize,
+    point: GeographicPoint,
+    direction: CartesianPoint,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+    removed: bool,
+}
+
+/// Represents a set of points indexed by a
+/// [k-d tree](https://en.wikipedia.org/wiki/K-d_tree) over their directions as
+/// unit vectors.
+///
+/// Since the straight distance between two unit vectors grows along with the
+/// angle between them, the closest points in space are the closest ones on
+/// the surface of the sphere too. Nevertheless, all results are ranked by
+/// their great-circle distance.
+///
+/// Each point is identified by the key it was given on insertion, which is
+/// never reused.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct PointIndex {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+    locations: Vec<Option<usize>>,
+    removed: usize,
+}
+
+#[wasm_bindgen]
+impl PointIndex {
+    /// Returns the index of the given points, whose keys are their positions in
+    /// the given vector.
+    #[wasm_bindgen(constructor)]
+    pub fn new(points: Vec<GeographicPoint>) -> Self {
+        let mut index = Self {
+            locations: vec![None; points.len()],
+            ..Default::default()
+        };
+
+        index.build(points.into_iter().enumerate().collect());
+        index
+    }
+
+    /// Returns the amount of points in the index.
+    pub fn len(&self) -> usize {
+        self.nodes.len() - self.removed
+    }
+
+    /// Returns true if, and only if, the index has no points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the point with the given key, if any.
+    pub fn get(&self, key: usize) -> Option<GeographicPoint> {
+        self.locations
+            .get(key)
+            .copied()
+            .flatten()
+            .map(|node| self.nodes[node].point)
+    }
+
+    /// Adds the given point into the index, returning its key.
+    pub fn insert(&mut self, point: GeographicPoint) -> usize {
+        let key = self.locations.len();
+        let direction = CartesianPoint::direction_of(&point);
+
+        let mut parent = None;
+        let mut current = self.root;
+        while let Some(node) = current {
+            let axis = self.nodes[node].axis;
+            let left = direction[axis] < self.nodes[node].direction[axis];
+            parent = Some((node, left));
+            current = if left {
+                self.nodes[node].left
+            } else {
+                self.nodes[node].right
+            };
+        }
+
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            key,
+            point,
+            direction,
+            axis: parent.map_or(0, |(parent, _)| (self.nodes[parent].axis + 1) % 3),
+            left: None,
+            right: None,
+            removed: false,
+        });
+
+        match parent {
+            Some((parent, true)) => self.nodes[parent].left = Some(node),
+            Some((parent, false)) => self.nodes[parent].right = Some(node),
+            None => self.root = Some(node),
+        }
+
+        self.locations.push(Some(node));
+        key
+    }
+
+    /// Removes the point with the given key from the index, returning it if it
+    /// was there.
+    pub fn remove(&mut self, key: usize) -> Option<GeographicPoint> {
+        let node = self.locations.get_mut(key)?.take()?;
+        self.nodes[node].removed = true;
+        self.removed += 1;
+
+        let point = self.nodes[node].point;
+        if self.removed > self.nodes.len() / 2 {
+            // too many dead nodes slow down the queries
+            let entries = self
+                .nodes
+                .drain(..)
+                .filter(|node| !node.removed)
+                .map(|node| (node.key, node.point))
+                .collect();
+
+            self.build(entries);
+        }
+
+        Some(point)
+    }
+
+    /// Returns the given amount of points closest to the given one, sorted by
+    /// their distance to it.
+    pub fn nearest(&self, point: &GeographicPoint, count: usize) -> Vec<Neighbour> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let target = CartesianPoint::direction_of(point);
+
+        // the closest nodes found so far, sorted by their chord to the target
+        let mut closest: Vec<(f64, usize)> = Vec::with_capacity(count + 1);
+        let worst = |closest: &Vec<(f64, usize)>| {
+            if closest.len() < count {
+                f64::INFINITY
+            } else {
+                closest[closest.len() - 1].0
+            }
+        };
+
+        self.traverse(&target, |chord, node| {
+            if chord < worst(&closest) {
+                let position = closest.partition_point(|&(other, _)| other <= chord);
+                closest.insert(position, (chord, node));
+                closest.truncate(count);
+            }
+
+            worst(&closest)
+        });
+
+        self.neighbours(point, closest.into_iter().map(|(_, node)| node))
+    }
+
+    /// Returns all the points at an angular distance (in radiants) of at most
+    /// the given one from the given point, sorted by their distance to it.
+    pub fn within(&self, point: &GeographicPoint, radius: f64) -> Vec<Neighbour> {
+        let target = CartesianPoint::direction_of(point);
+
+        // some tolerance keeps any point at the given distance despite rounding
+        // errors, since the final filter uses the great-circle distance.
+        let limit = 2. * (radius.clamp(0., PI) / 2.).sin() + 1e-12;
+
+        let mut found = Vec::new();
+        self.traverse(&target, |chord, node| {
+            if chord <= limit {
+                found.push(node);
+            }
+
+            limit
+        });
+
+        self.neighbours(point, found.into_iter())
+            .into_iter()
+            .filter(|neighbour| neighbour.distance <= radius)
+            .collect()
+    }
+}
+
+impl PointIndex {
+    /// Replaces the tree by a balanced one with the given entries.
+    fn build(&mut self, entries: Vec<(usize, GeographicPoint)>) {
+        self.nodes.clear();
+        self.removed = 0;
+
+        let mut entries: Vec<(usize, GeographicPoint, CartesianPoint)> = entries
+            .into_iter()
+            .map(|(key, point)| (key, point, CartesianPoint::direction_of(&point)))
+            .collect();
+
+        self.root = self.build_subtree(&mut entries);
+        self.nodes.iter().enumerate().for_each(|(node, entry)| {
+            self.locations[entry.key] = Some(node);
+        });
+    }
+
+    /// Returns the root of a balanced subtree with the given entries, if any.
+    fn build_subtree(
+        &mut self,
+        entries: &mut [(usize, GeographicPoint, CartesianPoint)],
+    ) -> Option<usize> {
+        if entries.is_empty() {
+            return None;
+        }
+
+        // splitting by the axis with the largest spread keeps nodes compact
+        let axis = (0..3)
+            .max_by(|&a, &b| {
+                let spread = |axis: usize| {
+                    let (min, max) = entries.iter().fold(
+                        (f64::INFINITY, f64::NEG_INFINITY),
+                        |(min, max), (_, _, direction)| {
+                            (min.min(direction[axis]), max.max(direction[axis]))
+                        },
+                    );
+
+                    max - min
+                };
+
+                spread(a).total_cmp(&spread(b))
+            })
+            .unwrap_or_default();
+
+        let median = entries.len() / 2;
+        entries.select_nth_unstable_by(median, |a, b| a.2[axis].total_cmp(&b.2[axis]));
+
+        let (key, point, direction) = entries[median];
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            key,
+            point,
+            direction,
+            axis,
+            left: None,
+            right: None,
+            removed: false,
+        });
+
+        let (left, right) = entries.split_at_mut(median);
+        self.nodes[node].left = self.build_subtree(left);
+        self.nodes[node].right = self.build_subtree(&mut right[1..]);
+        Some(node)
+    }
+
+    /// Visits every node of the tree that may be closer to the given target
+    /// than the bound returned by the given closure, which is called with the
+    /// chord between the target and each visited node that has not been
+    /// removed.
+    fn traverse(&self, target: &CartesianPoint, mut visit: impl FnMut(f64, usize) -> f64) {
+        let mut bound = f64::INFINITY;
+        let mut pending: Vec<(usize, f64)> = self.root.map(|root| (root, 0.)).into_iter().collect();
+
+        while let Some((node, distance)) = pending.pop() {
+            if distance > bound {
+                continue;
+            }
+
+            let current = &self.nodes[node];
+            if !current.removed {
+                bound = visit(target.distance(&current.direction), node);
+            }
+
+            let offset = target[current.axis] - current.direction[current.axis];
+            let (near, far) = if offset < 0. {
+                (current.left, current.right)
+            } else {
+                (current.right, current.left)
+            };
+
+            // the nearest side is pushed last so that it is visited first
+            pending.extend(far.map(|far| (far, offset.abs())));
+            pending.extend(near.map(|near| (near, 0.)));
+        }
+    }
+
+    /// Returns the neighbours of the given point for the given nodes, sorted by
+    /// their great-circle distance.
+    fn neighbours(
+        &self,
+        point: &GeographicPoint,
+        nodes: impl Iterator<Item = usize>,
+    ) -> Vec<Neighbour> {
+        let mut neighbours: Vec<Neighbour> = nodes
+            .map(|node| Neighbour {
+                key: self.nodes[node].key,
+                point: self.nodes[node].point,
+                distance: point.distance(&self.nodes[node].point),
+            })
+            .collect();
+
+        neighbours.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.key.cmp(&b.key)));
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a deterministic sequence of points spread all over the sphere.
+    fn points(count: usize) -> Vec<GeographicPoint> {
+        let golden_angle = PI * (3. - 5_f64.sqrt());
+        (0..count)
+            .map(|index| {
+                let z = 1. - 2. * (index as f64 + 0.5) / count as f64;
+                GeographicPoint::default()
+                    .with_longitude(golden_angle * index as f64)
+                    .with_latitude(z.asin())
+            })
+            .collect()
+    }
+
+    /// Returns the keys and distances of the given neighbours.
+    fn summary(neighbours: &[Neighbour]) -> Vec<(usize, f64)> {
+        neighbours
+            .iter()
+            .map(|neighbour| (neighbour.key(), neighbour.distance()))
+            .collect()
+    }
+
+    /// Returns the neighbours of the given point by brute force.
+    fn brute_force(
+        points: &[Option<GeographicPoint>],
+        point: &GeographicPoint,
+    ) -> Vec<(usize, f64)> {
+        let mut all: Vec<(usize, f64)> = points
+            .iter()
+            .enumerate()
+            .filter_map(|(key, other)| other.map(|other| (key, point.distance(&other))))
+            .collect();
+
+        all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        all
+    }
+
+    #[test]
+    fn queries_must_match_brute_force() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            count: usize,
+            radius: f64,
+        }
+
+        let mut index = PointIndex::new(points(500));
+        let mut expected: Vec<Option<GeographicPoint>> =
+            points(500).into_iter().map(Some).collect();
+
+        points(300).into_iter().for_each(|point| {
+            let point = point.with_longitude(point.longitude() + 0.01);
+            assert_eq!(index.insert(point), expected.len(), "key of inserted point");
+            expected.push(Some(point));
+        });
+
+        (0..expected.len()).step_by(3).for_each(|key| {
+            assert_eq!(index.remove(key), expected[key].take(), "removed point");
+        });
+
+        assert_eq!(index.remove(0), None, "removing twice");
+        assert_eq!(index.len(), expected.iter().flatten().count(), "length");
+
+        vec![
+            TestCase {
+                name: "origin of coordinates",
+                point: GeographicPoint::default(),
+                count: 10,
+                radius: 0.2,
+            },
+            TestCase {
+                name: "north pole",
+                point: GeographicPoint::default().with_latitude(PI / 2.),
+                count: 25,
+                radius: 0.3,
+            },
+            TestCase {
+                name: "antimeridian",
+                point: GeographicPoint::default()
+                    .with_longitude(PI)
+                    .with_latitude(-0.3),
+                count: 1,
+                radius: 0.1,
+            },
+            TestCase {
+                name: "more than available",
+                point: GeographicPoint::default().with_longitude(1.),
+                count: 2000,
+                radius: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let all = brute_force(&expected, &test_case.point);
+
+            let nearest = index.nearest(&test_case.point, test_case.count);
+            let want: Vec<(usize, f64)> = all.iter().copied().take(test_case.count).collect();
+            assert_eq!(summary(&nearest), want, "{}: nearest", test_case.name);
+
+            let within = index.within(&test_case.point, test_case.radius);
+            let want: Vec<(usize, f64)> = all
+                .iter()
+                .copied()
+                .filter(|(_, distance)| *distance <= test_case.radius)
+                .collect();
+
+            assert_eq!(summary(&within), want, "{}: within", test_case.name);
+        });
+    }
+
+    #[test]
+    fn empty_index_must_not_fail() {
+        let mut index = PointIndex::default();
+        assert!(index.is_empty(), "must be empty");
+        assert!(index.nearest(&GeographicPoint::default(), 3).is_empty());
+        assert!(index.within(&GeographicPoint::default(), PI).is_empty());
+
+        let key = index.insert(GeographicPoint::default());
+        assert_eq!(index.get(key), Some(GeographicPoint::default()), "get");
+        assert_eq!(index.nearest(&GeographicPoint::default(), 3).len(), 1);
+
+        index.remove(key);
+        assert!(index.is_empty(), "must be empty after removal");
+        assert_eq!(index.get(key), None, "get after removal");
+    }
+}
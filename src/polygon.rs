@@ -0,0 +1,569 @@
+use crate::{CartesianPoint, GeographicPoint, GreatCircleArc};
+use std::f64::consts::PI;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Angular tolerance (in radiants) when deciding whether a point lies on the
+/// boundary of a polygon.
+const EPSILON: f64 = 1e-12;
+
+/// Represents the direction in which the vertices of a ring are traversed, as
+/// seen from outside the sphere.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Represents a polygon on the surface of a sphere whose edges are arcs of
+/// great circles. All areas are in steradians and all distances are angular
+/// (in radiants).
+///
+/// Since any ring splits the sphere in two regions, the interior of the
+/// polygon is always the region to the left of its exterior ring, which is to
+/// say that small polygons must be given in counterclockwise order. Holes, on
+/// the contrary, are always taken as the smallest of the regions bounded by
+/// their ring, no matter their orientation.
+///
+/// Rings are implicitly closed, so there is no need to repeat the first vertex
+/// at the end.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SphericalPolygon {
+    exterior: Vec<GeographicPoint>,
+    holes: Vec<Vec<GeographicPoint>>,
+}
+
+#[wasm_bindgen]
+impl SphericalPolygon {
+    #[wasm_bindgen(constructor)]
+    pub fn new(exterior: Vec<GeographicPoint>) -> Self {
+        Self {
+            exterior: normalize_ring(exterior),
+            holes: Vec::new(),
+        }
+    }
+
+    /// Calls add_hole on self and returns it.
+    pub fn with_hole(mut self, hole: Vec<GeographicPoint>) -> Self {
+        self.add_hole(hole);
+        self
+    }
+
+    /// Adds the given ring as a hole of the polygon.
+    pub fn add_hole(&mut self, hole: Vec<GeographicPoint>) {
+        let mut hole = normalize_ring(hole);
+        if signed_area(&hole) > 0. {
+            // holes are kept in clockwise order, so the interior of the polygon
+            // is always to the left of any of its rings.
+            hole.reverse();
+        }
+
+        self.holes.push(hole);
+    }
+
+    /// Returns the vertices of the exterior ring of the polygon.
+    pub fn exterior(&self) -> Vec<GeographicPoint> {
+        self.exterior.clone()
+    }
+
+    /// Returns the orientation of the exterior ring of the polygon.
+    pub fn orientation(&self) -> Orientation {
+        if signed_area(&self.exterior) < 0. {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        }
+    }
+
+    /// Returns the area of the interior of the polygon, in steradians.
+    pub fn area(&self) -> f64 {
+        let exterior = signed_area(&self.exterior).rem_euclid(4. * PI);
+        let holes: f64 = self.holes.iter().map(|hole| signed_area(hole).abs()).sum();
+
+        (exterior - holes).max(0.)
+    }
+
+    /// Returns the area of the polygon in steradians, being positive if its
+    /// exterior ring is counterclockwise, and negative otherwise. In the latter
+    /// case, the absolute value is the area of the region outside the polygon.
+    pub fn signed_area(&self) -> f64 {
+        match self.orientation() {
+            Orientation::CounterClockwise => self.area(),
+            Orientation::Clockwise => self.area() - 4. * PI,
+        }
+    }
+
+    /// Returns the area of the polygon on a sphere of the given radius, in
+    /// squared units of the radius.
+    pub fn scaled_area(&self, radius: f64) -> f64 {
+        self.area() * radius.powi(2)
+    }
+
+    /// Returns the angular length (in radiants) of all the rings of the polygon.
+    pub fn perimeter(&self) -> f64 {
+        self.rings().map(perimeter).sum()
+    }
+
+    /// Returns the centroid of the surface of the polygon, projected onto the
+    /// sphere. If the polygon is empty or it is the whole sphere, the centroid
+    /// is undefined and the default point is returned instead.
+    pub fn centroid(&self) -> GeographicPoint {
+        let centroid =
+            self.rings()
+                .map(surface_integral)
+                .fold(CartesianPoint::default(), |sum, vector| {
+                    CartesianPoint::new(
+                        sum.x() + vector.x(),
+                        sum.y() + vector.y(),
+                        sum.z() + vector.z(),
+                    )
+                });
+
+        if centroid.magnitude() <= EPSILON {
+            return GeographicPoint::default();
+        }
+
+        GeographicPoint::from_cartesian(&centroid.normalize()).with_altitude(0.)
+    }
+
+    /// Returns true if, and only if, the given point lies inside the polygon or
+    /// on its boundary.
+    ///
+    /// The test finds the closest point of the boundary to the given one, and
+    /// checks on which side of the boundary the latter lies locally. Hence, it
+    /// does not depend on the polygon containing a pole or crossing the
+    /// antimeridian.
+    pub fn contains(&self, point: &GeographicPoint) -> bool {
+        let closest = self
+            .rings()
+            .filter(|ring| ring.len() >= 3)
+            .flat_map(|ring| (0..ring.len()).map(move |index| (ring, index)))
+            .map(|(ring, index)| {
+                let arc = GreatCircleArc::new(ring[index], ring[(index + 1) % ring.len()]);
+                (ring, index, arc, arc.distance(point))
+            })
+            .min_by(|a, b| a.3.total_cmp(&b.3));
+
+        let Some((ring, index, arc, distance)) = closest else {
+            return false;
+        };
+
+        if distance <= EPSILON {
+            return true;
+        }
+
+        let closest = arc.closest_point(point);
+        let vertex = if closest.distance(&arc.from()) <= EPSILON {
+            Some(index)
+        } else if closest.distance(&arc.to()) <= EPSILON {
+            Some((index + 1) % ring.len())
+        } else {
+            None
+        };
+
+        let Some(vertex) = vertex else {
+            // the closest point is in the middle of an edge
+            return arc.cross_track_distance(point) < 0.;
+        };
+
+        // the interior of the polygon is the wedge swept counterclockwise from
+        // the outgoing edge to the incoming one.
+        let current = ring[vertex];
+        let previous = ring[(vertex + ring.len() - 1) % ring.len()];
+        let next = ring[(vertex + 1) % ring.len()];
+
+        let outgoing = current.initial_bearing(&next);
+        let incoming = current.initial_bearing(&previous);
+        let target = current.initial_bearing(point);
+
+        (outgoing - target).rem_euclid(2. * PI) < (outgoing - incoming).rem_euclid(2. * PI)
+    }
+}
+
+impl SphericalPolygon {
+    /// Returns the vertices of the holes of the polygon, in clockwise order.
+    pub fn holes(&self) -> &[Vec<GeographicPoint>] {
+        &self.holes
+    }
+
+    /// Returns an iterator over all the rings of the polygon.
+    pub(crate) fn rings(&self) -> impl Iterator<Item = &[GeographicPoint]> {
+        std::iter::once(self.exterior.as_slice()).chain(self.holes.iter().map(Vec::as_slice))
+    }
+}
+
+/// Removes any consecutive duplicated vertex from the given ring, including the
+/// closing one.
+fn normalize_ring(mut ring: Vec<GeographicPoint>) -> Vec<GeographicPoint> {
+    ring.dedup_by(|a, b| a.distance(b) <= EPSILON);
+    while ring.len() > 1 && ring[0].distance(&ring[ring.len() - 1]) <= EPSILON {
+        ring.pop();
+    }
+
+    ring
+}
+
+/// Returns an iterator over all the edges of the given ring.
+pub(crate) fn edges(
+    ring: &[GeographicPoint],
+) -> impl Iterator<Item = (&GeographicPoint, &GeographicPoint)> {
+    ring.iter().zip(ring.iter().cycle().skip(1))
+}
+
+/// Returns the angular length of the given ring.
+fn perimeter(ring: &[GeographicPoint]) -> f64 {
+    if ring.len() < 2 {
+        return 0.;
+    }
+
+    edges(ring).map(|(from, to)| from.distance(to)).sum()
+}
+
+/// Returns the integral of the position vector over the region to the left of
+/// the given ring, whose direction is the one of the centroid of that region.
+fn surface_integral(ring: &[GeographicPoint]) -> CartesianPoint {
+    // see: https://doi.org/10.1080/13658810802034186
+    if ring.len() < 3 {
+        return CartesianPoint::default();
+    }
+
+    edges(ring)
+        .map(|(from, to)| {
+            let from = CartesianPoint::direction_of(from);
+            let to = CartesianPoint::direction_of(to);
+            let normal = from.cross(&to);
+            let angle = normal.magnitude().atan2(from.dot(&to)) / 2.;
+            let normal = normal.normalize();
+
+            CartesianPoint::new(normal.x() * angle, normal.y() * angle, normal.z() * angle)
+        })
+        .fold(CartesianPoint::default(), |sum, vector| {
+            CartesianPoint::new(
+                sum.x() + vector.x(),
+                sum.y() + vector.y(),
+                sum.z() + vector.z(),
+            )
+        })
+}
+
+/// Returns the area of the smallest region bounded by the given ring, being
+/// positive if the ring is counterclockwise, and negative otherwise.
+fn signed_area(ring: &[GeographicPoint]) -> f64 {
+    if ring.len() < 3 {
+        return 0.;
+    }
+
+    // The sum of the signed areas of a fan of triangles from any origin is the
+    // area of the region to the left of the ring, modulo 4π. Taking the
+    // centroid as origin avoids degenerate triangles as much as possible.
+    let centroid = surface_integral(ring);
+    let origin = if centroid.magnitude() > EPSILON {
+        centroid.normalize()
+    } else {
+        CartesianPoint::direction_of(&ring[0])
+    };
+
+    let area = edges(ring)
+        .map(|(a, b)| {
+            let a = CartesianPoint::direction_of(a);
+            let b = CartesianPoint::direction_of(b);
+
+            // see: https://doi.org/10.1109/TBME.1983.325207
+            2. * origin
+                .dot(&a.cross(&b))
+                .atan2(1. + origin.dot(&a) + a.dot(&b) + b.dot(&origin))
+        })
+        .sum::<f64>()
+        .rem_euclid(4. * PI);
+
+    if area > 2. * PI {
+        area - 4. * PI
+    } else {
+        area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    /// Returns the octant of the sphere between the given longitude and the one
+    /// at its east, from the equator to the north pole.
+    fn octant(longitude: f64) -> Vec<GeographicPoint> {
+        vec![
+            from_degrees(longitude, 0.),
+            from_degrees(longitude + 90., 0.),
+            from_degrees(0., 90.),
+        ]
+    }
+
+    #[test]
+    fn area_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            polygon: SphericalPolygon,
+            area: f64,
+            signed_area: f64,
+            perimeter: f64,
+            orientation: Orientation,
+        }
+
+        vec![
+            TestCase {
+                name: "counterclockwise octant",
+                polygon: SphericalPolygon::new(octant(0.)),
+                area: FRAC_PI_2,
+                signed_area: FRAC_PI_2,
+                perimeter: 3. * FRAC_PI_2,
+                orientation: Orientation::CounterClockwise,
+            },
+            TestCase {
+                name: "clockwise octant must be its complement",
+                polygon: SphericalPolygon::new(octant(0.).into_iter().rev().collect()),
+                area: 4. * PI - FRAC_PI_2,
+                signed_area: -FRAC_PI_2,
+                perimeter: 3. * FRAC_PI_2,
+                orientation: Orientation::Clockwise,
+            },
+            TestCase {
+                name: "octant crossing the antimeridian",
+                polygon: SphericalPolygon::new(octant(135.)),
+                area: FRAC_PI_2,
+                signed_area: FRAC_PI_2,
+                perimeter: 3. * FRAC_PI_2,
+                orientation: Orientation::CounterClockwise,
+            },
+            TestCase {
+                name: "north hemisphere containing the pole",
+                polygon: SphericalPolygon::new(vec![
+                    from_degrees(0., 0.),
+                    from_degrees(120., 0.),
+                    from_degrees(-120., 0.),
+                    from_degrees(0., 0.),
+                ]),
+                area: 2. * PI,
+                signed_area: 2. * PI,
+                perimeter: 2. * PI,
+                orientation: Orientation::CounterClockwise,
+            },
+            TestCase {
+                name: "north hemisphere with an octant as hole",
+                polygon: SphericalPolygon::new(vec![
+                    from_degrees(0., 0.),
+                    from_degrees(90., 0.),
+                    from_degrees(180., 0.),
+                    from_degrees(-90., 0.),
+                ])
+                .with_hole(octant(0.)),
+                area: 2. * PI - FRAC_PI_2,
+                signed_area: 2. * PI - FRAC_PI_2,
+                perimeter: 2. * PI + 3. * FRAC_PI_2,
+                orientation: Orientation::CounterClockwise,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.polygon.area(),
+                    test_case.area,
+                    epsilon = 1e-12
+                ),
+                "{}: area {} ±ε = {}",
+                test_case.name,
+                test_case.polygon.area(),
+                test_case.area
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.polygon.signed_area(),
+                    test_case.signed_area,
+                    epsilon = 1e-12
+                ),
+                "{}: signed area {} ±ε = {}",
+                test_case.name,
+                test_case.polygon.signed_area(),
+                test_case.signed_area
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.polygon.perimeter(),
+                    test_case.perimeter,
+                    epsilon = 1e-12
+                ),
+                "{}: perimeter {} ±ε = {}",
+                test_case.name,
+                test_case.polygon.perimeter(),
+                test_case.perimeter
+            );
+
+            assert_eq!(
+                test_case.polygon.orientation(),
+                test_case.orientation,
+                "{}: orientation",
+                test_case.name
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.polygon.scaled_area(2.),
+                    4. * test_case.area,
+                    epsilon = 1e-12
+                ),
+                "{}: scaled area {} ±ε = {}",
+                test_case.name,
+                test_case.polygon.scaled_area(2.),
+                4. * test_case.area
+            );
+        });
+    }
+
+    #[test]
+    fn centroid_must_not_fail() {
+        let polygon = SphericalPolygon::new(octant(0.));
+        let want = CartesianPoint::new(1., 1., 1.).normalize();
+
+        let centroid = CartesianPoint::direction_of(&polygon.centroid());
+        assert!(
+            centroid.distance(&want) < 1e-12,
+            "centroid of the octant {:?} ±ε = {:?}",
+            centroid,
+            want
+        );
+
+        let polygon = SphericalPolygon::new(vec![
+            from_degrees(0., -10.),
+            from_degrees(120., -10.),
+            from_degrees(-120., -10.),
+        ]);
+
+        assert!(
+            approx_eq!(
+                f64,
+                polygon.centroid().latitude(),
+                FRAC_PI_2,
+                epsilon = 1e-12
+            ),
+            "centroid of a polar cap must be the pole: {:?}",
+            polygon.centroid()
+        );
+    }
+
+    #[test]
+    fn contains_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            polygon: SphericalPolygon,
+            point: GeographicPoint,
+            contains: bool,
+        }
+
+        let antimeridian = SphericalPolygon::new(vec![
+            from_degrees(170., -10.),
+            from_degrees(-170., -10.),
+            from_degrees(-170., 10.),
+            from_degrees(170., 10.),
+        ]);
+
+        let polar = SphericalPolygon::new(vec![
+            from_degrees(0., 60.),
+            from_degrees(90., 60.),
+            from_degrees(180., 60.),
+            from_degrees(-90., 60.),
+        ])
+        .with_hole(vec![
+            from_degrees(0., 80.),
+            from_degrees(90., 80.),
+            from_degrees(180., 80.),
+            from_degrees(-90., 80.),
+        ]);
+
+        vec![
+            TestCase {
+                name: "point inside the octant",
+                polygon: SphericalPolygon::new(octant(0.)),
+                point: from_degrees(45., 45.),
+                contains: true,
+            },
+            TestCase {
+                name: "point outside the octant",
+                polygon: SphericalPolygon::new(octant(0.)),
+                point: from_degrees(-45., 45.),
+                contains: false,
+            },
+            TestCase {
+                name: "point outside the octant close to a vertex",
+                polygon: SphericalPolygon::new(octant(0.)),
+                point: from_degrees(-1., -1.),
+                contains: false,
+            },
+            TestCase {
+                name: "point inside the complement of the octant",
+                polygon: SphericalPolygon::new(octant(0.).into_iter().rev().collect()),
+                point: from_degrees(-45., 45.),
+                contains: true,
+            },
+            TestCase {
+                name: "point on the boundary",
+                polygon: SphericalPolygon::new(octant(0.)),
+                point: from_degrees(45., 0.),
+                contains: true,
+            },
+            TestCase {
+                name: "point inside a polygon crossing the antimeridian",
+                polygon: antimeridian.clone(),
+                point: from_degrees(180., 0.),
+                contains: true,
+            },
+            TestCase {
+                name: "point outside a polygon crossing the antimeridian",
+                polygon: antimeridian,
+                point: from_degrees(0., 0.),
+                contains: false,
+            },
+            TestCase {
+                name: "point inside a polar ring",
+                polygon: polar.clone(),
+                point: from_degrees(45., 75.),
+                contains: true,
+            },
+            TestCase {
+                name: "pole inside the hole of a polar ring",
+                polygon: polar.clone(),
+                point: from_degrees(0., 90.),
+                contains: false,
+            },
+            TestCase {
+                name: "point below a polar ring",
+                polygon: polar,
+                point: from_degrees(45., 45.),
+                contains: false,
+            },
+            TestCase {
+                name: "empty polygon",
+                polygon: SphericalPolygon::default(),
+                point: from_degrees(0., 0.),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.polygon.contains(&test_case.point),
+                test_case.contains,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+}
@@ -0,0 +1,923 @@
+use crate::{geographic::longitude_offset, GeographicPoint};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Represents a point on a plane, as given by a [`Projection`].
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PlanarPoint {
+    x: f64,
+    y: f64,
+}
+
+#[wasm_bindgen]
+impl PlanarPoint {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the horizontal coordinate of the point, positive eastwards.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the vertical coordinate of the point, positive northwards.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// A [map projection](https://en.wikipedia.org/wiki/Map_projection) of the
+/// sphere onto a plane.
+///
+/// All projections are spherical, and their planar coordinates are in the same
+/// units as the radius of the sphere, which is 1 by default.
+pub trait Projection {
+    /// Returns the projection of the given point, if defined. The altitude of
+    /// the point is ignored.
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint>;
+
+    /// Returns the point whose projection is the given one, if any. The
+    /// altitude of the resulting point is always zero.
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint>;
+}
+
+/// Exposes the [`Projection`] methods of the given types through wasm.
+macro_rules! wasm_projection {
+    ($($projection:ty),*) => {$(
+        #[wasm_bindgen]
+        impl $projection {
+            /// Returns the projection of the given point, if defined.
+            pub fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+                Projection::forward(self, point)
+            }
+
+            /// Returns the point whose projection is the given one, if any.
+            pub fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+                Projection::inverse(self, point)
+            }
+        }
+    )*};
+}
+
+wasm_projection!(
+    Equirectangular,
+    Mercator,
+    TransverseMercator,
+    LambertConformalConic,
+    AlbersEqualArea,
+    AzimuthalEquidistant,
+    Orthographic,
+    Stereographic,
+    Gnomonic
+);
+
+/// The [equirectangular](https://en.wikipedia.org/wiki/Equirectangular_projection)
+/// projection, which maps meridians and parallels into equally spaced straight
+/// lines.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equirectangular {
+    central_meridian: f64,
+    standard_parallel: f64,
+    radius: f64,
+}
+
+impl Default for Equirectangular {
+    fn default() -> Self {
+        Self {
+            central_meridian: 0.,
+            standard_parallel: 0.,
+            radius: 1.,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Equirectangular {
+    /// Sets the longitude (in radiants) at the center of the map.
+    pub fn with_central_meridian(mut self, value: f64) -> Self {
+        self.central_meridian = value;
+        self
+    }
+
+    /// Sets the latitude (in radiants) at which the map has no distortion.
+    pub fn with_standard_parallel(mut self, value: f64) -> Self {
+        self.standard_parallel = value;
+        self
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+}
+
+impl Projection for Equirectangular {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        Some(PlanarPoint {
+            x: self.radius
+                * longitude_offset(point.longitude(), self.central_meridian)
+                * self.standard_parallel.cos(),
+            y: self.radius * point.latitude(),
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        let longitude = point.x / (self.radius * self.standard_parallel.cos());
+        geographic(
+            longitude + self.central_meridian,
+            point.y / self.radius,
+            longitude,
+        )
+    }
+}
+
+/// The [Mercator](https://en.wikipedia.org/wiki/Mercator_projection)
+/// projection, which is conformal and maps rhumb lines into straight lines. It
+/// is not defined at the poles.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mercator {
+    central_meridian: f64,
+    radius: f64,
+}
+
+impl Default for Mercator {
+    fn default() -> Self {
+        Self {
+            central_meridian: 0.,
+            radius: 1.,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Mercator {
+    /// Sets the longitude (in radiants) at the center of the map.
+    pub fn with_central_meridian(mut self, value: f64) -> Self {
+        self.central_meridian = value;
+        self
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+}
+
+impl Projection for Mercator {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        if point.latitude().abs() >= FRAC_PI_2 {
+            return None;
+        }
+
+        Some(PlanarPoint {
+            x: self.radius * longitude_offset(point.longitude(), self.central_meridian),
+            y: self.radius * (FRAC_PI_4 + point.latitude() / 2.).tan().ln(),
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        let longitude = point.x / self.radius;
+        geographic(
+            longitude + self.central_meridian,
+            2. * (point.y / self.radius).exp().atan() - FRAC_PI_2,
+            longitude,
+        )
+    }
+}
+
+/// The [transverse Mercator](https://en.wikipedia.org/wiki/Transverse_Mercator_projection)
+/// projection, which is the Mercator projection rotated so that the central
+/// meridian plays the role of the equator. It is not defined at the points 90°
+/// away from the central meridian on the equator.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransverseMercator {
+    central_meridian: f64,
+    latitude_of_origin: f64,
+    scale_factor: f64,
+    radius: f64,
+}
+
+impl Default for TransverseMercator {
+    fn default() -> Self {
+        Self {
+            central_meridian: 0.,
+            latitude_of_origin: 0.,
+            scale_factor: 1.,
+            radius: 1.,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl TransverseMercator {
+    /// Sets the longitude (in radiants) at the center of the map.
+    pub fn with_central_meridian(mut self, value: f64) -> Self {
+        self.central_meridian = value;
+        self
+    }
+
+    /// Sets the latitude (in radiants) projected into the horizontal axis.
+    pub fn with_latitude_of_origin(mut self, value: f64) -> Self {
+        self.latitude_of_origin = value;
+        self
+    }
+
+    /// Sets the scale of the map along the central meridian.
+    pub fn with_scale_factor(mut self, value: f64) -> Self {
+        self.scale_factor = value;
+        self
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+}
+
+impl Projection for TransverseMercator {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        // see: Snyder, J. P. (1987). Map projections: A working manual, p. 58
+        let longitude = longitude_offset(point.longitude(), self.central_meridian);
+        let b = point.latitude().cos() * longitude.sin();
+        if b.abs() >= 1. {
+            return None;
+        }
+
+        let scale = self.scale_factor * self.radius;
+        Some(PlanarPoint {
+            x: scale * b.atanh(),
+            y: scale * (point.latitude().tan().atan2(longitude.cos()) - self.latitude_of_origin),
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        let scale = self.scale_factor * self.radius;
+        let x = point.x / scale;
+        let d = point.y / scale + self.latitude_of_origin;
+
+        let longitude = x.sinh().atan2(d.cos());
+        geographic(
+            longitude + self.central_meridian,
+            (d.sin() / x.cosh()).asin(),
+            longitude,
+        )
+    }
+}
+
+/// The [Lambert conformal conic](https://en.wikipedia.org/wiki/Lambert_conformal_conic_projection)
+/// projection, which maps parallels into concentric arcs and meridians into
+/// straight lines converging at one of the poles, where the projection is not
+/// defined.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LambertConformalConic {
+    central_meridian: f64,
+    latitude_of_origin: f64,
+    radius: f64,
+    cone: f64,
+    factor: f64,
+}
+
+#[wasm_bindgen]
+impl LambertConformalConic {
+    /// Returns the projection with the given standard parallels (in
+    /// radiants), at which the map has no distortion, if they are not opposite
+    /// to each other nor any of the poles.
+    pub fn new(first_parallel: f64, second_parallel: f64) -> Option<LambertConformalConic> {
+        // see: Snyder, J. P. (1987). Map projections: A working manual, p. 107
+        let stretch = |latitude: f64| (FRAC_PI_4 + latitude / 2.).tan();
+        let cone = if (first_parallel - second_parallel).abs() < f64::EPSILON {
+            first_parallel.sin()
+        } else {
+            (first_parallel.cos() / second_parallel.cos()).ln()
+                / (stretch(second_parallel) / stretch(first_parallel)).ln()
+        };
+
+        let factor = first_parallel.cos() * stretch(first_parallel).powf(cone) / cone;
+        (cone.abs() > f64::EPSILON && factor.is_finite() && factor != 0.).then_some(Self {
+            central_meridian: 0.,
+            latitude_of_origin: 0.,
+            radius: 1.,
+            cone,
+            factor,
+        })
+    }
+
+    /// Sets the longitude (in radiants) at the center of the map.
+    pub fn with_central_meridian(mut self, value: f64) -> Self {
+        self.central_meridian = value;
+        self
+    }
+
+    /// Sets the latitude (in radiants) projected into the horizontal axis.
+    pub fn with_latitude_of_origin(mut self, value: f64) -> Self {
+        self.latitude_of_origin = value;
+        self
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+}
+
+impl LambertConformalConic {
+    /// Returns the distance from the apex of the cone to the projection of the
+    /// given parallel.
+    fn rho(&self, latitude: f64) -> f64 {
+        self.radius * self.factor / (FRAC_PI_4 + latitude / 2.).tan().powf(self.cone)
+    }
+}
+
+impl Projection for LambertConformalConic {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        let rho = self.rho(point.latitude());
+        let origin = self.rho(self.latitude_of_origin);
+        if !rho.is_finite() || !origin.is_finite() {
+            return None;
+        }
+
+        let theta = self.cone * longitude_offset(point.longitude(), self.central_meridian);
+        Some(PlanarPoint {
+            x: rho * theta.sin(),
+            y: origin - rho * theta.cos(),
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        let (rho, theta) = conic_polar(self.cone, self.rho(self.latitude_of_origin), point);
+        let latitude = if rho == 0. {
+            self.cone.signum() * FRAC_PI_2
+        } else {
+            2. * (self.radius * self.factor / rho)
+                .powf(1. / self.cone)
+                .atan()
+                - FRAC_PI_2
+        };
+
+        let longitude = theta / self.cone;
+        geographic(longitude + self.central_meridian, latitude, longitude)
+    }
+}
+
+/// The [Albers equal-area conic](https://en.wikipedia.org/wiki/Albers_projection)
+/// projection, which preserves areas and maps parallels into concentric arcs
+/// and meridians into straight lines.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlbersEqualArea {
+    central_meridian: f64,
+    latitude_of_origin: f64,
+    radius: f64,
+    cone: f64,
+    constant: f64,
+}
+
+#[wasm_bindgen]
+impl AlbersEqualArea {
+    /// Returns the projection with the given standard parallels (in
+    /// radiants), at which the map has no distortion, if they are not opposite
+    /// to each other.
+    pub fn new(first_parallel: f64, second_parallel: f64) -> Option<AlbersEqualArea> {
+        // see: Snyder, J. P. (1987). Map projections: A working manual, p. 100
+        let cone = (first_parallel.sin() + second_parallel.sin()) / 2.;
+        let constant = first_parallel.cos().powi(2) + 2. * cone * first_parallel.sin();
+
+        (cone.abs() > f64::EPSILON).then_some(Self {
+            central_meridian: 0.,
+            latitude_of_origin: 0.,
+            radius: 1.,
+            cone,
+            constant,
+        })
+    }
+
+    /// Sets the longitude (in radiants) at the center of the map.
+    pub fn with_central_meridian(mut self, value: f64) -> Self {
+        self.central_meridian = value;
+        self
+    }
+
+    /// Sets the latitude (in radiants) projected into the horizontal axis.
+    pub fn with_latitude_of_origin(mut self, value: f64) -> Self {
+        self.latitude_of_origin = value;
+        self
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+}
+
+impl AlbersEqualArea {
+    /// Returns the distance from the apex of the cone to the projection of the
+    /// given parallel.
+    fn rho(&self, latitude: f64) -> f64 {
+        self.radius
+            * (self.constant - 2. * self.cone * latitude.sin())
+                .max(0.)
+                .sqrt()
+            / self.cone
+    }
+}
+
+impl Projection for AlbersEqualArea {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        let rho = self.rho(point.latitude());
+        let theta = self.cone * longitude_offset(point.longitude(), self.central_meridian);
+
+        Some(PlanarPoint {
+            x: rho * theta.sin(),
+            y: self.rho(self.latitude_of_origin) - rho * theta.cos(),
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        let (rho, theta) = conic_polar(self.cone, self.rho(self.latitude_of_origin), point);
+        let sin = (self.constant - (rho * self.cone / self.radius).powi(2)) / (2. * self.cone);
+        if sin.abs() > 1. + 1e-12 {
+            return None;
+        }
+
+        let longitude = theta / self.cone;
+        geographic(
+            longitude + self.central_meridian,
+            sin.clamp(-1., 1.).asin(),
+            longitude,
+        )
+    }
+}
+
+/// The [azimuthal equidistant](https://en.wikipedia.org/wiki/Azimuthal_equidistant_projection)
+/// projection, which preserves the distances and directions from its center.
+/// It is not defined at the antipode of the center.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AzimuthalEquidistant(Azimuthal);
+
+#[wasm_bindgen]
+impl AzimuthalEquidistant {
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: GeographicPoint) -> Self {
+        Self(Azimuthal::new(center))
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(self, value: f64) -> Self {
+        Self(self.0.with_radius(value))
+    }
+}
+
+impl Projection for AzimuthalEquidistant {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        self.0.forward(point, |sin, cos| {
+            if sin == 0. {
+                // the direction towards the antipode is undefined
+                (cos > 0.).then_some(1.)
+            } else {
+                Some(sin.atan2(cos) / sin)
+            }
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        self.0.inverse(point, |rho| (rho <= PI).then_some(rho))
+    }
+}
+
+/// The [orthographic](https://en.wikipedia.org/wiki/Orthographic_map_projection)
+/// projection, which depicts the hemisphere centered at a point as seen from
+/// an infinite distance. It is not defined beyond that hemisphere.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Orthographic(Azimuthal);
+
+#[wasm_bindgen]
+impl Orthographic {
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: GeographicPoint) -> Self {
+        Self(Azimuthal::new(center))
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(self, value: f64) -> Self {
+        Self(self.0.with_radius(value))
+    }
+}
+
+impl Projection for Orthographic {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        self.0.forward(point, |_, cos| (cos >= 0.).then_some(1.))
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        self.0.inverse(point, |rho| (rho <= 1.).then(|| rho.asin()))
+    }
+}
+
+/// The [stereographic](https://en.wikipedia.org/wiki/Stereographic_map_projection)
+/// projection, which is conformal and maps circles on the sphere into circles
+/// on the plane. It is not defined at the antipode of the center.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stereographic(Azimuthal);
+
+#[wasm_bindgen]
+impl Stereographic {
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: GeographicPoint) -> Self {
+        Self(Azimuthal::new(center))
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(self, value: f64) -> Self {
+        Self(self.0.with_radius(value))
+    }
+}
+
+impl Projection for Stereographic {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        self.0.forward(point, |sin, cos| {
+            if cos >= 0. {
+                Some(2. / (1. + cos))
+            } else {
+                // being 1 + cos = sin² / (1 - cos) avoids the cancellation close
+                // to the antipode of the center
+                (sin > 0.).then(|| 2. * (1. - cos) / sin.powi(2))
+            }
+        })
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        self.0.inverse(point, |rho| Some(2. * (rho / 2.).atan()))
+    }
+}
+
+/// The [gnomonic](https://en.wikipedia.org/wiki/Gnomonic_projection)
+/// projection, which maps great circles into straight lines. It is not defined
+/// beyond the hemisphere centered at its center, including its boundary.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Gnomonic(Azimuthal);
+
+#[wasm_bindgen]
+impl Gnomonic {
+    #[wasm_bindgen(constructor)]
+    pub fn new(center: GeographicPoint) -> Self {
+        Self(Azimuthal::new(center))
+    }
+
+    /// Sets the radius of the sphere.
+    pub fn with_radius(self, value: f64) -> Self {
+        Self(self.0.with_radius(value))
+    }
+}
+
+impl Projection for Gnomonic {
+    fn forward(&self, point: &GeographicPoint) -> Option<PlanarPoint> {
+        self.0
+            .forward(point, |_, cos| (cos > f64::EPSILON).then(|| 1. / cos))
+    }
+
+    fn inverse(&self, point: &PlanarPoint) -> Option<GeographicPoint> {
+        self.0.inverse(point, |rho| Some(rho.atan()))
+    }
+}
+
+/// The parameters shared by all azimuthal projections, which only differ in
+/// how the distance from the center is scaled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Azimuthal {
+    center: GeographicPoint,
+    radius: f64,
+}
+
+impl Default for Azimuthal {
+    fn default() -> Self {
+        Self::new(GeographicPoint::default())
+    }
+}
+
+impl Azimuthal {
+    fn new(center: GeographicPoint) -> Self {
+        Self { center, radius: 1. }
+    }
+
+    fn with_radius(mut self, value: f64) -> Self {
+        self.radius = value;
+        self
+    }
+
+    /// Returns the projection of the given point, being scale the function
+    /// that, given the sine and cosine of the angular distance from the center,
+    /// returns the factor to scale the orthographic projection by, if defined.
+    fn forward(
+        &self,
+        point: &GeographicPoint,
+        scale: impl FnOnce(f64, f64) -> Option<f64>,
+    ) -> Option<PlanarPoint> {
+        // see: Snyder, J. P. (1987). Map projections: A working manual, p. 145
+        let longitude = point.longitude() - self.center.longitude();
+        let (sin, cos) = self.center.latitude().sin_cos();
+        let x = point.latitude().cos() * longitude.sin();
+        let y = cos * point.latitude().sin() - sin * point.latitude().cos() * longitude.cos();
+
+        // the sine from the orthographic coordinates keeps the precision close
+        // to the antipode of the center, where the cosine is useless.
+        let cos_distance =
+            sin * point.latitude().sin() + cos * point.latitude().cos() * longitude.cos();
+
+        let scale = self.radius * scale(x.hypot(y), cos_distance)?;
+        Some(PlanarPoint {
+            x: scale * x,
+            y: scale * y,
+        })
+    }
+
+    /// Returns the point whose projection is the given one, being distance the
+    /// function that, given the distance from the center on a unit sphere,
+    /// returns the angular distance from the center, if any.
+    fn inverse(
+        &self,
+        point: &PlanarPoint,
+        distance: impl FnOnce(f64) -> Option<f64>,
+    ) -> Option<GeographicPoint> {
+        let rho = point.x.hypot(point.y) / self.radius;
+        let distance = distance(rho)?;
+        if rho == 0. {
+            return Some(self.center.with_altitude(0.));
+        }
+
+        let (sin, cos) = self.center.latitude().sin_cos();
+        let (sin_distance, cos_distance) = distance.sin_cos();
+        let (x, y) = (point.x / self.radius, point.y / self.radius);
+
+        let latitude = (cos_distance * sin + y * sin_distance * cos / rho)
+            .clamp(-1., 1.)
+            .asin();
+
+        let longitude = (x * sin_distance).atan2(rho * cos * cos_distance - y * sin * sin_distance);
+        Some(
+            GeographicPoint::default()
+                .with_longitude(self.center.longitude() + longitude)
+                .with_latitude(latitude),
+        )
+    }
+}
+
+/// Returns the polar coordinates of the given point relative to the apex of
+/// a conic projection, being origin the distance from the apex to the origin
+/// of the map.
+fn conic_polar(cone: f64, origin: f64, point: &PlanarPoint) -> (f64, f64) {
+    let sign = cone.signum();
+    let rho = sign * point.x.hypot(origin - point.y);
+    let theta = (sign * point.x).atan2(sign * (origin - point.y));
+    (rho, theta)
+}
+
+/// Returns the point with the given longitude and latitude, if the latter is
+/// in range and the given offset from the central meridian is not beyond the
+/// antimeridian.
+fn geographic(longitude: f64, latitude: f64, offset: f64) -> Option<GeographicPoint> {
+    const TOLERANCE: f64 = 1e-12;
+    (latitude.abs() <= FRAC_PI_2 && offset.abs() <= PI + TOLERANCE).then(|| {
+        GeographicPoint::default()
+            .with_longitude(longitude)
+            .with_latitude(latitude)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    /// Returns one instance of every projection, with non trivial parameters.
+    fn projections() -> Vec<(&'static str, Box<dyn Projection>)> {
+        let center = from_degrees(-30., 40.);
+        vec![
+            (
+                "equirectangular",
+                Box::new(
+                    Equirectangular::default()
+                        .with_central_meridian(0.5)
+                        .with_standard_parallel(0.6)
+                        .with_radius(2.),
+                ),
+            ),
+            (
+                "mercator",
+                Box::new(Mercator::default().with_central_meridian(-1.)),
+            ),
+            (
+                "transverse mercator",
+                Box::new(
+                    TransverseMercator::default()
+                        .with_central_meridian(0.3)
+                        .with_latitude_of_origin(0.2)
+                        .with_scale_factor(0.9996),
+                ),
+            ),
+            (
+                "lambert conformal conic",
+                Box::new(
+                    LambertConformalConic::new(0.5, 0.8)
+                        .unwrap()
+                        .with_latitude_of_origin(0.4),
+                ),
+            ),
+            (
+                "southern lambert conformal conic",
+                Box::new(LambertConformalConic::new(-0.8, -0.8).unwrap()),
+            ),
+            (
+                "albers equal area",
+                Box::new(
+                    AlbersEqualArea::new(0.5, 0.8)
+                        .unwrap()
+                        .with_central_meridian(2.),
+                ),
+            ),
+            (
+                "azimuthal equidistant",
+                Box::new(AzimuthalEquidistant::new(center)),
+            ),
+            ("orthographic", Box::new(Orthographic::new(center))),
+            ("stereographic", Box::new(Stereographic::new(center))),
+            ("gnomonic", Box::new(Gnomonic::new(center).with_radius(3.))),
+        ]
+    }
+
+    #[test]
+    fn forward_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            projection: Box<dyn Projection>,
+            point: GeographicPoint,
+            output: Option<PlanarPoint>,
+        }
+
+        vec![
+            TestCase {
+                name: "mercator",
+                projection: Box::new(Mercator::default()),
+                point: from_degrees(90., 45.),
+                output: Some(PlanarPoint::new(FRAC_PI_2, 0.881373587019543)),
+            },
+            TestCase {
+                name: "mercator at the pole",
+                projection: Box::new(Mercator::default()),
+                point: from_degrees(0., 90.),
+                output: None,
+            },
+            TestCase {
+                name: "transverse mercator",
+                projection: Box::new(
+                    TransverseMercator::default().with_central_meridian((-75_f64).to_radians()),
+                ),
+                point: from_degrees(-73.5, 40.5),
+                output: Some(PlanarPoint::new(0.0199077, 0.7070276)),
+            },
+            TestCase {
+                name: "lambert conformal conic",
+                projection: Box::new(
+                    LambertConformalConic::new(33_f64.to_radians(), 45_f64.to_radians())
+                        .unwrap()
+                        .with_central_meridian((-96_f64).to_radians())
+                        .with_latitude_of_origin(23_f64.to_radians()),
+                ),
+                point: from_degrees(-75., 35.),
+                output: Some(PlanarPoint::new(0.2966785, 0.2462112)),
+            },
+            TestCase {
+                name: "albers equal area",
+                projection: Box::new(
+                    AlbersEqualArea::new(29.5_f64.to_radians(), 45.5_f64.to_radians())
+                        .unwrap()
+                        .with_central_meridian((-96_f64).to_radians())
+                        .with_latitude_of_origin(23_f64.to_radians()),
+                ),
+                point: from_degrees(-75., 35.),
+                output: Some(PlanarPoint::new(0.2952720, 0.2416774)),
+            },
+            TestCase {
+                name: "azimuthal equidistant",
+                projection: Box::new(AzimuthalEquidistant::new(from_degrees(0., 90.))),
+                point: from_degrees(90., 0.),
+                output: Some(PlanarPoint::new(FRAC_PI_2, 0.)),
+            },
+            TestCase {
+                name: "orthographic",
+                projection: Box::new(Orthographic::new(GeographicPoint::default())),
+                point: from_degrees(0., 30.),
+                output: Some(PlanarPoint::new(0., 0.5)),
+            },
+            TestCase {
+                name: "orthographic beyond the horizon",
+                projection: Box::new(Orthographic::new(GeographicPoint::default())),
+                point: from_degrees(100., 0.),
+                output: None,
+            },
+            TestCase {
+                name: "stereographic",
+                projection: Box::new(Stereographic::new(GeographicPoint::default())),
+                point: from_degrees(-90., 0.),
+                output: Some(PlanarPoint::new(-2., 0.)),
+            },
+            TestCase {
+                name: "gnomonic",
+                projection: Box::new(Gnomonic::new(GeographicPoint::default())),
+                point: from_degrees(45., 0.),
+                output: Some(PlanarPoint::new(1., 0.)),
+            },
+            TestCase {
+                name: "gnomonic at the horizon",
+                projection: Box::new(Gnomonic::new(GeographicPoint::default())),
+                point: from_degrees(0., 90.),
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = test_case.projection.forward(&test_case.point);
+            match (output, test_case.output) {
+                (Some(got), Some(want)) => assert!(
+                    approx_eq!(f64, got.x(), want.x(), epsilon = 1e-7)
+                        && approx_eq!(f64, got.y(), want.y(), epsilon = 1e-7),
+                    "{}: got {:?}, want {:?}",
+                    test_case.name,
+                    got,
+                    want
+                ),
+                (got, want) => assert_eq!(got, want, "{}", test_case.name),
+            }
+        });
+    }
+
+    #[test]
+    fn inverse_must_revert_forward() {
+        let points: Vec<GeographicPoint> = (-8..=8)
+            .flat_map(|latitude| {
+                (-17..=18).map(move |longitude| {
+                    from_degrees(longitude as f64 * 10., latitude as f64 * 10.)
+                })
+            })
+            .collect();
+
+        projections().into_iter().for_each(|(name, projection)| {
+            points.iter().for_each(|point| {
+                let Some(planar) = projection.forward(point) else {
+                    return;
+                };
+
+                let reverted = projection.inverse(&planar);
+                assert!(
+                    reverted.is_some_and(|reverted| reverted.distance(point) < 1e-9),
+                    "{name}: {point:?} was reverted into {reverted:?}"
+                );
+            })
+        });
+    }
+
+    #[test]
+    fn inverse_out_of_range_must_fail() {
+        vec![
+            (
+                "equirectangular",
+                Box::new(Equirectangular::default()) as Box<dyn Projection>,
+                PlanarPoint::new(0., 2.),
+            ),
+            (
+                "mercator",
+                Box::new(Mercator::default()),
+                PlanarPoint::new(4., 0.),
+            ),
+            (
+                "orthographic",
+                Box::new(Orthographic::new(GeographicPoint::default())),
+                PlanarPoint::new(1., 1.),
+            ),
+            (
+                "azimuthal equidistant",
+                Box::new(AzimuthalEquidistant::new(GeographicPoint::default())),
+                PlanarPoint::new(0., 4.),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(name, projection, point)| {
+            assert_eq!(projection.inverse(&point), None, "{name}");
+        });
+    }
+}
@@ -0,0 +1,937 @@
+use crate::{
+    CartesianPoint, GeoBoundingBox, GeographicPoint, GreatCircleArc, SphericalCap, SphericalPolygon,
+};
+use std::f64::consts::PI;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The maximum amount of entries of any node of the tree.
+const MAX_ENTRIES: usize = 8;
+/// Tolerance when comparing bounds, so that rounding errors do not leave any
+/// region out of a query.
+const EPSILON: f64 = 1e-12;
+
+/// A region stored in a [`RegionIndex`].
+#[derive(Debug, Clone, PartialEq)]
+enum Region {
+    Box(GeoBoundingBox),
+    Cap(SphericalCap),
+    Polygon(SphericalPolygon),
+}
+
+impl Region {
+    /// Returns true if, and only if, the given point lies inside the region or
+    /// on its boundary.
+    fn contains(&self, point: &GeographicPoint) -> bool {
+        match self {
+            Region::Box(bounding_box) => bounding_box.contains(point),
+            Region::Cap(cap) => cap.contains(point),
+            Region::Polygon(polygon) => polygon.contains(point),
+        }
+    }
+
+    /// Returns true if, and only if, self and the given region share any point.
+    fn intersects(&self, other: &Region) -> bool {
+        match (self, other) {
+            (Region::Box(a), Region::Box(b)) => a.intersects(b),
+            (Region::Cap(a), Region::Cap(b)) => a.intersects(b),
+            (Region::Box(bounding_box), Region::Cap(cap))
+            | (Region::Cap(cap), Region::Box(bounding_box)) => {
+                bounding_box.distance(&cap.center()) <= cap.radius()
+            }
+            (Region::Cap(cap), Region::Polygon(polygon))
+            | (Region::Polygon(polygon), Region::Cap(cap)) => {
+                polygon.contains(&cap.center())
+                    || edges(polygon).any(|edge| edge.distance(&cap.center()) <= cap.radius())
+            }
+            (Region::Box(bounding_box), Region::Polygon(polygon))
+            | (Region::Polygon(polygon), Region::Box(bounding_box)) => {
+                polygon
+                    .rings()
+                    .flatten()
+                    .any(|vertex| bounding_box.contains(vertex))
+                    || corners(bounding_box).any(|corner| polygon.contains(&corner))
+                    || edges(polygon).any(|edge| crosses_box(&edge, bounding_box))
+            }
+            (Region::Polygon(a), Region::Polygon(b)) => {
+                a.rings().flatten().any(|vertex| b.contains(vertex))
+                    || b.rings().flatten().any(|vertex| a.contains(vertex))
+                    || edges(a)
+                        .any(|edge| edges(b).any(|other| !edge.intersections(&other).is_empty()))
+            }
+        }
+    }
+
+    /// Returns the smallest box, in the Cartesian space of the unit sphere,
+    /// containing the region.
+    fn bounds(&self) -> Bounds {
+        match self {
+            Region::Box(bounding_box) => box_bounds(bounding_box),
+            Region::Cap(cap) => cap_bounds(cap),
+            Region::Polygon(polygon) => polygon_bounds(polygon),
+        }
+    }
+}
+
+/// An axis-aligned box in Cartesian space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bounds {
+    min: [f64; 3],
+    max: [f64; 3],
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self {
+            min: [f64::INFINITY; 3],
+            max: [f64::NEG_INFINITY; 3],
+        }
+    }
+}
+
+impl Bounds {
+    /// Returns the smallest bounds containing both, self and the given point.
+    fn with_point(mut self, point: &CartesianPoint) -> Self {
+        (0..3).for_each(|axis| {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        });
+
+        self
+    }
+
+    /// Returns the smallest bounds containing both, self and the given ones.
+    fn union(&self, other: &Bounds) -> Self {
+        Self {
+            min: [0, 1, 2].map(|axis| self.min[axis].min(other.min[axis])),
+            max: [0, 1, 2].map(|axis| self.max[axis].max(other.max[axis])),
+        }
+    }
+
+    /// Returns true if, and only if, self and the given bounds share any point.
+    fn intersects(&self, other: &Bounds) -> bool {
+        (0..3).all(|axis| {
+            self.min[axis] <= other.max[axis] + EPSILON
+                && other.min[axis] <= self.max[axis] + EPSILON
+        })
+    }
+
+    /// Returns the volume and the sum of the sides of the bounds, which tell
+    /// how large they are even when flat.
+    fn size(&self) -> (f64, f64) {
+        let sides = [0, 1, 2].map(|axis| (self.max[axis] - self.min[axis]).max(0.));
+        (sides.iter().product(), sides.iter().sum())
+    }
+
+    /// Returns the coordinate of the center of the bounds along the given axis.
+    fn center(&self, axis: usize) -> f64 {
+        (self.min[axis] + self.max[axis]) / 2.
+    }
+}
+
+/// A node of the R-tree, whose entries are either keys of regions, if it is a
+/// leaf, or other nodes.
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Bounds,
+    entries: Vec<usize>,
+    leaf: bool,
+}
+
+/// Represents a set of regions indexed by an
+/// [R-tree](https://en.wikipedia.org/wiki/R-tree), being the bounds of each
+/// node a box in the Cartesian space of the unit sphere. These never have to
+/// deal with the antimeridian, and keep tight around the poles.
+///
+/// Bulk loaded indexes are packed by the Sort-Tile-Recursive algorithm, which
+/// yields faster queries than successive insertions.
+///
+/// Each region is identified by the key it was given on insertion, which is
+/// never reused.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct RegionIndex {
+    regions: Vec<Option<Region>>,
+    bounds: Vec<Bounds>,
+    nodes: Vec<Node>,
+    root: Option<usize>,
+    len: usize,
+    stale: usize,
+}
+
+#[wasm_bindgen]
+impl RegionIndex {
+    /// Returns an empty index.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the given boxes, whose keys are their positions in
+    /// the given vector.
+    pub fn from_boxes(boxes: Vec<GeoBoundingBox>) -> Self {
+        Self::bulk_load(boxes.into_iter().map(Region::Box))
+    }
+
+    /// Returns the index of the given caps, whose keys are their positions in
+    /// the given vector.
+    pub fn from_caps(caps: Vec<SphericalCap>) -> Self {
+        Self::bulk_load(caps.into_iter().map(Region::Cap))
+    }
+
+    /// Returns the index of the given polygons, whose keys are their positions
+    /// in the given vector.
+    pub fn from_polygons(polygons: Vec<SphericalPolygon>) -> Self {
+        Self::bulk_load(polygons.into_iter().map(Region::Polygon))
+    }
+
+    /// Returns the amount of regions in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if, and only if, the index has no regions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the given box into the index, returning its key.
+    pub fn insert_box(&mut self, bounding_box: GeoBoundingBox) -> usize {
+        self.insert(Region::Box(bounding_box))
+    }
+
+    /// Adds the given cap into the index, returning its key.
+    pub fn insert_cap(&mut self, cap: SphericalCap) -> usize {
+        self.insert(Region::Cap(cap))
+    }
+
+    /// Adds the given polygon into the index, returning its key.
+    pub fn insert_polygon(&mut self, polygon: SphericalPolygon) -> usize {
+        self.insert(Region::Polygon(polygon))
+    }
+
+    /// Removes the region with the given key from the index, returning true if
+    /// it was there.
+    pub fn remove(&mut self, key: usize) -> bool {
+        if self.regions.get_mut(key).and_then(Option::take).is_none() {
+            return false;
+        }
+
+        // removed regions are kept in the tree until it gets packed again
+        self.len -= 1;
+        self.stale += 1;
+        if self.stale > self.len {
+            self.pack();
+        }
+
+        true
+    }
+
+    /// Rebuilds the tree by bulk loading all the regions in the index, which
+    /// speeds up the queries after many insertions.
+    pub fn pack(&mut self) {
+        self.nodes.clear();
+        self.stale = 0;
+
+        let mut level = self.pack_level(
+            self.regions
+                .iter()
+                .enumerate()
+                .filter(|(_, region)| region.is_some())
+                .map(|(key, _)| (key, self.bounds[key]))
+                .collect(),
+            true,
+        );
+
+        while level.len() > 1 {
+            let entries = level
+                .into_iter()
+                .map(|node| (node, self.nodes[node].bounds))
+                .collect();
+
+            level = self.pack_level(entries, false);
+        }
+
+        self.root = level.first().copied();
+    }
+
+    /// Returns the keys of all the regions containing the given point, sorted.
+    pub fn containing(&self, point: &GeographicPoint) -> Vec<usize> {
+        let bounds = Bounds::default().with_point(&CartesianPoint::direction_of(point));
+        self.search(&bounds, |region| region.contains(point))
+    }
+
+    /// Returns the keys of all the regions sharing any point with the given
+    /// box, sorted.
+    pub fn intersecting_box(&self, bounding_box: &GeoBoundingBox) -> Vec<usize> {
+        self.intersecting(&Region::Box(*bounding_box))
+    }
+
+    /// Returns the keys of all the regions sharing any point with the given
+    /// cap, sorted.
+    pub fn intersecting_cap(&self, cap: &SphericalCap) -> Vec<usize> {
+        self.intersecting(&Region::Cap(*cap))
+    }
+
+    /// Returns the keys of all the regions sharing any point with the given
+    /// polygon, sorted.
+    pub fn intersecting_polygon(&self, polygon: &SphericalPolygon) -> Vec<usize> {
+        self.intersecting(&Region::Polygon(polygon.clone()))
+    }
+}
+
+impl RegionIndex {
+    /// Returns the packed index of the given regions, whose keys are their
+    /// positions in the iterator.
+    fn bulk_load(regions: impl Iterator<Item = Region>) -> Self {
+        let mut index = Self::default();
+        regions.for_each(|region| {
+            index.bounds.push(region.bounds());
+            index.regions.push(Some(region));
+            index.len += 1;
+        });
+
+        index.pack();
+        index
+    }
+
+    /// Adds the given region into the tree, returning its key.
+    fn insert(&mut self, region: Region) -> usize {
+        let key = self.regions.len();
+        let bounds = region.bounds();
+        self.bounds.push(bounds);
+        self.regions.push(Some(region));
+        self.len += 1;
+
+        let Some(root) = self.root else {
+            self.root = Some(self.push_node(vec![key], true));
+            return key;
+        };
+
+        if let Some(sibling) = self.insert_into(root, key, &bounds) {
+            self.root = Some(self.push_node(vec![root, sibling], false));
+        }
+
+        key
+    }
+
+    /// Adds the given key into the subtree of the given node, returning the new
+    /// sibling of that node if it had to be split.
+    fn insert_into(&mut self, node: usize, key: usize, bounds: &Bounds) -> Option<usize> {
+        self.nodes[node].bounds = self.nodes[node].bounds.union(bounds);
+
+        if self.nodes[node].leaf {
+            self.nodes[node].entries.push(key);
+        } else {
+            // the child needing the least enlargement is the best fit
+            let child = self.nodes[node].entries.iter().copied().min_by(|&a, &b| {
+                let growth = |child: usize| {
+                    let current = self.nodes[child].bounds.size();
+                    let grown = self.nodes[child].bounds.union(bounds).size();
+                    (grown.0 - current.0, grown.1 - current.1, current.0)
+                };
+
+                let (a, b) = (growth(a), growth(b));
+                a.0.total_cmp(&b.0)
+                    .then(a.1.total_cmp(&b.1))
+                    .then(a.2.total_cmp(&b.2))
+            })?;
+
+            if let Some(sibling) = self.insert_into(child, key, bounds) {
+                self.nodes[node].entries.push(sibling);
+            }
+        }
+
+        (self.nodes[node].entries.len() > MAX_ENTRIES).then(|| self.split(node))
+    }
+
+    /// Moves half of the entries of the given node into a new one, returning
+    /// it.
+    fn split(&mut self, node: usize) -> usize {
+        let leaf = self.nodes[node].leaf;
+        let mut entries: Vec<(usize, Bounds)> = self.nodes[node]
+            .entries
+            .iter()
+            .map(|&entry| (entry, self.entry_bounds(entry, leaf)))
+            .collect();
+
+        // splitting along the axis with the largest spread keeps nodes compact
+        let spread = |axis: usize| {
+            let (min, max) = entries.iter().fold(
+                (f64::INFINITY, f64::NEG_INFINITY),
+                |(min, max), (_, bounds)| {
+                    (min.min(bounds.center(axis)), max.max(bounds.center(axis)))
+                },
+            );
+
+            max - min
+        };
+
+        let axis = (0..3)
+            .max_by(|&a, &b| spread(a).total_cmp(&spread(b)))
+            .unwrap_or_default();
+
+        entries.sort_by(|a, b| a.1.center(axis).total_cmp(&b.1.center(axis)));
+        let half = entries.split_off(entries.len() / 2);
+
+        self.nodes[node] = Node {
+            bounds: union_of(&entries),
+            entries: entries.into_iter().map(|(entry, _)| entry).collect(),
+            leaf,
+        };
+
+        self.push_node(half.into_iter().map(|(entry, _)| entry).collect(), leaf)
+    }
+
+    /// Groups the given entries into nodes following the Sort-Tile-Recursive
+    /// algorithm, returning them.
+    fn pack_level(&mut self, mut entries: Vec<(usize, Bounds)>, leaf: bool) -> Vec<usize> {
+        let nodes = entries.len().div_ceil(MAX_ENTRIES);
+        let slices = (nodes as f64).cbrt().ceil().max(1.) as usize;
+
+        let sort = |entries: &mut [(usize, Bounds)], axis: usize| {
+            entries.sort_by(|a, b| a.1.center(axis).total_cmp(&b.1.center(axis)));
+        };
+
+        sort(&mut entries, 0);
+        let mut groups = Vec::with_capacity(nodes);
+        entries
+            .chunks_mut(slices * slices * MAX_ENTRIES)
+            .for_each(|slab| {
+                sort(slab, 1);
+                slab.chunks_mut(slices * MAX_ENTRIES).for_each(|run| {
+                    sort(run, 2);
+                    run.chunks(MAX_ENTRIES)
+                        .for_each(|group| groups.push(group.to_vec()));
+                });
+            });
+
+        groups
+            .into_iter()
+            .map(|group| {
+                let bounds = union_of(&group);
+                let node =
+                    self.push_node(group.into_iter().map(|(entry, _)| entry).collect(), leaf);
+                self.nodes[node].bounds = bounds;
+                node
+            })
+            .collect()
+    }
+
+    /// Adds a new node with the given entries, returning it.
+    fn push_node(&mut self, entries: Vec<usize>, leaf: bool) -> usize {
+        let bounds = entries
+            .iter()
+            .map(|&entry| self.entry_bounds(entry, leaf))
+            .fold(Bounds::default(), |bounds, other| bounds.union(&other));
+
+        self.nodes.push(Node {
+            bounds,
+            entries,
+            leaf,
+        });
+
+        self.nodes.len() - 1
+    }
+
+    /// Returns the bounds of the given entry of a node.
+    fn entry_bounds(&self, entry: usize, leaf: bool) -> Bounds {
+        if leaf {
+            self.bounds[entry]
+        } else {
+            self.nodes[entry].bounds
+        }
+    }
+
+    /// Returns the keys of all the regions sharing any point with the given
+    /// one, sorted.
+    fn intersecting(&self, region: &Region) -> Vec<usize> {
+        self.search(&region.bounds(), |other| other.intersects(region))
+    }
+
+    /// Returns the keys of all the regions whose bounds intersect the given
+    /// ones and satisfy the given predicate, sorted.
+    fn search(&self, bounds: &Bounds, predicate: impl Fn(&Region) -> bool) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut pending: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            if !node.bounds.intersects(bounds) {
+                continue;
+            }
+
+            if !node.leaf {
+                pending.extend(node.entries.iter().copied());
+                continue;
+            }
+
+            found.extend(node.entries.iter().copied().filter(|&key| {
+                self.bounds[key].intersects(bounds)
+                    && self.regions[key].as_ref().is_some_and(&predicate)
+            }));
+        }
+
+        found.sort_unstable();
+        found
+    }
+}
+
+/// Returns the smallest bounds containing all the given ones.
+fn union_of(entries: &[(usize, Bounds)]) -> Bounds {
+    entries
+        .iter()
+        .fold(Bounds::default(), |bounds, (_, other)| bounds.union(other))
+}
+
+/// Returns the six points where the axis of coordinates cross the unit sphere.
+fn axis_points() -> impl Iterator<Item = CartesianPoint> {
+    (0..3).flat_map(|axis| {
+        [1., -1.].map(|sign| {
+            let mut point = CartesianPoint::default();
+            point[axis] = sign;
+            point
+        })
+    })
+}
+
+/// Returns the bounds of the given lat/lon box.
+fn box_bounds(bounding_box: &GeoBoundingBox) -> Bounds {
+    // the box is the product of a range of latitudes and one of longitudes, so
+    // its extremes are the ones of their sines and cosines.
+    let at = |longitude: f64| {
+        GeographicPoint::default()
+            .with_longitude(longitude)
+            .with_latitude(bounding_box.south())
+    };
+
+    let range = |function: fn(f64) -> f64, maximum: f64, minimum: f64| {
+        let ends = [function(bounding_box.west()), function(bounding_box.east())];
+
+        let max = if bounding_box.contains(&at(maximum)) {
+            1.
+        } else {
+            ends[0].max(ends[1])
+        };
+
+        let min = if bounding_box.contains(&at(minimum)) {
+            -1.
+        } else {
+            ends[0].min(ends[1])
+        };
+
+        (min, max)
+    };
+
+    let (south, north) = (bounding_box.south(), bounding_box.north());
+    let radius_max = if south <= 0. && north >= 0. {
+        1.
+    } else {
+        south.cos().max(north.cos())
+    };
+
+    let radius_min = south.cos().min(north.cos());
+
+    // the product of a positive range of radiuses and any range of values
+    let scale = |(min, max): (f64, f64)| {
+        (
+            if min < 0. {
+                radius_max * min
+            } else {
+                radius_min * min
+            },
+            if max > 0. {
+                radius_max * max
+            } else {
+                radius_min * max
+            },
+        )
+    };
+
+    let x = scale(range(f64::cos, 0., PI));
+    let y = scale(range(f64::sin, PI / 2., -PI / 2.));
+
+    Bounds {
+        min: [x.0, y.0, south.sin()],
+        max: [x.1, y.1, north.sin()],
+    }
+}
+
+/// Returns the bounds of the given cap.
+fn cap_bounds(cap: &SphericalCap) -> Bounds {
+    let center = CartesianPoint::direction_of(&cap.center());
+
+    // the extreme along any axis is the point of the cap closest to the axis
+    let extreme = |coordinate: f64| {
+        let angle = coordinate.clamp(-1., 1.).acos();
+        if angle <= cap.radius() {
+            1.
+        } else {
+            (angle - cap.radius()).cos()
+        }
+    };
+
+    Bounds {
+        min: [0, 1, 2].map(|axis| -extreme(-center[axis])),
+        max: [0, 1, 2].map(|axis| extreme(center[axis])),
+    }
+}
+
+/// Returns the bounds of the given polygon.
+fn polygon_bounds(polygon: &SphericalPolygon) -> Bounds {
+    let vertices = polygon
+        .rings()
+        .flatten()
+        .map(CartesianPoint::direction_of)
+        .fold(Bounds::default(), |bounds, vertex| {
+            bounds.with_point(&vertex)
+        });
+
+    // edges may bulge beyond their ends, and the interior may contain the
+    // points where the axis cross the sphere.
+    edges(polygon)
+        .flat_map(|edge| {
+            let pole = edge.pole();
+            axis_points().filter_map(move |axis| {
+                let extreme = axis.add_scaled(&pole, -axis.dot(&pole));
+                let extreme =
+                    GeographicPoint::from_cartesian(&extreme.normalize()).with_altitude(0.);
+                (pole.magnitude() > 0. && edge.contains(&extreme))
+                    .then(|| CartesianPoint::direction_of(&extreme))
+            })
+        })
+        .chain(axis_points().filter(|axis| {
+            polygon.contains(&GeographicPoint::from_cartesian(axis).with_altitude(0.))
+        }))
+        .fold(vertices, |bounds, point| bounds.with_point(&point))
+}
+
+/// Returns an iterator over all the edges of the given polygon.
+fn edges(polygon: &SphericalPolygon) -> impl Iterator<Item = GreatCircleArc> + '_ {
+    polygon.rings().flat_map(|ring| {
+        crate::polygon::edges(ring).map(|(from, to)| GreatCircleArc::new(*from, *to))
+    })
+}
+
+/// Returns the corners of the given box.
+fn corners(bounding_box: &GeoBoundingBox) -> impl Iterator<Item = GeographicPoint> {
+    let (west, south) = (bounding_box.west(), bounding_box.south());
+    let (east, north) = (bounding_box.east(), bounding_box.north());
+
+    [(west, south), (east, south), (east, north), (west, north)]
+        .into_iter()
+        .map(|(longitude, latitude)| {
+            GeographicPoint::default()
+                .with_longitude(longitude)
+                .with_latitude(latitude)
+        })
+}
+
+/// Returns true if, and only if, the given arc crosses the boundary of the
+/// given box.
+fn crosses_box(arc: &GreatCircleArc, bounding_box: &GeoBoundingBox) -> bool {
+    let (south, north) = (bounding_box.south(), bounding_box.north());
+    let meridians = if bounding_box.is_full_longitude() {
+        Vec::new()
+    } else {
+        vec![bounding_box.west(), bounding_box.east()]
+    };
+
+    meridians
+        .into_iter()
+        .any(|longitude| bounding_box.meridian_crosses(longitude, arc))
+        || [south, north].into_iter().any(|latitude| {
+            parallel_crossings(arc, latitude)
+                .iter()
+                .any(|point| bounding_box.contains(point))
+        })
+}
+
+/// Returns the points where the given arc crosses the parallel of the given
+/// latitude.
+fn parallel_crossings(arc: &GreatCircleArc, latitude: f64) -> Vec<GeographicPoint> {
+    // the points are the ones at the given height whose position vector is
+    // orthogonal to the pole of the arc.
+    let pole = arc.pole();
+    let horizontal = pole.x().hypot(pole.y());
+    if horizontal <= EPSILON {
+        // the arc lays on the equator
+        return Vec::new();
+    }
+
+    let (z, radius) = latitude.sin_cos();
+    let offset = -pole.z() * z / horizontal;
+    let half_chord = radius.powi(2) - offset.powi(2);
+    if half_chord < 0. {
+        return Vec::new();
+    }
+
+    let half_chord = half_chord.sqrt();
+    let (normal_x, normal_y) = (pole.x() / horizontal, pole.y() / horizontal);
+
+    [half_chord, -half_chord]
+        .into_iter()
+        .map(|along| {
+            let point = CartesianPoint::new(
+                normal_x * offset - normal_y * along,
+                normal_y * offset + normal_x * along,
+                z,
+            );
+
+            GeographicPoint::from_cartesian(&point).with_altitude(0.)
+        })
+        .filter(|point| arc.contains(point))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+
+    fn box_from_degrees(west: f64, south: f64, east: f64, north: f64) -> GeoBoundingBox {
+        GeoBoundingBox::new(
+            west.to_radians(),
+            south.to_radians(),
+            east.to_radians(),
+            north.to_radians(),
+        )
+    }
+
+    /// Returns a deterministic set of regions of every kind spread all over
+    /// the sphere.
+    fn regions() -> Vec<Region> {
+        (0..120)
+            .map(|index| {
+                let longitude = (index as f64 * 47.) % 360. - 180.;
+                let latitude = (index as f64 * 29.) % 170. - 85.;
+                let size = 2. + (index % 7) as f64 * 3.;
+
+                match index % 3 {
+                    0 => Region::Box(box_from_degrees(
+                        longitude,
+                        latitude - size / 2.,
+                        longitude + size,
+                        latitude + size / 2.,
+                    )),
+                    1 => Region::Cap(SphericalCap::new(
+                        from_degrees(longitude, latitude),
+                        size.to_radians(),
+                    )),
+                    _ => Region::Polygon(SphericalPolygon::new(vec![
+                        from_degrees(longitude, latitude - size),
+                        from_degrees(longitude + size, latitude),
+                        from_degrees(longitude, latitude + size),
+                        from_degrees(longitude - size, latitude),
+                    ])),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns an index with the given regions, inserting them one by one.
+    fn index_by_insertion(regions: &[Region]) -> RegionIndex {
+        let mut index = RegionIndex::new();
+        regions.iter().for_each(|region| {
+            match region.clone() {
+                Region::Box(bounding_box) => index.insert_box(bounding_box),
+                Region::Cap(cap) => index.insert_cap(cap),
+                Region::Polygon(polygon) => index.insert_polygon(polygon),
+            };
+        });
+
+        index
+    }
+
+    #[test]
+    fn bounds_must_contain_regions() {
+        regions()
+            .into_iter()
+            .chain([
+                Region::Box(GeoBoundingBox::world()),
+                Region::Box(box_from_degrees(170., 80., -170., 90.)),
+                Region::Cap(SphericalCap::new(from_degrees(0., 90.), 0.5)),
+                Region::Polygon(SphericalPolygon::new(vec![
+                    from_degrees(0., 60.),
+                    from_degrees(120., 60.),
+                    from_degrees(-120., 60.),
+                ])),
+            ])
+            .for_each(|region| {
+                let bounds = region.bounds();
+                (-90..=90).step_by(5).for_each(|latitude| {
+                    (-180..180).step_by(5).for_each(|longitude| {
+                        let point = from_degrees(longitude as f64, latitude as f64);
+                        if region.contains(&point) {
+                            let point =
+                                Bounds::default().with_point(&CartesianPoint::direction_of(&point));
+                            assert!(
+                                bounds.intersects(&point),
+                                "{region:?}: bounds {bounds:?} must contain {point:?}"
+                            );
+                        }
+                    });
+                });
+            });
+    }
+
+    #[test]
+    fn queries_must_match_brute_force() {
+        let regions = regions();
+        let mut packed = RegionIndex::bulk_load(regions.clone().into_iter());
+        let mut inserted = index_by_insertion(&regions);
+
+        let mut alive: Vec<bool> = vec![true; regions.len()];
+        (0..regions.len()).step_by(5).for_each(|key| {
+            assert!(packed.remove(key), "removing from the packed index");
+            assert!(inserted.remove(key), "removing from the dynamic index");
+            alive[key] = false;
+        });
+
+        assert!(!packed.remove(0), "removing twice");
+        assert_eq!(packed.len(), alive.iter().filter(|alive| **alive).count());
+
+        let queries = [
+            Region::Box(box_from_degrees(-30., -20., 40., 10.)),
+            Region::Box(box_from_degrees(160., -50., -150., 50.)),
+            Region::Box(box_from_degrees(100., -90., 110., 90.)),
+            Region::Cap(SphericalCap::new(from_degrees(100., -40.), 0.4)),
+            Region::Cap(SphericalCap::new(from_degrees(0., -90.), 0.3)),
+            Region::Polygon(SphericalPolygon::new(vec![
+                from_degrees(-100., 10.),
+                from_degrees(-60., 10.),
+                from_degrees(-80., 50.),
+            ])),
+        ];
+
+        queries.iter().for_each(|query| {
+            let want: Vec<usize> = (0..regions.len())
+                .filter(|&key| alive[key] && regions[key].intersects(query))
+                .collect();
+
+            assert!(!want.is_empty(), "{query:?}: query must not be trivial");
+            [&packed, &inserted].into_iter().for_each(|index| {
+                let got = match query {
+                    Region::Box(bounding_box) => index.intersecting_box(bounding_box),
+                    Region::Cap(cap) => index.intersecting_cap(cap),
+                    Region::Polygon(polygon) => index.intersecting_polygon(polygon),
+                };
+
+                assert_eq!(got, want, "{query:?}");
+            });
+        });
+
+        (-80..=80).step_by(20).for_each(|latitude| {
+            (-180..180).step_by(30).for_each(|longitude| {
+                let point = from_degrees(longitude as f64, latitude as f64);
+                let want: Vec<usize> = (0..regions.len())
+                    .filter(|&key| alive[key] && regions[key].contains(&point))
+                    .collect();
+
+                assert_eq!(packed.containing(&point), want, "{point:?} packed");
+                assert_eq!(inserted.containing(&point), want, "{point:?} inserted");
+            });
+        });
+    }
+
+    #[test]
+    fn intersects_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            a: Region,
+            b: Region,
+            intersects: bool,
+        }
+
+        let square = SphericalPolygon::new(vec![
+            from_degrees(-10., -10.),
+            from_degrees(10., -10.),
+            from_degrees(10., 10.),
+            from_degrees(-10., 10.),
+        ]);
+
+        vec![
+            TestCase {
+                name: "box crossing a polygon without any vertex inside each other",
+                a: Region::Box(box_from_degrees(-20., -5., 20., 5.)),
+                b: Region::Polygon(square.clone()),
+                intersects: true,
+            },
+            TestCase {
+                name: "box near a polygon",
+                a: Region::Box(box_from_degrees(11., -5., 20., 5.)),
+                b: Region::Polygon(square.clone()),
+                intersects: false,
+            },
+            TestCase {
+                name: "arc crossing the parallel of a box",
+                a: Region::Box(box_from_degrees(-40., 10., 40., 20.)),
+                b: Region::Polygon(SphericalPolygon::new(vec![
+                    from_degrees(-30., 5.),
+                    from_degrees(30., 5.),
+                    from_degrees(0., 12.),
+                ])),
+                intersects: true,
+            },
+            TestCase {
+                name: "polygon crossing a box from pole to pole",
+                a: Region::Box(box_from_degrees(100., -90., 110., 90.)),
+                b: Region::Polygon(SphericalPolygon::new(vec![
+                    from_degrees(90., -5.),
+                    from_degrees(120., -5.),
+                    from_degrees(120., 5.),
+                    from_degrees(90., 5.),
+                ])),
+                intersects: true,
+            },
+            TestCase {
+                name: "polygon near a box from pole to pole",
+                a: Region::Box(box_from_degrees(100., -90., 110., 90.)),
+                b: Region::Polygon(SphericalPolygon::new(vec![
+                    from_degrees(115., -5.),
+                    from_degrees(125., -5.),
+                    from_degrees(125., 5.),
+                    from_degrees(115., 5.),
+                ])),
+                intersects: false,
+            },
+            TestCase {
+                name: "cap touching a box across the antimeridian",
+                a: Region::Box(box_from_degrees(170., -10., -170., 10.)),
+                b: Region::Cap(SphericalCap::new(from_degrees(-165., 0.), 0.1)),
+                intersects: true,
+            },
+            TestCase {
+                name: "cap near a polygon",
+                a: Region::Cap(SphericalCap::new(from_degrees(20., 0.), 0.1)),
+                b: Region::Polygon(square.clone()),
+                intersects: false,
+            },
+            TestCase {
+                name: "polygon inside a polygon",
+                a: Region::Polygon(square.clone()),
+                b: Region::Polygon(SphericalPolygon::new(vec![
+                    from_degrees(-1., -1.),
+                    from_degrees(1., -1.),
+                    from_degrees(0., 1.),
+                ])),
+                intersects: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.a.intersects(&test_case.b),
+                test_case.intersects,
+                "{}",
+                test_case.name
+            );
+
+            assert_eq!(
+                test_case.b.intersects(&test_case.a),
+                test_case.intersects,
+                "{}: symmetry",
+                test_case.name
+            );
+        });
+    }
+}
@@ -0,0 +1,303 @@
+use crate::GeographicPoint;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Represents the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line) (or
+/// loxodrome) going from one point to another on the surface of a sphere, which
+/// is the path that crosses all meridians at the same angle. All distances are
+/// angular (in radiants), and so they must be multiplied by the radius of the
+/// sphere to get a length.
+///
+/// Among both rhumb lines connecting any two points, the shortest one is always
+/// taken, which may cross the antimeridian.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RhumbLine {
+    from: GeographicPoint,
+    to: GeographicPoint,
+}
+
+#[wasm_bindgen]
+impl RhumbLine {
+    #[wasm_bindgen(constructor)]
+    pub fn new(from: GeographicPoint, to: GeographicPoint) -> Self {
+        Self { from, to }
+    }
+
+    /// Returns the point reached after traveling the given angular distance (in
+    /// radiants) from the given point with the given constant bearing (in
+    /// radiants, clockwise from the north). The altitude is preserved.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, RhumbLine};
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let from = GeographicPoint::default().with_latitude(1.);
+    /// let to = RhumbLine::destination(&from, FRAC_PI_2, 0.1);
+    ///
+    /// assert!(approx_eq!(f64, to.latitude(), 1., ulps = 2));
+    /// ```
+    pub fn destination(from: &GeographicPoint, bearing: f64, distance: f64) -> GeographicPoint {
+        // see: https://www.movable-type.co.uk/scripts/latlong.html#rhumblines
+        let delta_lat = distance * bearing.cos();
+        let mut latitude = from.latitude() + delta_lat;
+
+        // a rhumb line may not go beyond any of the poles
+        if latitude.abs() > FRAC_PI_2 {
+            latitude = latitude.signum() * PI - latitude;
+        }
+
+        let q = stretch_ratio(delta_lat, from.latitude(), latitude);
+        let delta_long = if q == 0. {
+            // the longitude of a pole is meaningless
+            0.
+        } else {
+            distance * bearing.sin() / q
+        };
+
+        GeographicPoint::default()
+            .with_longitude(from.longitude() + delta_long)
+            .with_latitude(latitude)
+            .with_altitude(from.altitude())
+    }
+
+    /// Returns the starting point of the rhumb line.
+    pub fn from(&self) -> GeographicPoint {
+        self.from
+    }
+
+    /// Returns the ending point of the rhumb line.
+    pub fn to(&self) -> GeographicPoint {
+        self.to
+    }
+
+    /// Returns the angular length of the rhumb line (in radiants).
+    pub fn length(&self) -> f64 {
+        let delta_lat = self.to.latitude() - self.from.latitude();
+        let q = stretch_ratio(delta_lat, self.from.latitude(), self.to.latitude());
+
+        delta_lat.hypot(q * self.longitude_difference())
+    }
+
+    /// Returns the constant bearing (in radiants, clockwise from the north) of
+    /// the rhumb line.
+    pub fn bearing(&self) -> f64 {
+        let delta_psi =
+            projected_latitude(self.to.latitude()) - projected_latitude(self.from.latitude());
+
+        self.longitude_difference().atan2(delta_psi)
+    }
+
+    /// Returns the point halfway along the rhumb line. The altitude is the
+    /// average of both ends.
+    pub fn midpoint(&self) -> GeographicPoint {
+        Self::destination(&self.from, self.bearing(), self.length() / 2.)
+            .with_altitude((self.from.altitude() + self.to.altitude()) / 2.)
+    }
+}
+
+impl RhumbLine {
+    /// Returns the shortest longitude difference from the start to the end of
+    /// the rhumb line, in the range __[-π, +π]__.
+    fn longitude_difference(&self) -> f64 {
+        GeographicPoint::default()
+            .with_longitude(self.to.longitude() - self.from.longitude())
+            .longitude()
+    }
+}
+
+/// Returns the latitude on a Mercator projection, also known as the isometric
+/// latitude, of the given one.
+fn projected_latitude(latitude: f64) -> f64 {
+    if latitude.abs() >= FRAC_PI_2 {
+        // the poles are infinitely far away on a Mercator projection
+        return latitude.signum() * f64::INFINITY;
+    }
+
+    (FRAC_PI_4 + latitude / 2.).tan().ln()
+}
+
+/// Returns the ratio between the latitude difference and its projected
+/// counterpart, which is the cosine of the latitude in the degenerate east-west
+/// case.
+fn stretch_ratio(delta_lat: f64, from: f64, to: f64) -> f64 {
+    let delta_psi = projected_latitude(to) - projected_latitude(from);
+    if delta_psi.abs() > 1e-12 {
+        delta_lat / delta_psi
+    } else {
+        from.cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::approx_eq;
+
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn rhumb_line_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            line: RhumbLine,
+            length: f64,
+            bearing: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "along the equator",
+                line: RhumbLine::new(
+                    GeographicPoint::default(),
+                    GeographicPoint::default().with_longitude(1.),
+                ),
+                length: 1.,
+                bearing: FRAC_PI_2,
+            },
+            TestCase {
+                name: "along a parallel must follow the parallel",
+                line: RhumbLine::new(
+                    GeographicPoint::default().with_latitude(1.),
+                    GeographicPoint::default()
+                        .with_longitude(-1.)
+                        .with_latitude(1.),
+                ),
+                length: 1_f64.cos(),
+                bearing: -FRAC_PI_2,
+            },
+            TestCase {
+                name: "along a meridian",
+                line: RhumbLine::new(
+                    GeographicPoint::default().with_latitude(0.5),
+                    GeographicPoint::default().with_latitude(-0.2),
+                ),
+                length: 0.7,
+                bearing: PI,
+            },
+            TestCase {
+                name: "across the antimeridian",
+                line: RhumbLine::new(
+                    GeographicPoint::default().with_longitude(PI - 0.1),
+                    GeographicPoint::default().with_longitude(-PI + 0.1),
+                ),
+                length: 0.2,
+                bearing: FRAC_PI_2,
+            },
+            TestCase {
+                name: "to the pole",
+                line: RhumbLine::new(
+                    GeographicPoint::default(),
+                    GeographicPoint::default()
+                        .with_longitude(2.)
+                        .with_latitude(FRAC_PI_2),
+                ),
+                length: FRAC_PI_2,
+                bearing: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.line.length(),
+                    test_case.length,
+                    epsilon = EPSILON
+                ),
+                "{}: length {} ±ε = {}",
+                test_case.name,
+                test_case.line.length(),
+                test_case.length
+            );
+
+            assert!(
+                approx_eq!(
+                    f64,
+                    test_case.line.bearing(),
+                    test_case.bearing,
+                    epsilon = EPSILON
+                ),
+                "{}: bearing {} ±ε = {}",
+                test_case.name,
+                test_case.line.bearing(),
+                test_case.bearing
+            );
+        });
+    }
+
+    #[test]
+    fn destination_must_revert_rhumb_line() {
+        struct TestCase {
+            name: &'static str,
+            from: GeographicPoint,
+            to: GeographicPoint,
+        }
+
+        vec![
+            TestCase {
+                name: "heading north-east",
+                from: GeographicPoint::default()
+                    .with_longitude(-0.1)
+                    .with_latitude(0.9),
+                to: GeographicPoint::default()
+                    .with_longitude(0.4)
+                    .with_latitude(1.1),
+            },
+            TestCase {
+                name: "heading south-west across the antimeridian",
+                from: GeographicPoint::default()
+                    .with_longitude(-PI + 0.2)
+                    .with_latitude(0.3),
+                to: GeographicPoint::default()
+                    .with_longitude(PI - 0.3)
+                    .with_latitude(-0.4),
+            },
+            TestCase {
+                name: "heading west along a parallel",
+                from: GeographicPoint::default()
+                    .with_longitude(1.)
+                    .with_latitude(-0.6),
+                to: GeographicPoint::default()
+                    .with_longitude(-1.)
+                    .with_latitude(-0.6),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let line = RhumbLine::new(test_case.from, test_case.to);
+            let to = RhumbLine::destination(&test_case.from, line.bearing(), line.length());
+            assert!(
+                to.distance(&test_case.to) < 1e-7,
+                "{}: destination {:?} ±ε = {:?}",
+                test_case.name,
+                to,
+                test_case.to
+            );
+
+            let midpoint = line.midpoint();
+            let first_half = RhumbLine::new(test_case.from, midpoint);
+            let second_half = RhumbLine::new(midpoint, test_case.to);
+            assert!(
+                approx_eq!(
+                    f64,
+                    first_half.length(),
+                    second_half.length(),
+                    epsilon = 1e-9
+                ),
+                "{}: midpoint {:?} must split the line in halves",
+                test_case.name,
+                midpoint
+            );
+
+            assert!(
+                approx_eq!(f64, first_half.bearing(), line.bearing(), epsilon = 1e-9),
+                "{}: midpoint {:?} must lay on the line",
+                test_case.name,
+                midpoint
+            );
+        });
+    }
+}
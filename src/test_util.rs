@@ -0,0 +1,8 @@
+use crate::GeographicPoint;
+
+/// Returns the point of the given longitude and latitude, in degrees.
+pub(crate) fn from_degrees(longitude: f64, latitude: f64) -> GeographicPoint {
+    GeographicPoint::default()
+        .with_longitude(longitude.to_radians())
+        .with_latitude(latitude.to_radians())
+}
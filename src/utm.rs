@@ -0,0 +1,700 @@
+use crate::{geographic::longitude_offset, Ellipsoid, GeographicPoint};
+use std::{f64::consts::FRAC_PI_2, fmt, sync::OnceLock};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The scale factor along the central meridian of every UTM zone.
+const UTM_SCALE_FACTOR: f64 = 0.9996;
+
+/// The scale factor at the poles of the UPS projections.
+const UPS_SCALE_FACTOR: f64 = 0.994;
+
+/// The easting (in meters) of the central meridian of every UTM zone.
+const UTM_FALSE_EASTING: f64 = 500_000.;
+
+/// The northing (in meters) of the equator in the southern UTM zones.
+const UTM_FALSE_NORTHING: f64 = 10_000_000.;
+
+/// Both the easting and northing (in meters) of the pole in the UPS
+/// projections.
+const UPS_FALSE_ORIGIN: f64 = 2_000_000.;
+
+/// The maximum number of iterations when reverting the conformal latitude.
+const MAX_ITERATIONS: usize = 10;
+
+/// Represents each of both halves of the globe split by the equator.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    North,
+    South,
+}
+
+impl Hemisphere {
+    /// Returns the hemisphere of the given latitude, being the equator part of
+    /// the northern one.
+    fn of(latitude: f64) -> Self {
+        if latitude < 0. {
+            Hemisphere::South
+        } else {
+            Hemisphere::North
+        }
+    }
+}
+
+/// Represents an error while building a [`UtmPoint`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum UtmError {
+    /// The zone is greater than 60.
+    InvalidZone(u8),
+    /// The point is too far away from the given zone to be represented in it.
+    OutOfZone(u8),
+    /// The easting or northing is not a finite number within the range of
+    /// the zone.
+    InvalidCoordinates(f64, f64),
+}
+
+impl fmt::Display for UtmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UtmError::InvalidZone(zone) => {
+                write!(f, "invalid zone {zone}, it must be in the range [0, 60]")
+            }
+            UtmError::OutOfZone(zone) => write!(f, "point is out of zone {zone}"),
+            UtmError::InvalidCoordinates(easting, northing) => write!(
+                f,
+                "invalid coordinates {easting} {northing} for the given zone"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UtmError {}
+
+/// Represents a point on the WGS84 ellipsoid as given by the
+/// [Universal Transverse Mercator](https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system)
+/// (UTM) coordinate system, or by the
+/// [Universal Polar Stereographic](https://en.wikipedia.org/wiki/Universal_polar_stereographic_coordinate_system)
+/// (UPS) one close to the poles.
+///
+/// UTM zones are numbered from 1 to 60, while the zone 0 stands for UPS. The
+/// transverse Mercator projection is computed by the Krüger series up to the
+/// sixth order, as given by Karney, which are accurate to within a few
+/// nanometers inside the zone.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UtmPoint {
+    zone: u8,
+    hemisphere: Hemisphere,
+    easting: f64,
+    northing: f64,
+}
+
+#[wasm_bindgen]
+impl UtmPoint {
+    /// Returns the coordinates of the given point in its standard zone, which
+    /// is the UTM zone including its longitude for latitudes in the range
+    /// __[-80°, 84°)__, and UPS otherwise. The exceptions of the zones 32V
+    /// (southwestern Norway) and 31X to 37X (Svalbard) are applied.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Hemisphere, UtmPoint};
+    /// use float_cmp::approx_eq;
+    ///
+    /// let point = UtmPoint::from_geographic(&GeographicPoint::default());
+    ///
+    /// assert_eq!(point.zone(), 31);
+    /// assert_eq!(point.hemisphere(), Hemisphere::North);
+    /// assert!(approx_eq!(f64, point.easting(), 166_021.443, epsilon = 1e-3));
+    /// ```
+    pub fn from_geographic(point: &GeographicPoint) -> UtmPoint {
+        let zone = standard_zone(point);
+        if zone == 0 {
+            ups_forward(point)
+        } else {
+            utm_forward(point, zone)
+        }
+    }
+
+    /// Returns the [`GeographicPoint`] of self, with no altitude.
+    pub fn to_geographic(&self) -> GeographicPoint {
+        if self.zone == 0 {
+            return ups_inverse(self);
+        }
+
+        let northing = match self.hemisphere {
+            Hemisphere::North => self.northing,
+            Hemisphere::South => self.northing - UTM_FALSE_NORTHING,
+        };
+
+        let (longitude, latitude) = Series::get().inverse(
+            (self.easting - UTM_FALSE_EASTING) / UTM_SCALE_FACTOR,
+            northing / UTM_SCALE_FACTOR,
+        );
+
+        GeographicPoint::default()
+            .with_longitude(central_meridian(self.zone) + longitude)
+            .with_latitude(latitude)
+    }
+
+    /// Returns the UTM zone of the point, or 0 if UPS.
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Returns the hemisphere of the point.
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the easting of the point, in meters.
+    pub fn easting(&self) -> f64 {
+        self.easting
+    }
+
+    /// Returns the northing of the point, in meters.
+    pub fn northing(&self) -> f64 {
+        self.northing
+    }
+}
+
+impl UtmPoint {
+    /// Returns the point with the given coordinates, if they are valid for the
+    /// given zone.
+    pub fn new(
+        zone: u8,
+        hemisphere: Hemisphere,
+        easting: f64,
+        northing: f64,
+    ) -> Result<Self, UtmError> {
+        if zone > 60 {
+            return Err(UtmError::InvalidZone(zone));
+        }
+
+        let point = Self {
+            zone,
+            hemisphere,
+            easting,
+            northing,
+        };
+
+        if !point.is_in_range() {
+            return Err(UtmError::InvalidCoordinates(easting, northing));
+        }
+
+        Ok(point)
+    }
+
+    /// Returns the coordinates of the given point in the given zone, no matter
+    /// its standard one, being 0 the UPS zone of its hemisphere. Fails if the
+    /// point is too far away from the zone.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, UtmPoint};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(5.9_f64.to_radians())
+    ///     .with_latitude(40_f64.to_radians());
+    ///
+    /// assert_eq!(UtmPoint::from_geographic(&point).zone(), 31);
+    /// assert_eq!(UtmPoint::from_geographic_in_zone(&point, 32).unwrap().zone(), 32);
+    /// ```
+    pub fn from_geographic_in_zone(point: &GeographicPoint, zone: u8) -> Result<Self, UtmError> {
+        if zone > 60 {
+            return Err(UtmError::InvalidZone(zone));
+        }
+
+        let projected = if zone == 0 {
+            ups_forward(point)
+        } else if longitude_offset(point.longitude(), central_meridian(zone)).abs() < FRAC_PI_2 {
+            utm_forward(point, zone)
+        } else {
+            // the projection is not defined that far from the central meridian
+            return Err(UtmError::OutOfZone(zone));
+        };
+
+        if !projected.is_in_range() {
+            return Err(UtmError::OutOfZone(zone));
+        }
+
+        Ok(projected)
+    }
+
+    /// Returns true if, and only if, the coordinates of self are finite and
+    /// close enough to the origin of its zone.
+    fn is_in_range(&self) -> bool {
+        let (easting, northing) = if self.zone == 0 {
+            (0. ..=2. * UPS_FALSE_ORIGIN, 0. ..=2. * UPS_FALSE_ORIGIN)
+        } else {
+            (0. ..=2. * UTM_FALSE_EASTING, 0. ..=UTM_FALSE_NORTHING)
+        };
+
+        easting.contains(&self.easting) && northing.contains(&self.northing)
+    }
+}
+
+/// The coefficients of the Krüger series for the WGS84 ellipsoid.
+struct Series {
+    eccentricity: f64,
+    rectifying_radius: f64,
+    alpha: [f64; 6],
+    beta: [f64; 6],
+}
+
+impl Series {
+    /// Returns the series of the WGS84 ellipsoid, which are computed once.
+    fn get() -> &'static Series {
+        static SERIES: OnceLock<Series> = OnceLock::new();
+        SERIES.get_or_init(|| {
+            // see: Karney, C. F. F. (2011). Transverse Mercator with an accuracy
+            // of a few nanometers. Journal of Geodesy, 85(8), 475-485.
+            let ellipsoid = Ellipsoid::WGS84;
+            let n = ellipsoid.flattening() / (2. - ellipsoid.flattening());
+            let [n2, n3, n4, n5, n6] = [n.powi(2), n.powi(3), n.powi(4), n.powi(5), n.powi(6)];
+
+            Series {
+                eccentricity: ellipsoid.eccentricity_squared().sqrt(),
+                rectifying_radius: ellipsoid.semi_major_axis() / (1. + n)
+                    * (1. + n2 / 4. + n4 / 64. + n6 / 256.),
+                alpha: [
+                    n / 2. - 2. * n2 / 3. + 5. * n3 / 16. + 41. * n4 / 180. - 127. * n5 / 288.
+                        + 7891. * n6 / 37800.,
+                    13. * n2 / 48. - 3. * n3 / 5. + 557. * n4 / 1440. + 281. * n5 / 630.
+                        - 1983433. * n6 / 1935360.,
+                    61. * n3 / 240. - 103. * n4 / 140.
+                        + 15061. * n5 / 26880.
+                        + 167603. * n6 / 181440.,
+                    49561. * n4 / 161280. - 179. * n5 / 168. + 6601661. * n6 / 7257600.,
+                    34729. * n5 / 80640. - 3418889. * n6 / 1995840.,
+                    212378941. * n6 / 319334400.,
+                ],
+                beta: [
+                    n / 2. - 2. * n2 / 3. + 37. * n3 / 96. - n4 / 360. - 81. * n5 / 512.
+                        + 96199. * n6 / 604800.,
+                    n2 / 48. + n3 / 15. - 437. * n4 / 1440. + 46. * n5 / 105.
+                        - 1118711. * n6 / 3870720.,
+                    17. * n3 / 480. - 37. * n4 / 840. - 209. * n5 / 4480. + 5569. * n6 / 90720.,
+                    4397. * n4 / 161280. - 11. * n5 / 504. - 830251. * n6 / 7257600.,
+                    4583. * n5 / 161280. - 108847. * n6 / 3991680.,
+                    20648693. * n6 / 638668800.,
+                ],
+            }
+        })
+    }
+
+    /// Returns the transverse Mercator projection (in meters, with unit
+    /// scale) of the given longitude, relative to the central meridian, and
+    /// latitude.
+    fn forward(&self, longitude: f64, latitude: f64) -> (f64, f64) {
+        let conformal = conformal_tangent(latitude.tan(), self.eccentricity);
+        let xi = conformal.atan2(longitude.cos());
+        let eta = (longitude.sin() / conformal.hypot(longitude.cos())).asinh();
+
+        let (x, y) = self
+            .alpha
+            .iter()
+            .zip(1..)
+            .fold((eta, xi), |(x, y), (coefficient, order)| {
+                let factor = 2. * order as f64;
+                (
+                    x + coefficient * (factor * xi).cos() * (factor * eta).sinh(),
+                    y + coefficient * (factor * xi).sin() * (factor * eta).cosh(),
+                )
+            });
+
+        (self.rectifying_radius * x, self.rectifying_radius * y)
+    }
+
+    /// Returns the longitude, relative to the central meridian, and latitude
+    /// of the given transverse Mercator coordinates (in meters, with unit
+    /// scale).
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let xi = y / self.rectifying_radius;
+        let eta = x / self.rectifying_radius;
+
+        let (eta_prime, xi_prime) = self.beta.iter().zip(1..).fold(
+            (eta, xi),
+            |(eta_prime, xi_prime), (coefficient, order)| {
+                let factor = 2. * order as f64;
+                (
+                    eta_prime - coefficient * (factor * xi).cos() * (factor * eta).sinh(),
+                    xi_prime - coefficient * (factor * xi).sin() * (factor * eta).cosh(),
+                )
+            },
+        );
+
+        let conformal = xi_prime.sin() / eta_prime.sinh().hypot(xi_prime.cos());
+        let longitude = eta_prime.sinh().atan2(xi_prime.cos());
+        let latitude = geodetic_tangent(conformal, self.eccentricity).atan();
+        (longitude, latitude)
+    }
+}
+
+/// Returns the tangent of the conformal latitude for the given tangent of the
+/// geodetic latitude.
+fn conformal_tangent(tangent: f64, eccentricity: f64) -> f64 {
+    let secant = tangent.hypot(1.);
+    let sigma = (eccentricity * (eccentricity * tangent / secant).atanh()).sinh();
+    tangent * sigma.hypot(1.) - sigma * secant
+}
+
+/// Returns the tangent of the geodetic latitude for the given tangent of the
+/// conformal latitude, as given by Newton's method.
+fn geodetic_tangent(conformal: f64, eccentricity: f64) -> f64 {
+    let one_minus_e2 = 1. - eccentricity.powi(2);
+    let mut tangent = conformal;
+    for _ in 0..MAX_ITERATIONS {
+        let current = conformal_tangent(tangent, eccentricity);
+        let delta = (conformal - current) * (1. + one_minus_e2 * tangent.powi(2))
+            / (one_minus_e2 * tangent.hypot(1.) * current.hypot(1.));
+
+        tangent += delta;
+        if delta.abs() <= f64::EPSILON * tangent.abs().max(1.) {
+            break;
+        }
+    }
+
+    tangent
+}
+
+/// Returns the UTM coordinates of the given point in the given zone.
+fn utm_forward(point: &GeographicPoint, zone: u8) -> UtmPoint {
+    let longitude = longitude_offset(point.longitude(), central_meridian(zone));
+    let (x, y) = Series::get().forward(longitude, point.latitude());
+
+    let hemisphere = Hemisphere::of(point.latitude());
+    UtmPoint {
+        zone,
+        hemisphere,
+        easting: UTM_FALSE_EASTING + UTM_SCALE_FACTOR * x,
+        northing: match hemisphere {
+            Hemisphere::North => UTM_SCALE_FACTOR * y,
+            Hemisphere::South => UTM_FALSE_NORTHING + UTM_SCALE_FACTOR * y,
+        },
+    }
+}
+
+/// Returns the scale factor from the distance to the pole on the polar
+/// stereographic projection to the tangent of the half colatitude.
+fn ups_scale() -> f64 {
+    let ellipsoid = Ellipsoid::WGS84;
+    let e = ellipsoid.eccentricity_squared().sqrt();
+    let c = ((1. + e).powf(1. + e) * (1. - e).powf(1. - e)).sqrt();
+    2. * ellipsoid.semi_major_axis() * UPS_SCALE_FACTOR / c
+}
+
+/// Returns the UPS coordinates of the given point, on the projection of its
+/// hemisphere.
+fn ups_forward(point: &GeographicPoint) -> UtmPoint {
+    // see: Snyder, J. P. (1987). Map projections: A working manual, p. 161
+    let hemisphere = Hemisphere::of(point.latitude());
+    let sign = match hemisphere {
+        Hemisphere::North => 1.,
+        Hemisphere::South => -1.,
+    };
+
+    let eccentricity = Ellipsoid::WGS84.eccentricity_squared().sqrt();
+    let conformal = conformal_tangent((sign * point.latitude()).tan(), eccentricity);
+
+    // the tangent of the half conformal colatitude
+    let half = 1. / (conformal.hypot(1.) + conformal);
+
+    let rho = ups_scale() * half;
+    UtmPoint {
+        zone: 0,
+        hemisphere,
+        easting: UPS_FALSE_ORIGIN + rho * point.longitude().sin(),
+        northing: UPS_FALSE_ORIGIN - sign * rho * point.longitude().cos(),
+    }
+}
+
+/// Returns the [`GeographicPoint`] of the given UPS coordinates.
+fn ups_inverse(point: &UtmPoint) -> GeographicPoint {
+    let sign = match point.hemisphere {
+        Hemisphere::North => 1.,
+        Hemisphere::South => -1.,
+    };
+
+    let x = point.easting - UPS_FALSE_ORIGIN;
+    let y = point.northing - UPS_FALSE_ORIGIN;
+    let half = x.hypot(y) / ups_scale();
+    if half == 0. {
+        return GeographicPoint::default().with_latitude(sign * FRAC_PI_2);
+    }
+
+    let conformal = (1. - half.powi(2)) / (2. * half);
+    let eccentricity = Ellipsoid::WGS84.eccentricity_squared().sqrt();
+    let latitude = geodetic_tangent(conformal, eccentricity).atan();
+
+    GeographicPoint::default()
+        .with_longitude(x.atan2(-sign * y))
+        .with_latitude(sign * latitude)
+}
+
+/// Returns the standard zone of the given point, being 0 for UPS.
+fn standard_zone(point: &GeographicPoint) -> u8 {
+    let latitude = point.latitude().to_degrees();
+    let longitude = point.longitude().to_degrees();
+    if !(-80. ..84.).contains(&latitude) {
+        return 0;
+    }
+
+    let zone = ((longitude + 180.) / 6.).floor() as i32 % 60 + 1;
+    if (56. ..64.).contains(&latitude) && (3. ..12.).contains(&longitude) {
+        // southwestern Norway
+        return 32;
+    }
+
+    if latitude >= 72. && (0. ..42.).contains(&longitude) {
+        // Svalbard only has odd zones, from 31 to 37
+        return match longitude {
+            longitude if longitude < 9. => 31,
+            longitude if longitude < 21. => 33,
+            longitude if longitude < 33. => 35,
+            _ => 37,
+        };
+    }
+
+    zone as u8
+}
+
+/// Returns the longitude (in radiants) of the central meridian of the given
+/// UTM zone.
+fn central_meridian(zone: u8) -> f64 {
+    (6. * zone as f64 - 183.).to_radians()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn from_geographic_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            zone: u8,
+            hemisphere: Hemisphere,
+            easting: f64,
+            northing: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "origin of coordinates",
+                point: from_degrees(0., 0.),
+                zone: 31,
+                hemisphere: Hemisphere::North,
+                easting: 166_021.443,
+                northing: 0.,
+            },
+            TestCase {
+                name: "central meridian",
+                point: from_degrees(3., 45.),
+                zone: 31,
+                hemisphere: Hemisphere::North,
+                easting: 500_000.,
+                northing: 4_982_950.400,
+            },
+            TestCase {
+                name: "southern hemisphere",
+                point: from_degrees(21., -45.),
+                zone: 34,
+                hemisphere: Hemisphere::South,
+                easting: 500_000.,
+                northing: 10_000_000. - 4_982_950.400,
+            },
+            TestCase {
+                name: "antimeridian",
+                point: from_degrees(180., 0.),
+                zone: 1,
+                hemisphere: Hemisphere::North,
+                easting: 166_021.443,
+                northing: 0.,
+            },
+            TestCase {
+                name: "southwestern norway",
+                point: from_degrees(9., 60.),
+                zone: 32,
+                hemisphere: Hemisphere::North,
+                easting: 500_000.,
+                northing: UTM_SCALE_FACTOR * 6_654_072.820,
+            },
+            TestCase {
+                name: "svalbard",
+                point: from_degrees(15., 78.),
+                zone: 33,
+                hemisphere: Hemisphere::North,
+                easting: 500_000.,
+                northing: UTM_SCALE_FACTOR * 8_661_834.320,
+            },
+            TestCase {
+                name: "north pole",
+                point: from_degrees(0., 90.),
+                zone: 0,
+                hemisphere: Hemisphere::North,
+                easting: 2_000_000.,
+                northing: 2_000_000.,
+            },
+            TestCase {
+                name: "northern ups",
+                point: from_degrees(90., 85.),
+                zone: 0,
+                hemisphere: Hemisphere::North,
+                easting: 2_555_457.391,
+                northing: 2_000_000.,
+            },
+            TestCase {
+                name: "southern ups",
+                point: from_degrees(-45., -87.),
+                zone: 0,
+                hemisphere: Hemisphere::South,
+                easting: 1_764_431.275,
+                northing: 2_235_568.725,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let point = UtmPoint::from_geographic(&test_case.point);
+            assert_eq!(point.zone(), test_case.zone, "{}: zone", test_case.name);
+            assert_eq!(
+                point.hemisphere(),
+                test_case.hemisphere,
+                "{}: hemisphere",
+                test_case.name
+            );
+
+            assert!(
+                approx_eq!(f64, point.easting(), test_case.easting, epsilon = 1e-3)
+                    && approx_eq!(f64, point.northing(), test_case.northing, epsilon = 1e-3),
+                "{}: got {} {}, want {} {}",
+                test_case.name,
+                point.easting(),
+                point.northing(),
+                test_case.easting,
+                test_case.northing
+            );
+        });
+    }
+
+    #[test]
+    fn to_geographic_must_revert_from_geographic() {
+        // a millimeter is about 1.6e-10 radiants on the surface of the earth
+        const TOLERANCE: f64 = 1e-11;
+
+        (-90..=90).step_by(3).for_each(|latitude| {
+            (-180..180).step_by(7).for_each(|longitude| {
+                let point = from_degrees(longitude as f64, latitude as f64);
+                let projected = UtmPoint::from_geographic(&point);
+                let reverted = projected.to_geographic();
+
+                assert!(
+                    approx_eq!(f64, reverted.distance(&point), 0., epsilon = TOLERANCE),
+                    "{point:?} was reverted into {reverted:?} from {projected:?}"
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn from_geographic_in_zone_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            zone: u8,
+            output: Result<(u8, Hemisphere), UtmError>,
+        }
+
+        vec![
+            TestCase {
+                name: "neighbour zone",
+                point: from_degrees(5.9, 40.),
+                zone: 32,
+                output: Ok((32, Hemisphere::North)),
+            },
+            TestCase {
+                name: "standard zone in norway",
+                point: from_degrees(5., 60.),
+                zone: 31,
+                output: Ok((31, Hemisphere::North)),
+            },
+            TestCase {
+                name: "ups out of the polar regions",
+                point: from_degrees(0., 82.),
+                zone: 0,
+                output: Ok((0, Hemisphere::North)),
+            },
+            TestCase {
+                name: "ups close to the equator",
+                point: from_degrees(0., 10.),
+                zone: 0,
+                output: Err(UtmError::OutOfZone(0)),
+            },
+            TestCase {
+                name: "far away zone",
+                point: from_degrees(5.9, 40.),
+                zone: 40,
+                output: Err(UtmError::OutOfZone(40)),
+            },
+            TestCase {
+                name: "opposite zone",
+                point: from_degrees(0., 0.),
+                zone: 1,
+                output: Err(UtmError::OutOfZone(1)),
+            },
+            TestCase {
+                name: "invalid zone",
+                point: from_degrees(0., 0.),
+                zone: 61,
+                output: Err(UtmError::InvalidZone(61)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let output = UtmPoint::from_geographic_in_zone(&test_case.point, test_case.zone);
+            assert_eq!(
+                output
+                    .as_ref()
+                    .map(|point| (point.zone(), point.hemisphere()))
+                    .map_err(Clone::clone),
+                test_case.output,
+                "{}",
+                test_case.name
+            );
+
+            if let Ok(point) = output {
+                let reverted = point.to_geographic();
+                assert!(
+                    approx_eq!(
+                        f64,
+                        reverted.distance(&test_case.point),
+                        0.,
+                        epsilon = 1e-11
+                    ),
+                    "{}: {:?} was reverted into {:?}",
+                    test_case.name,
+                    test_case.point,
+                    reverted
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn new_must_not_fail() {
+        assert!(UtmPoint::new(31, Hemisphere::North, 500_000., 4_000_000.).is_ok());
+        assert_eq!(
+            UtmPoint::new(61, Hemisphere::North, 500_000., 4_000_000.),
+            Err(UtmError::InvalidZone(61))
+        );
+
+        assert_eq!(
+            UtmPoint::new(31, Hemisphere::South, f64::NAN, 4_000_000.)
+                .map_err(|err| err.to_string()),
+            Err("invalid coordinates NaN 4000000 for the given zone".to_string())
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{GeoBoundingBox, GeographicPoint};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The radius (in meters) of the sphere the Web Mercator projection is defined
+/// on, which is the semi-major axis of the WGS84 ellipsoid.
+pub const WEB_MERCATOR_RADIUS: f64 = 6_378_137.;
+
+/// The largest absolute latitude (in radiants) of the Web Mercator projection,
+/// about 85.0511°, which makes the projected world a square.
+pub const MAX_WEB_MERCATOR_LATITUDE: f64 = 1.4844222297453324;
+
+/// The largest zoom level whose tile coordinates fit in 32 bits.
+pub const MAX_TILE_ZOOM: u8 = 31;
+
+/// Represents a point on the [Web Mercator](https://epsg.io/3857) projection
+/// (EPSG:3857), in meters from the intersection of the equator and the
+/// prime meridian, being the y axis positive northwards.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WebMercatorPoint {
+    x: f64,
+    y: f64,
+}
+
+#[wasm_bindgen]
+impl WebMercatorPoint {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the projection of the given [`GeographicPoint`], whose latitude
+    /// is clamped into the range __[-[`MAX_WEB_MERCATOR_LATITUDE`],
+    /// +[`MAX_WEB_MERCATOR_LATITUDE`]]__ beforehand.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, WebMercatorPoint};
+    /// use std::f64::consts::PI;
+    /// use float_cmp::approx_eq;
+    ///
+    /// let point = GeographicPoint::default().with_longitude(PI);
+    /// let projected = WebMercatorPoint::from_geographic(&point);
+    ///
+    /// assert!(approx_eq!(f64, projected.x(), 20037508.342789244, ulps = 2));
+    /// ```
+    pub fn from_geographic(point: &GeographicPoint) -> Self {
+        let latitude = point
+            .latitude()
+            .clamp(-MAX_WEB_MERCATOR_LATITUDE, MAX_WEB_MERCATOR_LATITUDE);
+
+        Self {
+            x: WEB_MERCATOR_RADIUS * point.longitude(),
+            y: WEB_MERCATOR_RADIUS * (FRAC_PI_4 + latitude / 2.).tan().ln(),
+        }
+    }
+
+    /// Returns the [`GeographicPoint`] projected onto self, with no altitude.
+    pub fn to_geographic(&self) -> GeographicPoint {
+        GeographicPoint::default()
+            .with_longitude(self.x / WEB_MERCATOR_RADIUS)
+            .with_latitude(2. * (self.y / WEB_MERCATOR_RADIUS).exp().atan() - FRAC_PI_2)
+    }
+
+    /// Returns the easting (in meters) of the point.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the northing (in meters) of the point.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// Represents a pixel, given by its coordinates from the top-left corner of a
+/// tile, being the y axis positive southwards.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Pixel {
+    x: f64,
+    y: f64,
+}
+
+#[wasm_bindgen]
+impl Pixel {
+    /// Returns the horizontal coordinate of the pixel.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the vertical coordinate of the pixel.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// Represents a tile of the
+/// [XYZ tiling scheme](https://wiki.openstreetmap.org/wiki/Slippy_map_tilenames)
+/// of the Web Mercator projection: at each zoom level the projected world is
+/// split into a grid of 2^zoom by 2^zoom tiles, counted from the north-west
+/// corner.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    x: u32,
+    y: u32,
+    zoom: u8,
+}
+
+#[wasm_bindgen]
+impl Tile {
+    /// Returns the tile at the given coordinates and zoom level, if any.
+    pub fn new(x: u32, y: u32, zoom: u8) -> Option<Tile> {
+        (zoom <= MAX_TILE_ZOOM && x < 1 << zoom && y < 1 << zoom).then_some(Self { x, y, zoom })
+    }
+
+    /// Returns the tile of the given zoom level containing the given point,
+    /// whose latitude is clamped into the range of the projection. The zoom
+    /// level is clamped to [`MAX_TILE_ZOOM`].
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Tile};
+    ///
+    /// let berlin = GeographicPoint::default()
+    ///     .with_longitude(13.405_f64.to_radians())
+    ///     .with_latitude(52.52_f64.to_radians());
+    ///
+    /// let tile = Tile::from_geographic(&berlin, 10);
+    /// assert_eq!((tile.x(), tile.y()), (550, 335));
+    /// ```
+    pub fn from_geographic(point: &GeographicPoint, zoom: u8) -> Tile {
+        let zoom = zoom.min(MAX_TILE_ZOOM);
+        let size = (1_u64 << zoom) as f64;
+        let (x, y) = world_coordinates(point);
+
+        let clamp = |value: f64| (value * size).floor().clamp(0., size - 1.) as u32;
+        Self {
+            x: clamp(x),
+            y: clamp(y),
+            zoom,
+        }
+    }
+
+    /// Returns the tile with the given [Bing Maps quadkey](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system),
+    /// if valid.
+    pub fn from_quadkey(quadkey: &str) -> Option<Tile> {
+        if quadkey.len() > MAX_TILE_ZOOM as usize {
+            return None;
+        }
+
+        quadkey.chars().try_fold(Self::default(), |tile, digit| {
+            let digit = digit.to_digit(4)?;
+            Some(Self {
+                x: tile.x << 1 | (digit & 1),
+                y: tile.y << 1 | (digit >> 1),
+                zoom: tile.zoom + 1,
+            })
+        })
+    }
+
+    /// Returns the column of the tile, from west to east.
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Returns the row of the tile, from north to south.
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Returns the zoom level of the tile.
+    pub fn zoom(&self) -> u8 {
+        self.zoom
+    }
+
+    /// Returns the Bing Maps quadkey of the tile.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::Tile;
+    ///
+    /// assert_eq!(Tile::new(3, 5, 3).unwrap().quadkey(), "213");
+    /// ```
+    pub fn quadkey(&self) -> String {
+        (0..self.zoom)
+            .rev()
+            .map(|bit| {
+                let digit = (self.x >> bit & 1) | (self.y >> bit & 1) << 1;
+                char::from(b'0' + digit as u8)
+            })
+            .collect()
+    }
+
+    /// Returns the region of the sphere covered by the tile.
+    pub fn bounding_box(&self) -> GeoBoundingBox {
+        let size = (1_u64 << self.zoom) as f64;
+        let longitude = |x: u32| x as f64 / size * 2. * PI - PI;
+        let latitude = |y: u32| (PI * (1. - 2. * y as f64 / size)).sinh().atan();
+
+        GeoBoundingBox::new(
+            longitude(self.x),
+            latitude(self.y + 1),
+            longitude(self.x + 1),
+            latitude(self.y),
+        )
+    }
+
+    /// Returns the pixel of the given point on a tile of the given size (in
+    /// pixels), relative to the top-left corner of self. Points outside the
+    /// tile have coordinates out of the range __[0, size)__.
+    pub fn pixel(&self, point: &GeographicPoint, tile_size: u32) -> Pixel {
+        let scale = (1_u64 << self.zoom) as f64 * tile_size as f64;
+        let (x, y) = world_coordinates(point);
+
+        Pixel {
+            x: x * scale - self.x as f64 * tile_size as f64,
+            y: y * scale - self.y as f64 * tile_size as f64,
+        }
+    }
+}
+
+/// Returns the coordinates of the given point on the projected world, scaled
+/// into the range __[0, 1]__ from its north-west corner.
+fn world_coordinates(point: &GeographicPoint) -> (f64, f64) {
+    let projected = WebMercatorPoint::from_geographic(point);
+    let half_width = WEB_MERCATOR_RADIUS * PI;
+
+    (
+        (projected.x + half_width) / (2. * half_width),
+        (half_width - projected.y) / (2. * half_width),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn projection_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            x: f64,
+            y: f64,
+        }
+
+        vec![
+            TestCase {
+                name: "origin of coordinates",
+                point: from_degrees(0., 0.),
+                x: 0.,
+                y: 0.,
+            },
+            TestCase {
+                name: "madrid",
+                point: from_degrees(-3.70379, 40.41678),
+                x: -412_304.02,
+                y: 4_926_693.75,
+            },
+            TestCase {
+                name: "north east corner",
+                point: from_degrees(180., MAX_WEB_MERCATOR_LATITUDE.to_degrees()),
+                x: 20_037_508.342789244,
+                y: 20_037_508.342789244,
+            },
+            TestCase {
+                name: "north pole must be clamped",
+                point: from_degrees(0., 90.),
+                x: 0.,
+                y: 20_037_508.342789244,
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let projected = WebMercatorPoint::from_geographic(&test_case.point);
+            assert!(
+                approx_eq!(f64, projected.x(), test_case.x, epsilon = 1e-2),
+                "{}: x {} ±ε = {}",
+                test_case.name,
+                projected.x(),
+                test_case.x
+            );
+
+            assert!(
+                approx_eq!(f64, projected.y(), test_case.y, epsilon = 1e-2),
+                "{}: y {} ±ε = {}",
+                test_case.name,
+                projected.y(),
+                test_case.y
+            );
+
+            let latitude = test_case
+                .point
+                .latitude()
+                .clamp(-MAX_WEB_MERCATOR_LATITUDE, MAX_WEB_MERCATOR_LATITUDE);
+
+            let unprojected = projected.to_geographic();
+            let want = test_case.point.with_latitude(latitude);
+            assert!(
+                approx_eq!(f64, unprojected.distance(&want), 0., epsilon = 1e-12),
+                "{}: {:?} must be reverted into {:?}",
+                test_case.name,
+                unprojected,
+                want
+            );
+        });
+    }
+
+    #[test]
+    fn tile_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            point: GeographicPoint,
+            zoom: u8,
+            tile: (u32, u32),
+            quadkey: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "whole world",
+                point: from_degrees(-3.70379, 40.41678),
+                zoom: 0,
+                tile: (0, 0),
+                quadkey: "",
+            },
+            TestCase {
+                name: "madrid",
+                point: from_degrees(-3.70379, 40.41678),
+                zoom: 12,
+                tile: (2005, 1544),
+                quadkey: "033111012101",
+            },
+            TestCase {
+                name: "south east corner",
+                point: from_degrees(180., -90.),
+                zoom: 4,
+                tile: (15, 15),
+                quadkey: "3333",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let tile = Tile::from_geographic(&test_case.point, test_case.zoom);
+            assert_eq!((tile.x(), tile.y()), test_case.tile, "{}", test_case.name);
+            assert_eq!(tile.quadkey(), test_case.quadkey, "{}", test_case.name);
+            assert_eq!(
+                Tile::from_quadkey(test_case.quadkey),
+                Some(tile),
+                "{}: from quadkey",
+                test_case.name
+            );
+
+            let latitude = test_case
+                .point
+                .latitude()
+                .clamp(-MAX_WEB_MERCATOR_LATITUDE, MAX_WEB_MERCATOR_LATITUDE);
+
+            assert!(
+                tile.bounding_box()
+                    .contains(&test_case.point.with_latitude(latitude)),
+                "{}: bounding box {:?}",
+                test_case.name,
+                tile.bounding_box()
+            );
+
+            let pixel = tile.pixel(&test_case.point, 256);
+            assert!(
+                [pixel.x(), pixel.y()]
+                    .iter()
+                    .all(|coordinate| (-1e-9..=256. + 1e-9).contains(coordinate)),
+                "{}: pixel {:?}",
+                test_case.name,
+                pixel
+            );
+        });
+
+        assert_eq!(Tile::new(4, 0, 2), None, "column out of range");
+        assert_eq!(Tile::from_quadkey("0124"), None, "invalid digit");
+    }
+}
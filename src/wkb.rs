@@ -0,0 +1,691 @@
+use crate::{geometry::point_from_degrees, GeographicPoint, Geometry};
+use std::fmt::{self, Write};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The type codes of each geometry.
+const POINT: u32 = 1;
+const LINESTRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTIPOINT: u32 = 4;
+const MULTILINESTRING: u32 = 5;
+const MULTIPOLYGON: u32 = 6;
+
+/// The flags of the type code of an EWKB geometry.
+const EWKB_ALTITUDE: u32 = 0x8000_0000;
+const EWKB_MEASURE: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Represents an error while decoding a WKB buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WkbError {
+    /// The buffer ended while reading the value at the given offset.
+    UnexpectedEnd(usize),
+    /// The byte order at the given offset is neither 0 nor 1.
+    InvalidByteOrder(u8, usize),
+    /// The type code at the given offset is not supported.
+    UnknownGeometry(u32, usize),
+    /// The type code at the given offset is not the one of the members of its
+    /// multi-geometry.
+    UnexpectedGeometry(u32, usize),
+    /// The position at the given offset is out of range.
+    OutOfRange(usize),
+    /// The point at the given offset is empty, as written by PostGIS with
+    /// both coordinates being NaN, which is not supported.
+    EmptyPoint(usize),
+    /// The buffer has bytes after the geometry, from the given offset.
+    TrailingBytes(usize),
+    /// The character at the given position is not hexadecimal, or the
+    /// string has an odd length.
+    InvalidHex(usize),
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEnd(offset) => {
+                write!(f, "unexpected end of buffer at offset {offset}")
+            }
+            WkbError::InvalidByteOrder(order, offset) => {
+                write!(f, "invalid byte order {order} at offset {offset}")
+            }
+            WkbError::UnknownGeometry(code, offset) => {
+                write!(f, "unknown geometry type {code} at offset {offset}")
+            }
+            WkbError::UnexpectedGeometry(code, offset) => {
+                write!(f, "unexpected geometry type {code} at offset {offset}")
+            }
+            WkbError::OutOfRange(offset) => {
+                write!(f, "coordinates out of range at offset {offset}")
+            }
+            WkbError::EmptyPoint(offset) => {
+                write!(f, "unsupported empty point at offset {offset}")
+            }
+            WkbError::TrailingBytes(offset) => {
+                write!(f, "unexpected bytes after the geometry at offset {offset}")
+            }
+            WkbError::InvalidHex(position) => {
+                write!(f, "invalid hexadecimal digit at position {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WkbError {}
+
+/// Represents the order of the bytes of each number.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Most significant byte first, also known as XDR.
+    BigEndian,
+    /// Least significant byte first, also known as NDR.
+    #[default]
+    LittleEndian,
+}
+
+/// Represents the way geometries are written as
+/// [Well-Known Binary](https://www.ogc.org/standard/sfa/), or as the extended
+/// variant of PostGIS when tagged with a spatial reference identifier (SRID).
+///
+/// Positions are written as longitude and latitude in degrees, optionally
+/// followed by the altitude. Both ISO and extended type codes of any dimension
+/// are accepted when decoding, measures being ignored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Wkb {
+    byte_order: ByteOrder,
+    altitude: bool,
+    srid: Option<u32>,
+}
+
+impl Wkb {
+    /// Sets the byte order of the numbers.
+    pub fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Sets whether positions include the altitude, as a Z coordinate.
+    pub fn with_altitude(mut self, altitude: bool) -> Self {
+        self.altitude = altitude;
+        self
+    }
+
+    /// Sets the spatial reference identifier of the geometries, which makes
+    /// them extended WKB (EWKB).
+    pub fn with_srid(mut self, srid: u32) -> Self {
+        self.srid = Some(srid);
+        self
+    }
+
+    /// Returns the given geometry as WKB.
+    pub fn encode(&self, geometry: &Geometry) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_geometry(&mut bytes, geometry);
+        bytes
+    }
+
+    /// Returns the given geometry as WKB, in hexadecimal.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Geometry, Wkb};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(1_f64.to_radians())
+    ///     .with_latitude(2_f64.to_radians());
+    ///
+    /// let ewkb = Wkb::default().with_srid(4326).encode_hex(&Geometry::Point(point));
+    /// assert_eq!(ewkb, "0101000020E6100000000000000000F03F0000000000000040");
+    /// ```
+    pub fn encode_hex(&self, geometry: &Geometry) -> String {
+        self.encode(geometry)
+            .into_iter()
+            .fold(String::new(), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02X}");
+                hex
+            })
+    }
+
+    /// Returns the geometry in the given WKB buffer, and its spatial
+    /// reference identifier, if any.
+    pub fn decode(bytes: &[u8]) -> Result<(Geometry, Option<u32>), WkbError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        let (geometry, srid) = reader.geometry()?;
+        if reader.offset < bytes.len() {
+            return Err(WkbError::TrailingBytes(reader.offset));
+        }
+
+        Ok((geometry, srid))
+    }
+
+    /// Returns the geometry in the given hexadecimal WKB string, and its
+    /// spatial reference identifier, if any. Positions of
+    /// [`WkbError::InvalidHex`] are in characters of the string, while
+    /// offsets of any other error are in bytes of the decoded buffer.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{Geometry, Wkb};
+    /// use float_cmp::approx_eq;
+    ///
+    /// let (geometry, srid) = Wkb::decode_hex("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+    /// let Geometry::Point(point) = geometry else {
+    ///     panic!("must be a point");
+    /// };
+    ///
+    /// assert_eq!(srid, Some(4326));
+    /// assert!(approx_eq!(f64, point.latitude().to_degrees(), 2., epsilon = 1e-12));
+    /// ```
+    pub fn decode_hex(value: &str) -> Result<(Geometry, Option<u32>), WkbError> {
+        if !value.len().is_multiple_of(2) {
+            return Err(WkbError::InvalidHex(value.len()));
+        }
+
+        let bytes = value
+            .as_bytes()
+            .chunks(2)
+            .enumerate()
+            .map(|(index, pair)| {
+                let digit = |position: usize| {
+                    (pair[position] as char)
+                        .to_digit(16)
+                        .ok_or(WkbError::InvalidHex(index * 2 + position))
+                };
+
+                Ok((digit(0)? * 16 + digit(1)?) as u8)
+            })
+            .collect::<Result<Vec<u8>, WkbError>>()?;
+
+        Self::decode(&bytes)
+    }
+
+    fn write_geometry(&self, bytes: &mut Vec<u8>, geometry: &Geometry) {
+        let srid = self.srid;
+        match geometry {
+            Geometry::Point(point) => {
+                self.write_header(bytes, POINT, srid);
+                self.write_point(bytes, point);
+            }
+            Geometry::LineString(points) => {
+                self.write_header(bytes, LINESTRING, srid);
+                self.write_points(bytes, points);
+            }
+            Geometry::Polygon(rings) => {
+                self.write_header(bytes, POLYGON, srid);
+                self.write_rings(bytes, rings);
+            }
+            Geometry::MultiPoint(points) => {
+                self.write_header(bytes, MULTIPOINT, srid);
+                self.write_u32(bytes, points.len() as u32);
+                points.iter().for_each(|point| {
+                    self.write_header(bytes, POINT, None);
+                    self.write_point(bytes, point);
+                });
+            }
+            Geometry::MultiLineString(lines) => {
+                self.write_header(bytes, MULTILINESTRING, srid);
+                self.write_u32(bytes, lines.len() as u32);
+                lines.iter().for_each(|points| {
+                    self.write_header(bytes, LINESTRING, None);
+                    self.write_points(bytes, points);
+                });
+            }
+            Geometry::MultiPolygon(polygons) => {
+                self.write_header(bytes, MULTIPOLYGON, srid);
+                self.write_u32(bytes, polygons.len() as u32);
+                polygons.iter().for_each(|rings| {
+                    self.write_header(bytes, POLYGON, None);
+                    self.write_rings(bytes, rings);
+                });
+            }
+        }
+    }
+
+    /// Writes the byte order and type code of a geometry of the given kind.
+    /// Only the outermost geometry is tagged with the SRID.
+    fn write_header(&self, bytes: &mut Vec<u8>, kind: u32, srid: Option<u32>) {
+        bytes.push(match self.byte_order {
+            ByteOrder::BigEndian => 0,
+            ByteOrder::LittleEndian => 1,
+        });
+
+        // extended type codes are flags, while ISO ones are offsets
+        let mut code = kind;
+        if self.srid.is_none() {
+            code += 1000 * self.altitude as u32;
+        } else if self.altitude {
+            code |= EWKB_ALTITUDE;
+        }
+
+        if srid.is_some() {
+            code |= EWKB_SRID;
+        }
+
+        self.write_u32(bytes, code);
+        if let Some(srid) = srid {
+            self.write_u32(bytes, srid);
+        }
+    }
+
+    fn write_rings(&self, bytes: &mut Vec<u8>, rings: &[Vec<GeographicPoint>]) {
+        self.write_u32(bytes, rings.len() as u32);
+        rings.iter().for_each(|ring| self.write_points(bytes, ring));
+    }
+
+    fn write_points(&self, bytes: &mut Vec<u8>, points: &[GeographicPoint]) {
+        self.write_u32(bytes, points.len() as u32);
+        points
+            .iter()
+            .for_each(|point| self.write_point(bytes, point));
+    }
+
+    fn write_point(&self, bytes: &mut Vec<u8>, point: &GeographicPoint) {
+        self.write_f64(bytes, point.longitude().to_degrees());
+        self.write_f64(bytes, point.latitude().to_degrees());
+        if self.altitude {
+            self.write_f64(bytes, point.altitude());
+        }
+    }
+
+    fn write_u32(&self, bytes: &mut Vec<u8>, value: u32) {
+        bytes.extend(match self.byte_order {
+            ByteOrder::BigEndian => value.to_be_bytes(),
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+        });
+    }
+
+    fn write_f64(&self, bytes: &mut Vec<u8>, value: f64) {
+        bytes.extend(match self.byte_order {
+            ByteOrder::BigEndian => value.to_be_bytes(),
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+        });
+    }
+}
+
+/// Represents the layout of the positions of a geometry.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    byte_order: ByteOrder,
+    altitude: bool,
+    measure: bool,
+}
+
+/// A cursor over a WKB buffer.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    /// Returns the geometry at the current offset, and its SRID, if any.
+    fn geometry(&mut self) -> Result<(Geometry, Option<u32>), WkbError> {
+        let (kind, layout, srid) = self.header(None)?;
+        let geometry = match kind {
+            POINT => Geometry::Point(self.point(layout)?),
+            LINESTRING => Geometry::LineString(self.points(layout)?),
+            POLYGON => Geometry::Polygon(self.rings(layout)?),
+            MULTIPOINT => Geometry::MultiPoint(self.list(layout, |reader| {
+                let (_, layout, _) = reader.header(Some(POINT))?;
+                reader.point(layout)
+            })?),
+            MULTILINESTRING => Geometry::MultiLineString(self.list(layout, |reader| {
+                let (_, layout, _) = reader.header(Some(LINESTRING))?;
+                reader.points(layout)
+            })?),
+            _ => Geometry::MultiPolygon(self.list(layout, |reader| {
+                let (_, layout, _) = reader.header(Some(POLYGON))?;
+                reader.rings(layout)
+            })?),
+        };
+
+        Ok((geometry, srid))
+    }
+
+    /// Returns the kind, layout and SRID of the geometry at the current
+    /// offset, whose kind must be the given one, if any.
+    fn header(&mut self, expected: Option<u32>) -> Result<(u32, Layout, Option<u32>), WkbError> {
+        let start = self.offset;
+        let byte_order = match self.take::<1>()?[0] {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            order => return Err(WkbError::InvalidByteOrder(order, start)),
+        };
+
+        let code_offset = self.offset;
+        let code = self.u32(byte_order)?;
+        let iso = code & 0x0FFF_FFFF;
+        let (kind, dimension) = (iso % 1000, iso / 1000);
+        if !(POINT..=MULTIPOLYGON).contains(&kind) || dimension > 3 {
+            return Err(WkbError::UnknownGeometry(code, code_offset));
+        }
+
+        if expected.is_some_and(|expected| expected != kind) {
+            return Err(WkbError::UnexpectedGeometry(code, code_offset));
+        }
+
+        let srid = if code & EWKB_SRID != 0 {
+            Some(self.u32(byte_order)?)
+        } else {
+            None
+        };
+
+        let layout = Layout {
+            byte_order,
+            altitude: code & EWKB_ALTITUDE != 0 || dimension & 1 != 0,
+            measure: code & EWKB_MEASURE != 0 || dimension & 2 != 0,
+        };
+
+        Ok((kind, layout, srid))
+    }
+
+    fn rings(&mut self, layout: Layout) -> Result<Vec<Vec<GeographicPoint>>, WkbError> {
+        self.list(layout, |reader| reader.points(layout))
+    }
+
+    fn points(&mut self, layout: Layout) -> Result<Vec<GeographicPoint>, WkbError> {
+        self.list(layout, |reader| reader.point(layout))
+    }
+
+    /// Returns as many items as the count at the current offset says.
+    fn list<T>(
+        &mut self,
+        layout: Layout,
+        mut item: impl FnMut(&mut Self) -> Result<T, WkbError>,
+    ) -> Result<Vec<T>, WkbError> {
+        let count = self.u32(layout.byte_order)?;
+        // the count is not trusted for preallocating, since the buffer may be
+        // truncated or malicious
+        (0..count).map(|_| item(self)).collect()
+    }
+
+    fn point(&mut self, layout: Layout) -> Result<GeographicPoint, WkbError> {
+        let start = self.offset;
+        let longitude = self.f64(layout.byte_order)?;
+        let latitude = self.f64(layout.byte_order)?;
+        let altitude = if layout.altitude {
+            self.f64(layout.byte_order)?
+        } else {
+            0.
+        };
+
+        if layout.measure {
+            self.f64(layout.byte_order)?;
+        }
+
+        if longitude.is_nan() && latitude.is_nan() {
+            return Err(WkbError::EmptyPoint(start));
+        }
+
+        point_from_degrees(longitude, latitude, altitude).ok_or(WkbError::OutOfRange(start))
+    }
+
+    fn u32(&mut self, byte_order: ByteOrder) -> Result<u32, WkbError> {
+        let bytes = self.take()?;
+        Ok(match byte_order {
+            ByteOrder::BigEndian => u32::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self, byte_order: ByteOrder) -> Result<f64, WkbError> {
+        let bytes = self.take()?;
+        Ok(match byte_order {
+            ByteOrder::BigEndian => f64::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => f64::from_le_bytes(bytes),
+        })
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + N)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(WkbError::UnexpectedEnd(self.offset))?;
+
+        self.offset += N;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn encode_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            format: Wkb,
+            geometry: Geometry,
+            output: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "point",
+                format: Wkb::default(),
+                geometry: Geometry::Point(from_degrees(1., 2.)),
+                output: "0101000000000000000000F03F0000000000000040",
+            },
+            TestCase {
+                name: "big endian point",
+                format: Wkb::default().with_byte_order(ByteOrder::BigEndian),
+                geometry: Geometry::Point(from_degrees(1., 2.)),
+                output: "00000000013FF00000000000004000000000000000",
+            },
+            TestCase {
+                name: "iso point with altitude",
+                format: Wkb::default().with_altitude(true),
+                geometry: Geometry::Point(from_degrees(1., 2.).with_altitude(3.)),
+                output: "01E9030000000000000000F03F00000000000000400000000000000840",
+            },
+            TestCase {
+                name: "extended point with altitude",
+                format: Wkb::default().with_altitude(true).with_srid(4326),
+                geometry: Geometry::Point(from_degrees(1., 2.).with_altitude(3.)),
+                output: "01010000A0E6100000000000000000F03F00000000000000400000000000000840",
+            },
+            TestCase {
+                name: "extended multipoint",
+                format: Wkb::default().with_srid(4326),
+                geometry: Geometry::MultiPoint(vec![from_degrees(1., 2.)]),
+                output: "0104000020E6100000010000000101000000000000000000F03F0000000000000040",
+            },
+            TestCase {
+                name: "empty polygon",
+                format: Wkb::default(),
+                geometry: Geometry::Polygon(Vec::new()),
+                output: "010300000000000000",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.format.encode_hex(&test_case.geometry),
+                test_case.output,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn encode_and_decode_must_round_trip() {
+        let ring = vec![
+            from_degrees(0., 0.).with_altitude(1.),
+            from_degrees(10., 0.).with_altitude(2.),
+            from_degrees(0., 10.).with_altitude(3.),
+            from_degrees(0., 0.).with_altitude(1.),
+        ];
+
+        let geometries = [
+            Geometry::Point(from_degrees(-179.5, 89.5).with_altitude(-10.)),
+            Geometry::LineString(ring.clone()),
+            Geometry::Polygon(vec![ring.clone(), ring.clone()]),
+            Geometry::MultiPoint(ring.clone()),
+            Geometry::MultiLineString(vec![ring.clone(), Vec::new()]),
+            Geometry::MultiPolygon(vec![vec![ring.clone()], vec![ring]]),
+        ];
+
+        let formats = [
+            Wkb::default(),
+            Wkb::default().with_altitude(true),
+            Wkb::default()
+                .with_byte_order(ByteOrder::BigEndian)
+                .with_srid(3857),
+            Wkb::default().with_altitude(true).with_srid(4326),
+        ];
+
+        formats.into_iter().for_each(|format| {
+            geometries.iter().for_each(|geometry| {
+                let (decoded, srid) = Wkb::decode(&format.encode(geometry)).unwrap();
+                assert_eq!(decoded.kind(), geometry.kind(), "{format:?}");
+                assert_eq!(srid, format.srid, "{format:?}");
+
+                let (want, got) = (geometry.points(), decoded.points());
+                assert_eq!(want.len(), got.len(), "{format:?}");
+                want.iter().zip(got).for_each(|(want, got)| {
+                    let altitude = if format.altitude { want.altitude() } else { 0. };
+                    assert!(
+                        want.distance(&got) < 1e-12 && approx_eq!(f64, got.altitude(), altitude),
+                        "{format:?}: got {got:?}, want {want:?}"
+                    );
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn decode_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            points: Vec<(f64, f64, f64)>,
+        }
+
+        vec![
+            TestCase {
+                name: "measured point",
+                input: "01D1070000000000000000F03F00000000000000400000000000000840",
+                points: vec![(1., 2., 0.)],
+            },
+            TestCase {
+                name: "extended point with altitude and measure",
+                input: "01010000E0E6100000000000000000F03F000000000000004000000000000008400000000000001040",
+                points: vec![(1., 2., 3.)],
+            },
+            TestCase {
+                name: "lowercase hex",
+                input: "0101000000000000000000f03f0000000000000040",
+                points: vec![(1., 2., 0.)],
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let (geometry, _) = Wkb::decode_hex(test_case.input).unwrap();
+            let points = geometry.points();
+            assert_eq!(points.len(), test_case.points.len(), "{}", test_case.name);
+            points
+                .iter()
+                .zip(test_case.points)
+                .for_each(|(point, (longitude, latitude, altitude))| {
+                    assert!(
+                        approx_eq!(f64, point.longitude().to_degrees(), longitude, epsilon = 1e-12)
+                            && approx_eq!(f64, point.latitude().to_degrees(), latitude, epsilon = 1e-12)
+                            && approx_eq!(f64, point.altitude(), altitude),
+                        "{}: got {:?}",
+                        test_case.name,
+                        point
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn decode_errors_must_be_positioned() {
+        struct TestCase {
+            name: &'static str,
+            input: &'static str,
+            error: WkbError,
+        }
+
+        vec![
+            TestCase {
+                name: "empty",
+                input: "",
+                error: WkbError::UnexpectedEnd(0),
+            },
+            TestCase {
+                name: "invalid byte order",
+                input: "0201000000",
+                error: WkbError::InvalidByteOrder(2, 0),
+            },
+            TestCase {
+                name: "unknown geometry",
+                input: "0107000000",
+                error: WkbError::UnknownGeometry(7, 1),
+            },
+            TestCase {
+                name: "truncated point",
+                input: "0101000000000000000000F03F00000000",
+                error: WkbError::UnexpectedEnd(13),
+            },
+            TestCase {
+                name: "huge count",
+                input: "0102000000FFFFFFFF",
+                error: WkbError::UnexpectedEnd(9),
+            },
+            TestCase {
+                name: "unexpected member",
+                input: "0104000000010000000102000000",
+                error: WkbError::UnexpectedGeometry(2, 10),
+            },
+            TestCase {
+                name: "out of range",
+                input: "0101000000000000000000F03F0000000000C05640",
+                error: WkbError::OutOfRange(5),
+            },
+            TestCase {
+                name: "longitude out of range",
+                input: "01010000000000000000A06640000000000000F03F",
+                error: WkbError::OutOfRange(5),
+            },
+            TestCase {
+                name: "empty point",
+                input: "0101000000000000000000F87F000000000000F87F",
+                error: WkbError::EmptyPoint(5),
+            },
+            TestCase {
+                name: "empty member point",
+                input: "0104000000010000000101000000000000000000F87F000000000000F87F",
+                error: WkbError::EmptyPoint(14),
+            },
+            TestCase {
+                name: "trailing bytes",
+                input: "0101000000000000000000F03F000000000000004000",
+                error: WkbError::TrailingBytes(21),
+            },
+            TestCase {
+                name: "invalid hex",
+                input: "01G1",
+                error: WkbError::InvalidHex(2),
+            },
+            TestCase {
+                name: "odd hex",
+                input: "010",
+                error: WkbError::InvalidHex(3),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                Wkb::decode_hex(test_case.input),
+                Err(test_case.error),
+                "{}",
+                test_case.name
+            );
+        });
+    }
+}
@@ -0,0 +1,829 @@
+use crate::{
+    geometry::{format_number, point_from_degrees, TextReader, MAX_PRECISION},
+    GeographicPoint, Geometry,
+};
+use std::fmt::{self, Write};
+
+/// Represents an error while parsing a WKT string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WktError {
+    /// The string ended while expecting the given token.
+    UnexpectedEnd(&'static str),
+    /// The token at the given position is not the expected one.
+    UnexpectedToken(String, usize, &'static str),
+    /// The geometry type at the given position is not supported.
+    UnknownGeometry(String, usize),
+    /// The number at the given position is not valid.
+    InvalidNumber(String, usize),
+    /// The position at the given position has a different amount of
+    /// coordinates than the rest of the geometry.
+    InvalidDimension(usize, usize),
+    /// The position at the given position is out of range.
+    OutOfRange(usize),
+    /// The point at the given position is empty, which is not supported.
+    EmptyPoint(usize),
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WktError::UnexpectedEnd(expected) => {
+                write!(f, "expected {expected}, found end of text")
+            }
+            WktError::UnexpectedToken(token, position, expected) => write!(
+                f,
+                "expected {expected}, found {token:?} at position {position}"
+            ),
+            WktError::UnknownGeometry(kind, position) => {
+                write!(f, "unknown geometry type {kind:?} at position {position}")
+            }
+            WktError::InvalidNumber(number, position) => {
+                write!(f, "invalid number {number:?} at position {position}")
+            }
+            WktError::InvalidDimension(count, position) => write!(
+                f,
+                "unexpected amount of coordinates {count} at position {position}"
+            ),
+            WktError::OutOfRange(position) => {
+                write!(f, "coordinates out of range at position {position}")
+            }
+            WktError::EmptyPoint(position) => {
+                write!(f, "unsupported empty point at position {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// Represents the way geometries are written as
+/// [Well-Known Text](https://www.ogc.org/standard/sfa/).
+///
+/// Positions are written as longitude and latitude in degrees, optionally
+/// followed by the altitude. Any dimension is accepted when parsing, measures
+/// being ignored. Empty geometries and members are written and parsed as
+/// `EMPTY`, except for points, which cannot be empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wkt {
+    precision: usize,
+    altitude: bool,
+}
+
+impl Default for Wkt {
+    fn default() -> Self {
+        Self {
+            precision: 9,
+            altitude: false,
+        }
+    }
+}
+
+impl Wkt {
+    /// Sets the maximum amount of decimal places of the coordinates, up to 15.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(MAX_PRECISION);
+        self
+    }
+
+    /// Sets whether positions include the altitude, as a Z coordinate.
+    pub fn with_altitude(mut self, altitude: bool) -> Self {
+        self.altitude = altitude;
+        self
+    }
+
+    /// Returns the given geometry as WKT.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{GeographicPoint, Geometry, Wkt};
+    ///
+    /// let point = GeographicPoint::default()
+    ///     .with_longitude(2_f64.to_radians())
+    ///     .with_latitude(41.5_f64.to_radians())
+    ///     .with_altitude(12.);
+    ///
+    /// let wkt = Wkt::default().with_altitude(true).format(&Geometry::Point(point));
+    /// assert_eq!(wkt, "POINT Z (2 41.5 12)");
+    /// ```
+    pub fn format(&self, geometry: &Geometry) -> String {
+        let mut wkt = geometry.kind().to_uppercase();
+        if self.altitude {
+            wkt.push_str(" Z");
+        }
+
+        wkt.push(' ');
+        match geometry {
+            Geometry::Point(point) => self.write_points(&mut wkt, &[*point]),
+            Geometry::LineString(points) => self.write_points(&mut wkt, points),
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                self.write_lines(&mut wkt, rings)
+            }
+            Geometry::MultiPoint(points) => {
+                let points: Vec<_> = points.iter().map(|&point| vec![point]).collect();
+                self.write_lines(&mut wkt, &points)
+            }
+            Geometry::MultiPolygon(polygons) => write_list(&mut wkt, polygons, |wkt, rings| {
+                self.write_lines(wkt, rings)
+            }),
+        }
+
+        wkt
+    }
+
+    /// Returns the geometry in the given WKT string.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::{Geometry, Wkt};
+    /// use float_cmp::approx_eq;
+    ///
+    /// let Ok(Geometry::LineString(points)) = Wkt::parse("LINESTRING (30 10, 10 30, 40 40)") else {
+    ///     panic!("must be a linestring");
+    /// };
+    ///
+    /// assert_eq!(points.len(), 3);
+    /// assert!(approx_eq!(f64, points[1].latitude().to_degrees(), 30., epsilon = 1e-12));
+    /// ```
+    pub fn parse(value: &str) -> Result<Geometry, WktError> {
+        let mut parser = Parser {
+            text: value,
+            position: 0,
+            dimension: None,
+        };
+
+        let geometry = parser.geometry()?;
+        parser.skip_whitespace()?;
+        if parser.position < value.len() {
+            return Err(parser.unexpected("end of text"));
+        }
+
+        Ok(geometry)
+    }
+
+    fn write_points(&self, wkt: &mut String, points: &[GeographicPoint]) {
+        write_list(wkt, points, |wkt, point| {
+            let _ = write!(
+                wkt,
+                "{} {}",
+                format_number(point.longitude().to_degrees(), self.precision),
+                format_number(point.latitude().to_degrees(), self.precision)
+            );
+
+            if self.altitude {
+                let _ = write!(wkt, " {}", format_number(point.altitude(), self.precision));
+            }
+        })
+    }
+
+    fn write_lines(&self, wkt: &mut String, lines: &[Vec<GeographicPoint>]) {
+        write_list(wkt, lines, |wkt, points| self.write_points(wkt, points))
+    }
+}
+
+/// Writes the given items between parentheses and separated by commas, or
+/// `EMPTY` if there is none.
+fn write_list<T>(wkt: &mut String, items: &[T], mut write: impl FnMut(&mut String, &T)) {
+    if items.is_empty() {
+        wkt.push_str("EMPTY");
+        return;
+    }
+
+    wkt.push('(');
+    items.iter().enumerate().for_each(|(index, item)| {
+        if index > 0 {
+            wkt.push_str(", ");
+        }
+
+        write(wkt, item);
+    });
+
+    wkt.push(')');
+}
+
+/// Returns true if, and only if, the given byte may be part of a number.
+fn is_numeric(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'+')
+}
+
+/// Represents the coordinates of each position of a geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Dimension {
+    altitude: bool,
+    measure: bool,
+}
+
+impl Dimension {
+    fn count(&self) -> usize {
+        2 + self.altitude as usize + self.measure as usize
+    }
+}
+
+/// A recursive descent parser over a WKT string.
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    dimension: Option<Dimension>,
+}
+
+impl<'a> Parser<'a> {
+    fn geometry(&mut self) -> Result<Geometry, WktError> {
+        self.skip_whitespace()?;
+        let start = self.position;
+        let kind = self
+            .word()
+            .ok_or_else(|| self.unexpected("geometry type"))?;
+
+        let kind = kind.to_ascii_uppercase();
+        if !matches!(
+            kind.as_str(),
+            "POINT" | "LINESTRING" | "POLYGON" | "MULTIPOINT" | "MULTILINESTRING" | "MULTIPOLYGON"
+        ) {
+            return Err(WktError::UnknownGeometry(kind, start));
+        }
+
+        self.skip_whitespace()?;
+        let mut word_start = self.position;
+        let mut word = self.word().map(str::to_ascii_uppercase);
+        self.dimension = match word.as_deref() {
+            Some("Z") => Some(Dimension {
+                altitude: true,
+                measure: false,
+            }),
+            Some("M") => Some(Dimension {
+                altitude: false,
+                measure: true,
+            }),
+            Some("ZM") => Some(Dimension {
+                altitude: true,
+                measure: true,
+            }),
+            _ => None,
+        };
+
+        if self.dimension.is_some() {
+            self.skip_whitespace()?;
+            word_start = self.position;
+            word = self.word().map(str::to_ascii_uppercase);
+        }
+
+        match word.as_deref() {
+            Some("EMPTY") => {
+                return match kind.as_str() {
+                    "LINESTRING" => Ok(Geometry::LineString(Vec::new())),
+                    "POLYGON" => Ok(Geometry::Polygon(Vec::new())),
+                    "MULTIPOINT" => Ok(Geometry::MultiPoint(Vec::new())),
+                    "MULTILINESTRING" => Ok(Geometry::MultiLineString(Vec::new())),
+                    "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(Vec::new())),
+                    _ => Err(WktError::EmptyPoint(word_start)),
+                };
+            }
+            Some(_) => {
+                return Err(WktError::UnexpectedToken(
+                    self.text[word_start..self.position].to_string(),
+                    word_start,
+                    "dimension or '('",
+                ))
+            }
+            None => {}
+        }
+
+        match kind.as_str() {
+            "POINT" => {
+                self.expect(b'(', "'('")?;
+                let point = self.point()?;
+                self.expect(b')', "')'")?;
+                Ok(Geometry::Point(point))
+            }
+            "LINESTRING" => self.points().map(Geometry::LineString),
+            "POLYGON" => self.lines().map(Geometry::Polygon),
+            "MULTIPOINT" => self
+                .list(|parser| {
+                    // both the parenthesized and the bare forms are accepted
+                    parser.skip_whitespace()?;
+                    let start = parser.position;
+                    if parser.empty()? {
+                        return Err(WktError::EmptyPoint(start));
+                    }
+
+                    if parser.peek()? != Some(b'(') {
+                        return parser.point();
+                    }
+
+                    parser.expect(b'(', "'('")?;
+                    let point = parser.point()?;
+                    parser.expect(b')', "')'")?;
+                    Ok(point)
+                })
+                .map(Geometry::MultiPoint),
+            "MULTILINESTRING" => self.lines().map(Geometry::MultiLineString),
+            _ => self
+                .list(|parser| parser.lines())
+                .map(Geometry::MultiPolygon),
+        }
+    }
+
+    fn points(&mut self) -> Result<Vec<GeographicPoint>, WktError> {
+        self.list(|parser| parser.point())
+    }
+
+    fn lines(&mut self) -> Result<Vec<Vec<GeographicPoint>>, WktError> {
+        self.list(|parser| parser.points())
+    }
+
+    /// Returns the items between parentheses and separated by commas, or none
+    /// if the list is `EMPTY`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, WktError>,
+    ) -> Result<Vec<T>, WktError> {
+        if self.empty()? {
+            return Ok(Vec::new());
+        }
+
+        self.expect(b'(', "'('")?;
+
+        let mut items = vec![item(self)?];
+        loop {
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b',') => self.position += 1,
+                Some(b')') => break,
+                _ => return Err(self.unexpected("',' or ')'")),
+            }
+
+            items.push(item(self)?);
+        }
+
+        self.expect(b')', "')'")?;
+        Ok(items)
+    }
+
+    fn point(&mut self) -> Result<GeographicPoint, WktError> {
+        self.skip_whitespace()?;
+        let start = self.position;
+
+        let mut coordinates = Vec::with_capacity(4);
+        loop {
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b',' | b')') | None if !coordinates.is_empty() => break,
+                Some(byte)
+                    if coordinates.len() == 4 || !is_numeric(byte) && coordinates.len() >= 2 =>
+                {
+                    return Err(self.unexpected("',' or ')'"))
+                }
+                _ => coordinates.push(self.number()?),
+            }
+        }
+
+        let dimension = match (self.dimension, coordinates.len()) {
+            (Some(dimension), count) if dimension.count() == count => dimension,
+            (None, count @ 2..=4) => {
+                // undeclared dimensions are taken from the first position
+                let dimension = Dimension {
+                    altitude: count > 2,
+                    measure: count > 3,
+                };
+
+                self.dimension = Some(dimension);
+                dimension
+            }
+            (_, count) => return Err(WktError::InvalidDimension(count, start)),
+        };
+
+        let altitude = if dimension.altitude {
+            coordinates[2]
+        } else {
+            0.
+        };
+
+        point_from_degrees(coordinates[0], coordinates[1], altitude)
+            .ok_or(WktError::OutOfRange(start))
+    }
+
+    fn number(&mut self) -> Result<f64, WktError> {
+        self.skip_whitespace()?;
+        let start = self.position;
+        let length = self.text[start..]
+            .find(|c: char| !c.is_ascii() || !is_numeric(c as u8))
+            .unwrap_or(self.text.len() - start);
+
+        if length == 0 {
+            return Err(self.unexpected("number"));
+        }
+
+        let number = &self.text[start..start + length];
+        let value = number
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| WktError::InvalidNumber(number.to_string(), start))?;
+
+        self.position += length;
+        Ok(value)
+    }
+
+    /// Consumes the `EMPTY` keyword, if it is the next token.
+    fn empty(&mut self) -> Result<bool, WktError> {
+        self.skip_whitespace()?;
+        let start = self.position;
+        if self
+            .word()
+            .is_some_and(|word| word.eq_ignore_ascii_case("EMPTY"))
+        {
+            return Ok(true);
+        }
+
+        self.position = start;
+        Ok(false)
+    }
+
+    fn word(&mut self) -> Option<&'a str> {
+        let start = self.position;
+        let length = self.text[start..]
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.text.len() - start);
+
+        self.position += length;
+        (length > 0).then(|| &self.text[start..start + length])
+    }
+}
+
+impl TextReader for Parser<'_> {
+    type Error = WktError;
+
+    fn peek(&mut self) -> Result<Option<u8>, WktError> {
+        Ok(self.text.as_bytes().get(self.position).copied())
+    }
+
+    fn bump(&mut self) -> Result<Option<u8>, WktError> {
+        let byte = self.peek()?;
+        if byte.is_some() {
+            self.position += 1;
+        }
+
+        Ok(byte)
+    }
+
+    fn unexpected(&self, expected: &'static str) -> WktError {
+        let rest = &self.text[self.position..];
+        let Some(first) = rest.chars().next() else {
+            return WktError::UnexpectedEnd(expected);
+        };
+
+        let length = if first.is_ascii_alphanumeric() {
+            rest.find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len())
+        } else {
+            first.len_utf8()
+        };
+
+        WktError::UnexpectedToken(rest[..length].to_string(), self.position, expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::from_degrees;
+    use float_cmp::approx_eq;
+
+    #[test]
+    fn format_must_not_fail() {
+        struct TestCase {
+            name: &'static str,
+            format: Wkt,
+            geometry: Geometry,
+            output: &'static str,
+        }
+
+        vec![
+            TestCase {
+                name: "point",
+                format: Wkt::default(),
+                geometry: Geometry::Point(from_degrees(-3.7038, 40.4168)),
+                output: "POINT (-3.7038 40.4168)",
+            },
+            TestCase {
+                name: "point with altitude",
+                format: Wkt::default().with_altitude(true).with_precision(2),
+                geometry: Geometry::Point(from_degrees(-3.7038, 40.4168).with_altitude(657.)),
+                output: "POINT Z (-3.7 40.42 657)",
+            },
+            TestCase {
+                name: "linestring",
+                format: Wkt::default(),
+                geometry: Geometry::LineString(vec![
+                    from_degrees(30., 10.),
+                    from_degrees(10., 30.),
+                    from_degrees(40., 40.),
+                ]),
+                output: "LINESTRING (30 10, 10 30, 40 40)",
+            },
+            TestCase {
+                name: "polygon with hole",
+                format: Wkt::default(),
+                geometry: Geometry::Polygon(vec![
+                    vec![
+                        from_degrees(35., 10.),
+                        from_degrees(45., 45.),
+                        from_degrees(15., 40.),
+                        from_degrees(35., 10.),
+                    ],
+                    vec![
+                        from_degrees(20., 30.),
+                        from_degrees(35., 35.),
+                        from_degrees(30., 20.),
+                        from_degrees(20., 30.),
+                    ],
+                ]),
+                output: "POLYGON ((35 10, 45 45, 15 40, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            },
+            TestCase {
+                name: "multipoint",
+                format: Wkt::default(),
+                geometry: Geometry::MultiPoint(vec![from_degrees(10., 40.), from_degrees(-0., 0.)]),
+                output: "MULTIPOINT ((10 40), (0 0))",
+            },
+            TestCase {
+                name: "multipolygon",
+                format: Wkt::default(),
+                geometry: Geometry::MultiPolygon(vec![vec![vec![
+                    from_degrees(0., 0.),
+                    from_degrees(1., 0.),
+                    from_degrees(0., 1.),
+                    from_degrees(0., 0.),
+                ]]]),
+                output: "MULTIPOLYGON (((0 0, 1 0, 0 1, 0 0)))",
+            },
+            TestCase {
+                name: "empty multilinestring",
+                format: Wkt::default().with_altitude(true),
+                geometry: Geometry::MultiLineString(Vec::new()),
+                output: "MULTILINESTRING Z EMPTY",
+            },
+            TestCase {
+                name: "polygon with an empty ring",
+                format: Wkt::default(),
+                geometry: Geometry::Polygon(vec![vec![]]),
+                output: "POLYGON (EMPTY)",
+            },
+            TestCase {
+                name: "multipolygon with an empty member",
+                format: Wkt::default(),
+                geometry: Geometry::MultiPolygon(vec![
+                    vec![],
+                    vec![vec![
+                        from_degrees(0., 0.),
+                        from_degrees(1., 0.),
+                        from_degrees(0., 1.),
+                        from_degrees(0., 0.),
+                    ]],
+                ]),
+                output: "MULTIPOLYGON (EMPTY, ((0 0, 1 0, 0 1, 0 0)))",
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                test_case.format.format(&test_case.geometry),
+                test_case.output,
+                "{}",
+                test_case.name
+            );
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        struct TestCase {
+            input: &'static str,
+            kind: &'static str,
+            points: Vec<(f64, f64, f64)>,
+        }
+
+        vec![
+            TestCase {
+                input: "POINT(-3.7038 40.4168)",
+                kind: "Point",
+                points: vec![(-3.7038, 40.4168, 0.)],
+            },
+            TestCase {
+                input: " point z ( 1e1 -2.5 100 ) ",
+                kind: "Point",
+                points: vec![(10., -2.5, 100.)],
+            },
+            TestCase {
+                input: "POINT (1 2 3)",
+                kind: "Point",
+                points: vec![(1., 2., 3.)],
+            },
+            TestCase {
+                input: "POINT M (1 2 3)",
+                kind: "Point",
+                points: vec![(1., 2., 0.)],
+            },
+            TestCase {
+                input: "LineString ZM (1 2 3 4, 5 6 7 8)",
+                kind: "LineString",
+                points: vec![(1., 2., 3.), (5., 6., 7.)],
+            },
+            TestCase {
+                input: "MULTIPOINT (10 40, 40 30)",
+                kind: "MultiPoint",
+                points: vec![(10., 40., 0.), (40., 30., 0.)],
+            },
+            TestCase {
+                input: "MULTIPOINT ((10 40), (40 30))",
+                kind: "MultiPoint",
+                points: vec![(10., 40., 0.), (40., 30., 0.)],
+            },
+            TestCase {
+                input: "MULTILINESTRING ((10 10, 20 20), (40 40, 30 30))",
+                kind: "MultiLineString",
+                points: vec![
+                    (10., 10., 0.),
+                    (20., 20., 0.),
+                    (40., 40., 0.),
+                    (30., 30., 0.),
+                ],
+            },
+            TestCase {
+                input: "MULTIPOLYGON (((30 20, 45 40, 10 40, 30 20)), ((15 5, 40 10, 5 10, 15 5)))",
+                kind: "MultiPolygon",
+                points: vec![
+                    (30., 20., 0.),
+                    (45., 40., 0.),
+                    (10., 40., 0.),
+                    (30., 20., 0.),
+                    (15., 5., 0.),
+                    (40., 10., 0.),
+                    (5., 10., 0.),
+                    (15., 5., 0.),
+                ],
+            },
+            TestCase {
+                input: "POLYGON EMPTY",
+                kind: "Polygon",
+                points: vec![],
+            },
+            TestCase {
+                input: "MULTILINESTRING (empty, (1 2, 3 4))",
+                kind: "MultiLineString",
+                points: vec![(1., 2., 0.), (3., 4., 0.)],
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            let geometry = Wkt::parse(test_case.input).unwrap();
+            assert_eq!(geometry.kind(), test_case.kind, "{}", test_case.input);
+
+            let points = geometry.points();
+            assert_eq!(points.len(), test_case.points.len(), "{}", test_case.input);
+            points.iter().zip(test_case.points).for_each(
+                |(point, (longitude, latitude, altitude))| {
+                    assert!(
+                        approx_eq!(
+                            f64,
+                            point.longitude().to_degrees(),
+                            longitude,
+                            epsilon = 1e-12
+                        ) && approx_eq!(
+                            f64,
+                            point.latitude().to_degrees(),
+                            latitude,
+                            epsilon = 1e-12
+                        ) && approx_eq!(f64, point.altitude(), altitude, epsilon = 1e-12),
+                        "{}: got {:?}",
+                        test_case.input,
+                        point
+                    );
+                },
+            );
+        });
+    }
+
+    #[test]
+    fn parse_errors_must_be_positioned() {
+        struct TestCase {
+            input: &'static str,
+            error: WktError,
+        }
+
+        vec![
+            TestCase {
+                input: "",
+                error: WktError::UnexpectedEnd("geometry type"),
+            },
+            TestCase {
+                input: "CIRCLE (1 2)",
+                error: WktError::UnknownGeometry("CIRCLE".to_string(), 0),
+            },
+            TestCase {
+                input: "POINT (1 2",
+                error: WktError::UnexpectedEnd("')'"),
+            },
+            TestCase {
+                input: "POINT (1 2) x",
+                error: WktError::UnexpectedToken("x".to_string(), 12, "end of text"),
+            },
+            TestCase {
+                input: "POINT (1 a)",
+                error: WktError::InvalidNumber("a".to_string(), 9),
+            },
+            TestCase {
+                input: "POINT (1)",
+                error: WktError::InvalidDimension(1, 7),
+            },
+            TestCase {
+                input: "POINT Z (1 2)",
+                error: WktError::InvalidDimension(2, 9),
+            },
+            TestCase {
+                input: "LINESTRING (1 2, 3 4 5)",
+                error: WktError::InvalidDimension(3, 17),
+            },
+            TestCase {
+                input: "LINESTRING (1 2; 3 4)",
+                error: WktError::UnexpectedToken(";".to_string(), 15, "',' or ')'"),
+            },
+            TestCase {
+                input: "POINT (1 91)",
+                error: WktError::OutOfRange(7),
+            },
+            TestCase {
+                input: "POINT (181 1)",
+                error: WktError::OutOfRange(7),
+            },
+            TestCase {
+                input: "POINT EMPTY",
+                error: WktError::EmptyPoint(6),
+            },
+            TestCase {
+                input: "MULTIPOINT ((1 2), EMPTY)",
+                error: WktError::EmptyPoint(19),
+            },
+            TestCase {
+                input: "LINESTRING (1 2, EMPTY)",
+                error: WktError::InvalidNumber("EMPTY".to_string(), 17),
+            },
+            TestCase {
+                input: "POLYGON ((0 0, 1 1, 0 0)",
+                error: WktError::UnexpectedEnd("',' or ')'"),
+            },
+            TestCase {
+                input: "POINT (1 ñ)",
+                error: WktError::UnexpectedToken("ñ".to_string(), 9, "number"),
+            },
+        ]
+        .into_iter()
+        .for_each(|test_case| {
+            assert_eq!(
+                Wkt::parse(test_case.input),
+                Err(test_case.error),
+                "{}",
+                test_case.input
+            );
+        });
+    }
+
+    #[test]
+    fn empty_members_must_round_trip() {
+        [
+            Geometry::LineString(vec![]),
+            Geometry::Polygon(vec![vec![]]),
+            Geometry::MultiLineString(vec![vec![], vec![]]),
+            Geometry::MultiPolygon(vec![vec![], vec![vec![]]]),
+        ]
+        .into_iter()
+        .for_each(|geometry| {
+            let wkt = Wkt::default().format(&geometry);
+            assert_eq!(Wkt::parse(&wkt), Ok(geometry), "{wkt}");
+        });
+    }
+
+    #[test]
+    fn format_and_parse_must_round_trip() {
+        let geometry = Geometry::MultiLineString(vec![
+            vec![
+                from_degrees(-179.5, -89.25).with_altitude(-12.5),
+                from_degrees(0.123456789, 45.).with_altitude(8848.),
+            ],
+            vec![from_degrees(179.999, 0.).with_altitude(0.)],
+        ]);
+
+        let wkt = Wkt::default().with_altitude(true).format(&geometry);
+        let parsed = Wkt::parse(&wkt).unwrap();
+
+        geometry
+            .points()
+            .iter()
+            .zip(parsed.points())
+            .for_each(|(want, got)| {
+                assert!(
+                    want.distance(&got) < 1e-10 && approx_eq!(f64, want.altitude(), got.altitude()),
+                    "{wkt}: got {got:?}, want {want:?}"
+                );
+            });
+    }
+}
@@ -0,0 +1,253 @@
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+use std::{fmt, io::BufRead};
+
+/// Represents an error while reading an XML document.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum XmlError {
+    /// The XML at the given byte offset is not well formed.
+    Invalid(String, usize),
+    /// The underlying reader failed.
+    Io(String),
+}
+
+impl XmlError {
+    fn new(error: quick_xml::Error, position: usize) -> Self {
+        match error {
+            quick_xml::Error::Io(error) => XmlError::Io(error.to_string()),
+            error => XmlError::Invalid(error.to_string(), position),
+        }
+    }
+}
+
+/// Represents the start tag of an element.
+pub(crate) struct Element {
+    start: BytesStart<'static>,
+    /// Whether the element has no content, as in `<name/>`.
+    pub empty: bool,
+    /// The byte offset of the start tag.
+    pub position: usize,
+}
+
+impl Element {
+    /// Returns the name of the element, with no namespace prefix.
+    pub fn name(&self) -> &[u8] {
+        self.start.local_name().into_inner()
+    }
+
+    /// Returns the name of the element as written in the document.
+    pub fn qualified_name(&self) -> String {
+        String::from_utf8_lossy(self.start.name().as_ref()).into_owned()
+    }
+
+    /// Returns the unescaped value of the attribute of the given name, if
+    /// any.
+    pub fn attribute(&self, name: &str) -> Result<Option<String>, XmlError> {
+        self.start
+            .try_get_attribute(name)
+            .map_err(|error| XmlError::new(error.into(), self.position))?
+            .map(|attribute| {
+                attribute
+                    .unescape_value()
+                    .map(|value| value.into_owned())
+                    .map_err(|error| XmlError::new(error, self.position))
+            })
+            .transpose()
+    }
+
+    /// Returns the unescaped values of all the attributes of the element,
+    /// keyed by their name as written in the document.
+    #[cfg(feature = "gpx")]
+    pub fn attributes(&self) -> Result<Vec<(String, String)>, XmlError> {
+        self.start
+            .attributes()
+            .map(|attribute| {
+                let attribute =
+                    attribute.map_err(|error| XmlError::new(error.into(), self.position))?;
+                let value = attribute
+                    .unescape_value()
+                    .map_err(|error| XmlError::new(error, self.position))?;
+
+                Ok((
+                    String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                    value.into_owned(),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// A reader of the elements of an XML document, for recursive descent
+/// parsers. Whitespace-only text is ignored.
+pub(crate) struct XmlReader<R> {
+    reader: Reader<R>,
+}
+
+impl<R: BufRead> XmlReader<R> {
+    pub fn new(reader: R) -> Self {
+        let mut reader = Reader::from_reader(reader);
+        reader.config_mut().trim_text(true);
+        Self { reader }
+    }
+
+    /// Returns the root element of the document.
+    pub fn root(&mut self) -> Result<Element, XmlError> {
+        let mut buffer = Vec::new();
+        loop {
+            let (start, empty) = match self.next(&mut buffer)? {
+                Event::Start(start) => (start.into_owned(), false),
+                Event::Empty(start) => (start.into_owned(), true),
+                Event::Eof => return Err(self.end()),
+                _ => continue,
+            };
+
+            return Ok(self.element(start, empty));
+        }
+    }
+
+    /// Calls the given closure for each child element of the given one.
+    pub fn children<E: From<XmlError>>(
+        &mut self,
+        element: &Element,
+        mut child: impl FnMut(&mut Self, Element) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if element.empty {
+            return Ok(());
+        }
+
+        let mut buffer = Vec::new();
+        loop {
+            let (start, empty) = match self.next(&mut buffer)? {
+                Event::Start(start) => (start.into_owned(), false),
+                Event::Empty(start) => (start.into_owned(), true),
+                Event::End(_) => return Ok(()),
+                Event::Eof => return Err(self.end().into()),
+                _ => continue,
+            };
+
+            let element = self.element(start, empty);
+            child(self, element)?;
+        }
+    }
+
+    /// Returns the text content of the given element, ignoring any child
+    /// element.
+    pub fn text(&mut self, element: &Element) -> Result<String, XmlError> {
+        let mut text = String::new();
+        if element.empty {
+            return Ok(text);
+        }
+
+        let mut buffer = Vec::new();
+        loop {
+            let position = self.position();
+            match self.next(&mut buffer)? {
+                Event::Text(content) => {
+                    let content = content
+                        .unescape()
+                        .map_err(|error| XmlError::new(error, position))?;
+                    text.push_str(&content);
+                }
+                Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+                Event::Start(start) => {
+                    let start = start.into_owned();
+                    let element = self.element(start, false);
+                    self.skip(&element)?;
+                }
+                Event::End(_) => return Ok(text),
+                Event::Eof => return Err(self.end()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the content of the given element as raw XML.
+    #[cfg(feature = "gpx")]
+    pub fn raw(&mut self, element: &Element) -> Result<String, XmlError> {
+        if element.empty {
+            return Ok(String::new());
+        }
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        let mut depth = 0_usize;
+        let mut buffer = Vec::new();
+
+        loop {
+            let event = self.next(&mut buffer)?;
+            match &event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => break,
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(self.end()),
+                _ => {}
+            }
+
+            writer
+                .write_event(event)
+                .map_err(|error| XmlError::Io(error.to_string()))?;
+        }
+
+        Ok(String::from_utf8_lossy(&writer.into_inner()).into_owned())
+    }
+
+    /// Skips the content of the given element.
+    pub fn skip(&mut self, element: &Element) -> Result<(), XmlError> {
+        if !element.empty {
+            let position = self.position();
+            self.reader
+                .read_to_end_into(element.start.name(), &mut Vec::new())
+                .map_err(|error| XmlError::new(error, position))?;
+        }
+
+        Ok(())
+    }
+
+    fn next<'b>(&mut self, buffer: &'b mut Vec<u8>) -> Result<Event<'b>, XmlError> {
+        buffer.clear();
+        let position = self.position();
+        self.reader
+            .read_event_into(buffer)
+            .map_err(|error| XmlError::new(error, position))
+    }
+
+    /// Returns the element of the given start tag, which has just been read.
+    fn element(&self, start: BytesStart<'static>, empty: bool) -> Element {
+        // the tag is enclosed by "<" and ">", or "/>" if empty
+        let delimiters = if empty { 3 } else { 2 };
+        let position = self.position().saturating_sub(start.len() + delimiters);
+
+        Element {
+            start,
+            empty,
+            position,
+        }
+    }
+
+    fn position(&self) -> usize {
+        self.reader.buffer_position() as usize
+    }
+
+    fn end(&self) -> XmlError {
+        XmlError::Invalid("unexpected end of document".to_string(), self.position())
+    }
+}
+
+/// Writes the given element with the given text content, indented to the
+/// given depth.
+pub(crate) fn write_element(
+    out: &mut impl fmt::Write,
+    depth: usize,
+    name: &str,
+    text: &str,
+) -> fmt::Result {
+    writeln!(
+        out,
+        "{:indent$}<{name}>{}</{name}>",
+        "",
+        escape(text),
+        indent = depth * 2
+    )
+}